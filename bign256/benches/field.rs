@@ -41,11 +41,17 @@ fn bench_field_element_invert<M: Measurement>(group: &mut BenchmarkGroup<M>) {
     group.bench_function("invert", |b| b.iter(|| x.invert()));
 }
 
+fn bench_field_element_invert_vartime<M: Measurement>(group: &mut BenchmarkGroup<M>) {
+    let x = test_field_element_x();
+    group.bench_function("invert_vartime", |b| b.iter(|| x.invert_vartime()));
+}
+
 fn bench_field_element(c: &mut Criterion) {
     let mut group = c.benchmark_group("field element operations");
     bench_field_element_mul(&mut group);
     bench_field_element_square(&mut group);
     bench_field_element_invert(&mut group);
+    bench_field_element_invert_vartime(&mut group);
     bench_field_element_sqrt(&mut group);
     group.finish();
 }
@@ -55,6 +55,11 @@ fn bench_scalar_invert<M: Measurement>(group: &mut BenchmarkGroup<M>) {
     group.bench_function("invert", |b| b.iter(|| x.invert()));
 }
 
+fn bench_scalar_invert_vartime<M: Measurement>(group: &mut BenchmarkGroup<M>) {
+    let x = test_scalar_x();
+    group.bench_function("invert_vartime", |b| b.iter(|| x.invert_vartime()));
+}
+
 fn bench_point(c: &mut Criterion) {
     let mut group = c.benchmark_group("point operations");
     bench_point_mul(&mut group);
@@ -68,6 +73,7 @@ fn bench_scalar(c: &mut Criterion) {
     bench_scalar_mul(&mut group);
     bench_scalar_negate(&mut group);
     bench_scalar_invert(&mut group);
+    bench_scalar_invert_vartime(&mut group);
     group.finish();
 }
 
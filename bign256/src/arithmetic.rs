@@ -6,8 +6,16 @@
 //! See table B.1: l = 128.
 
 pub(crate) mod field;
+#[cfg(feature = "hash2curve")]
+mod hash2curve;
 pub(crate) mod scalar;
 
+#[cfg(all(
+    feature = "precomputed-tables",
+    not(any(feature = "critical-section", feature = "std"))
+))]
+compile_error!("`precomputed-tables` feature requires either `critical-section` or `std`");
+
 pub use self::scalar::Scalar;
 
 pub use self::field::FieldElement;
@@ -15,12 +23,19 @@ use crate::BignP256;
 pub use elliptic_curve::{CurveArithmetic, PrimeCurveArithmetic};
 pub use primeorder::{point_arithmetic, PrimeCurveParams};
 
+#[cfg(feature = "precomputed-tables")]
+use {once_cell::sync::Lazy, primeorder::BasepointTable};
+
 /// Elliptic curve point in affine coordinates.
 pub type AffinePoint = primeorder::AffinePoint<BignP256>;
 
 /// Elliptic curve point in projective coordinates.
 pub type ProjectivePoint = primeorder::ProjectivePoint<BignP256>;
 
+/// Lazily computed basepoint table.
+#[cfg(feature = "precomputed-tables")]
+static BASEPOINT_TABLE: Lazy<BasepointTable<BignP256, 32>> = Lazy::new(BasepointTable::new);
+
 impl CurveArithmetic for BignP256 {
     type AffinePoint = AffinePoint;
     type ProjectivePoint = ProjectivePoint;
@@ -42,4 +57,9 @@ impl PrimeCurveParams for BignP256 {
         FieldElement::ZERO,
         FieldElement::from_hex("6BF7FC3CFB16D69F5CE4C9A351D6835D78913966C408F6521E29CF1804516A93"),
     );
+
+    #[cfg(feature = "precomputed-tables")]
+    fn mul_by_generator(k: &Scalar) -> ProjectivePoint {
+        BASEPOINT_TABLE.mul(k)
+    }
 }
@@ -0,0 +1,24 @@
+//! Hashing to bign-curve256v1 using the simplified SWU map.
+
+use super::{field::FieldElement, scalar::Scalar};
+use crate::BignP256;
+use elliptic_curve::consts::U48;
+use primeorder::hash2curve::SswuParams;
+
+impl SswuParams for BignP256 {
+    /// Z = -11
+    const Z: FieldElement = FieldElement::from_u64(11).neg();
+
+    const SUITE_ID_RO: &'static str = "BIGN256_XMD:BELT-HASH_SSWU_RO_";
+    const SUITE_ID_NU: &'static str = "BIGN256_XMD:BELT-HASH_SSWU_NU_";
+}
+
+primeorder::impl_hash2curve!(BignP256, FieldElement, Scalar, U48);
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldElement, Scalar};
+    use crate::{AffinePoint, BignP256};
+
+    primeorder::impl_hash2curve_tests!(BignP256, AffinePoint, FieldElement, Scalar);
+}
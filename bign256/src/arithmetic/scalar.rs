@@ -1,43 +1,31 @@
 //! bign-curve256v1 scalar field elements.
+//!
+//! Arithmetic is implemented in terms of `crypto-bigint`'s `ConstMontyForm`.
 
-#![allow(
-    clippy::cast_possible_wrap,
-    clippy::cast_sign_loss,
-    clippy::cast_possible_truncation,
-    clippy::identity_op,
-    clippy::arithmetic_side_effects,
-    clippy::too_many_arguments,
-    clippy::unnecessary_cast
-)]
-
-#[cfg_attr(target_pointer_width = "32", path = "scalar/bign256_scalar_32.rs")]
-#[cfg_attr(target_pointer_width = "64", path = "scalar/bign256_scalar_64.rs")]
-mod scalar_impl;
-
-use self::scalar_impl::*;
-use crate::{BignP256, FieldBytes, FieldBytesEncoding, SecretKey, ORDER_HEX, U256};
-use core::{
-    iter::{Product, Sum},
-    ops::{AddAssign, MulAssign, Neg, Shr, ShrAssign, SubAssign},
-};
+#![allow(clippy::cast_possible_truncation, clippy::arithmetic_side_effects)]
+
+use crate::{BignP256, FieldBytes, FieldBytesEncoding, SecretKey, WideBytes, ORDER_HEX, U256};
+use core::ops::{Shr, ShrAssign};
 use elliptic_curve::{
-    bigint::Limb,
-    ff::PrimeField,
-    ops::{Invert, Reduce},
+    bigint::{ArrayEncoding, Limb, U512},
+    ff::{FromUniformBytes, PrimeField},
+    ops::Reduce,
     scalar::{FromUintUnchecked, IsHigh},
-    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, CtOption},
+    subtle::{Choice, ConditionallySelectable, ConstantTimeGreater},
     Curve as _, Error, Result, ScalarPrimitive,
 };
-use primeorder::impl_bernstein_yang_invert;
 
 #[cfg(feature = "bits")]
-use {crate::ScalarBits, elliptic_curve::group::ff::PrimeFieldBits};
+use {
+    crate::ScalarBits,
+    elliptic_curve::{bigint::Word, group::ff::PrimeFieldBits},
+};
 
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
 
 #[cfg(doc)]
-use core::ops::{Add, Mul, Sub};
+use core::ops::{Add, Mul, Neg, Sub};
 
 /// Scalars are elements in the finite field modulo `n`.
 ///
@@ -62,66 +50,12 @@ use core::ops::{Add, Mul, Sub};
 ///   operations over field elements represented as bits (requires `bits` feature)
 ///
 /// Please see the documentation for the relevant traits for more information.
-#[derive(Clone, Copy, Debug, PartialOrd, Ord)]
+#[derive(Clone, Copy)]
 pub struct Scalar(pub U256);
 
-primeorder::impl_mont_field_element!(
-    BignP256,
-    Scalar,
-    FieldBytes,
-    U256,
-    BignP256::ORDER,
-    fiat_bign256_scalar_montgomery_domain_field_element,
-    fiat_bign256_scalar_from_montgomery,
-    fiat_bign256_scalar_to_montgomery,
-    fiat_bign256_scalar_add,
-    fiat_bign256_scalar_sub,
-    fiat_bign256_scalar_mul,
-    fiat_bign256_scalar_opp,
-    fiat_bign256_scalar_square
-);
+primefield::impl_monty_field_element!(BignP256, Scalar, FieldBytes, U256, ORDER_HEX, 3);
 
 impl Scalar {
-    /// Compute [`Scalar`] inversion: `1 / self`.
-    pub fn invert(&self) -> CtOption<Self> {
-        CtOption::new(self.invert_unchecked(), !self.is_zero())
-    }
-
-    /// Returns the multiplicative inverse of self.
-    ///
-    /// Does not check that self is non-zero.
-    const fn invert_unchecked(&self) -> Self {
-        let words = impl_bernstein_yang_invert!(
-            self.0.as_words(),
-            Self::ONE.0.to_words(),
-            256,
-            U256::LIMBS,
-            Limb,
-            fiat_bign256_scalar_from_montgomery,
-            fiat_bign256_scalar_mul,
-            fiat_bign256_scalar_opp,
-            fiat_bign256_scalar_divstep_precomp,
-            fiat_bign256_scalar_divstep,
-            fiat_bign256_scalar_msat,
-            fiat_bign256_scalar_selectznz,
-        );
-        Self(U256::from_words(words))
-    }
-
-    /// Returns the square root of self mod p, or `None` if no square root
-    /// exists.
-    pub fn sqrt(&self) -> CtOption<Self> {
-        // Because p ≡ 3 mod 4, sqrt can be done with only one
-        // exponentiation via the computation of self^((p + 1) // 4) (mod p).
-        let sqrt = self.pow_vartime(&[
-            0x1f96afe6498f5982,
-            0xf65723b5837ed37f,
-            0xffffffffffffffff,
-            0x3fffffffffffffff,
-        ]);
-        CtOption::new(sqrt, (sqrt * sqrt).ct_eq(self))
-    }
-
     /// Right shifts the scalar.
     ///
     /// Note: not constant-time with respect to the `shift` parameter.
@@ -144,14 +78,6 @@ impl FromUintUnchecked for Scalar {
     }
 }
 
-impl Invert for Scalar {
-    type Output = CtOption<Self>;
-
-    fn invert(&self) -> CtOption<Self> {
-        self.invert()
-    }
-}
-
 impl IsHigh for Scalar {
     fn is_high(&self) -> Choice {
         const MODULUS_SHR1: U256 = BignP256::ORDER.shr_vartime(1);
@@ -181,36 +107,9 @@ impl ShrAssign<usize> for Scalar {
     }
 }
 
-impl PrimeField for Scalar {
-    type Repr = FieldBytes;
-
-    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
-        Self::from_bytes(&repr)
-    }
-
-    fn to_repr(&self) -> Self::Repr {
-        self.to_bytes()
-    }
-
-    fn is_odd(&self) -> Choice {
-        self.is_odd()
-    }
-
-    const MODULUS: &'static str = ORDER_HEX;
-    const NUM_BITS: u32 = 256;
-    const CAPACITY: u32 = 255;
-    const TWO_INV: Self = Self::from_u64(2).invert_unchecked();
-    const MULTIPLICATIVE_GENERATOR: Self = Self::from_u64(3);
-    const S: u32 = 1;
-    const ROOT_OF_UNITY: Self =
-        Self::from_hex("ffffffffffffffffffffffffffffffffd95c8ed60dfb4dfc7e5abf99263d6606");
-    const ROOT_OF_UNITY_INV: Self = Self::ROOT_OF_UNITY.invert_unchecked();
-    const DELTA: Self = Self::from_u64(9);
-}
-
 #[cfg(feature = "bits")]
 impl PrimeFieldBits for Scalar {
-    type ReprBits = fiat_bign256_scalar_montgomery_domain_field_element;
+    type ReprBits = [Word; U256::LIMBS];
 
     fn to_le_bits(&self) -> ScalarBits {
         self.to_canonical().to_words().into()
@@ -237,6 +136,25 @@ impl Reduce<U256> for Scalar {
     }
 }
 
+impl Reduce<U512> for Scalar {
+    type Bytes = WideBytes;
+
+    fn reduce(w: U512) -> Self {
+        primefield::reduce_wide(&w)
+    }
+
+    #[inline]
+    fn reduce_bytes(bytes: &WideBytes) -> Self {
+        Self::reduce(U512::from_be_byte_array(*bytes))
+    }
+}
+
+impl FromUniformBytes<64> for Scalar {
+    fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
+        primefield::reduce_le_bytes(bytes)
+    }
+}
+
 impl From<ScalarPrimitive<BignP256>> for Scalar {
     fn from(w: ScalarPrimitive<BignP256>) -> Self {
         Scalar::from(&w)
@@ -302,9 +220,14 @@ impl TryFrom<U256> for Scalar {
 #[cfg(test)]
 mod tests {
     use super::Scalar;
-    use elliptic_curve::ff::PrimeField;
+    use elliptic_curve::{bigint::U512, ff::PrimeField};
+    use primefield::{
+        impl_field_differential_tests, impl_field_reduce_wide_tests,
+        impl_field_sqrt_differential_tests,
+    };
     use primeorder::{
-        impl_field_identity_tests, impl_field_invert_tests, impl_field_sqrt_tests,
+        impl_field_identity_tests, impl_field_invert_tests, impl_field_invert_vartime_tests,
+        impl_field_pow_tests, impl_field_sqrt_ratio_tests, impl_field_sqrt_tests,
         impl_primefield_tests,
     };
 
@@ -318,6 +241,12 @@ mod tests {
 
     impl_field_identity_tests!(Scalar);
     impl_field_invert_tests!(Scalar);
+    impl_field_invert_vartime_tests!(Scalar);
     impl_field_sqrt_tests!(Scalar);
+    impl_field_sqrt_ratio_tests!(Scalar);
+    impl_field_pow_tests!(Scalar);
+    impl_field_reduce_wide_tests!(Scalar, U512, 64);
+    impl_field_differential_tests!(Scalar);
+    impl_field_sqrt_differential_tests!(Scalar);
     impl_primefield_tests!(Scalar, T);
 }
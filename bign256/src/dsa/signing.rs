@@ -15,7 +15,9 @@
 #![allow(non_snake_case)]
 
 use super::{Signature, VerifyingKey, BELT_OID};
-use crate::{BignP256, FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Scalar, SecretKey};
+use crate::{
+    BignP256, FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Scalar, SecretKey, U256,
+};
 use belt_hash::{BeltHash, Digest};
 use core::fmt::{self, Debug};
 use elliptic_curve::{
@@ -25,7 +27,14 @@ use elliptic_curve::{
     subtle::{Choice, ConstantTimeEq},
     Curve, Field, FieldBytesEncoding, PrimeField,
 };
-use signature::{hazmat::PrehashSigner, Error, KeypairRef, Result, Signer};
+use signature::{
+    hazmat::{PrehashSigner, RandomizedPrehashSigner},
+    rand_core::CryptoRngCore,
+    Error, KeypairRef, RandomizedSigner, Result, Signer,
+};
+
+#[cfg(feature = "fault-countermeasures")]
+use signature::hazmat::PrehashVerifier;
 
 /// BignP256 secret key used for signing messages and producing signatures.
 ///
@@ -102,25 +111,74 @@ impl SigningKey {
 
 impl PrehashSigner<Signature> for SigningKey {
     fn sign_prehash(&self, prehash: &[u8]) -> Result<Signature> {
+        self.sign_prehash_rfc6979(prehash, &[], ProjectivePoint::mul_by_generator)
+    }
+}
+
+impl RandomizedPrehashSigner<Signature> for SigningKey {
+    fn sign_prehash_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        prehash: &[u8],
+    ) -> Result<Signature> {
+        let mut data = FieldBytes::default();
+        rng.try_fill_bytes(&mut data)?;
+
+        // With `side-channel-hardening`, also use `rng` to randomise the
+        // computation of `𝑘𝐺`.
+        #[cfg(feature = "side-channel-hardening")]
+        let mul_by_generator = |k: &Scalar| ProjectivePoint::GENERATOR.mul_blinded(k, rng);
+        #[cfg(not(feature = "side-channel-hardening"))]
+        let mul_by_generator = ProjectivePoint::mul_by_generator;
+
+        self.sign_prehash_rfc6979(prehash, &data, mul_by_generator)
+    }
+}
+
+impl Signer<Signature> for SigningKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature> {
+        // 1. Set 𝐻 ← ℎ(𝑋).
+        let hash = self.verifying_key.hash_msg(msg);
+        self.sign_prehash(&hash)
+    }
+}
+
+impl RandomizedSigner<Signature> for SigningKey {
+    fn try_sign_with_rng(&self, rng: &mut impl CryptoRngCore, msg: &[u8]) -> Result<Signature> {
+        // 1. Set 𝐻 ← ℎ(𝑋).
+        let hash = self.verifying_key.hash_msg(msg);
+        self.sign_prehash_with_rng(rng, &hash)
+    }
+}
+
+impl SigningKey {
+    /// Compute a signature using RFC6979 to deterministically derive 𝑘 with
+    /// the additional data `data`, and `mul_by_generator` to compute 𝑘𝐺.
+    fn sign_prehash_rfc6979(
+        &self,
+        prehash: &[u8],
+        data: &[u8],
+        mul_by_generator: impl FnOnce(&Scalar) -> ProjectivePoint,
+    ) -> Result<Signature> {
         if prehash.len() != <BignP256 as Curve>::FieldBytesSize::USIZE {
             return Err(Error::new());
         }
         let mut h_word: Array<u8, U32> = Array::clone_from_slice(prehash);
         h_word.reverse();
 
-        let h = Scalar::reduce_bytes(&h_word);
+        let h = <Scalar as Reduce<U256>>::reduce_bytes(&h_word);
 
         //2. Generate 𝑘 ← rand(1,..,𝑞-1)
         let k = Scalar::from_repr(rfc6979::generate_k::<BeltHash, _>(
             &self.secret_scalar.to_repr(),
             &FieldBytesEncoding::<BignP256>::encode_field_bytes(&BignP256::ORDER),
             &h.to_bytes(),
-            &[],
+            data,
         ))
         .unwrap();
 
         // 3. Set 𝑅 ← 𝑘𝐺.
-        let mut R: Array<u8, _> = ProjectivePoint::mul_by_generator(&k).to_affine().x();
+        let mut R: Array<u8, _> = mul_by_generator(&k).to_affine().x();
         R.reverse();
 
         // 4. Set 𝑆0 ← ⟨︀belt-hash(OID(ℎ) ‖ ⟨𝑅⟩2𝑙 ‖ 𝐻)⟩︀_𝑙.
@@ -143,16 +201,15 @@ impl PrehashSigner<Signature> for SigningKey {
         let s1 = k.sub(&h).sub(&right);
 
         // 6. Set 𝑆 ← 𝑆0 ‖ 𝑆1.
-        // 7. Return S.
-        Signature::from_scalars(s0_scalar, s1)
-    }
-}
+        let signature = Signature::from_scalars(s0_scalar, s1)?;
 
-impl Signer<Signature> for SigningKey {
-    fn try_sign(&self, msg: &[u8]) -> Result<Signature> {
-        // 1. Set 𝐻 ← ℎ(𝑋).
-        let hash = self.verifying_key.hash_msg(msg);
-        self.sign_prehash(&hash)
+        // Verify the signature before releasing it, so a fault injected while
+        // signing can't leak the secret key.
+        #[cfg(feature = "fault-countermeasures")]
+        self.verifying_key.verify_prehash(prehash, &signature)?;
+
+        // 7. Return S.
+        Ok(signature)
     }
 }
 
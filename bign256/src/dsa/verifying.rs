@@ -16,7 +16,7 @@
 
 use super::{Signature, BELT_OID};
 use crate::{
-    AffinePoint, BignP256, EncodedPoint, FieldBytes, Hash, ProjectivePoint, PublicKey, Scalar,
+    AffinePoint, BignP256, EncodedPoint, FieldBytes, Hash, ProjectivePoint, PublicKey, Scalar, U256,
 };
 use belt_hash::{
     digest::{Digest, FixedOutput},
@@ -25,7 +25,7 @@ use belt_hash::{
 use elliptic_curve::{
     array::{consts::U32, typenum::Unsigned, Array},
     group::GroupEncoding,
-    ops::{LinearCombination, Reduce},
+    ops::Reduce,
     sec1::ToEncodedPoint,
     Curve, Field, Group,
 };
@@ -124,13 +124,13 @@ impl PrehashVerifier<Signature> for VerifyingKey {
         let mut hash: Array<u8, U32> = Array::clone_from_slice(prehash);
         hash.reverse();
 
-        let hw = Scalar::reduce_bytes(FieldBytes::from_slice(&hash));
+        let hw = <Scalar as Reduce<U256>>::reduce_bytes(FieldBytes::from_slice(&hash));
         let left = s1.add(&hw);
 
         let right = s0.add(&Scalar::from_u64(2).pow([128, 0, 0, 0]));
 
         // 5. Set 𝑅 ← (︀(𝑆1 + 𝐻) mod 𝑞)︀𝐺 + (𝑆0 + 2𝑙)𝑄.
-        let r = ProjectivePoint::lincomb(&[
+        let r = ProjectivePoint::lincomb_vartime(&[
             (ProjectivePoint::generator(), left),
             (self.public_key.to_projective(), right),
         ]);
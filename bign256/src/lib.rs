@@ -33,6 +33,9 @@ extern crate alloc;
 #[cfg(feature = "arithmetic")]
 pub mod arithmetic;
 
+#[cfg(feature = "ecdh")]
+pub mod ecdh;
+
 #[cfg(any(feature = "test-vectors", test))]
 pub mod test_vectors;
 
@@ -50,7 +53,7 @@ pub use elliptic_curve::pkcs8;
 use elliptic_curve::{
     array::Array,
     bigint::ArrayEncoding,
-    consts::{U32, U33},
+    consts::{U32, U33, U64},
     FieldBytesEncoding,
 };
 
@@ -114,6 +117,9 @@ pub type CompressedPoint = Array<u8, U33>;
 /// Byte array containing a serialized field element value (base field or scalar).
 pub type FieldBytes = elliptic_curve::FieldBytes<BignP256>;
 
+/// Bytes used by a wide reduction: twice the width of [`FieldBytes`].
+pub type WideBytes = Array<u8, U64>;
+
 /// SEC1 encoded point.
 pub type EncodedPoint = elliptic_curve::sec1::EncodedPoint<BignP256>;
 
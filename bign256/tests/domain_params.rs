@@ -0,0 +1,18 @@
+//! Domain parameter audit.
+
+#![cfg(feature = "arithmetic")]
+
+use bign256::BignP256;
+use primeorder::{
+    dev::{AuditReport, TwistSecurity},
+    impl_domain_params_tests,
+};
+
+impl_domain_params_tests!(
+    BignP256,
+    AuditReport {
+        twist_security: TwistSecurity::Exact(125),
+        embedding_degree: None,
+        cm_discriminant: None,
+    }
+);
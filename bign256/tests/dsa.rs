@@ -4,7 +4,7 @@
 
 use bign256::{
     dsa::{
-        signature::{Signer, Verifier},
+        signature::{RandomizedSigner, Signer, Verifier},
         Signature, SigningKey, VerifyingKey,
     },
     NonZeroScalar, Scalar, U256,
@@ -12,6 +12,7 @@ use bign256::{
 use elliptic_curve::ops::Reduce;
 use hex_literal::hex;
 use proptest::prelude::*;
+use rand_core::OsRng;
 
 const PUBLIC_KEY: [u8; 65] = hex!(
     "04
@@ -49,6 +50,12 @@ proptest! {
         prop_assert!(sk.verifying_key().verify(MSG, &signature).is_ok());
     }
 
+    #[test]
+    fn sign_with_rng_and_verify(sk in signing_key()) {
+        let signature = sk.sign_with_rng(&mut OsRng, MSG);
+        prop_assert!(sk.verifying_key().verify(MSG, &signature).is_ok());
+    }
+
     #[test]
     fn reject_invalid_signature(sk in signing_key(), byte in 0usize..32, bit in 0usize..8) {
         let mut signature_bytes = sk.sign(MSG).to_bytes();
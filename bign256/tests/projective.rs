@@ -2,14 +2,14 @@
 
 #![cfg(all(feature = "arithmetic", feature = "test-vectors"))]
 
+use bign256::{
+    test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS},
+    AffinePoint, ProjectivePoint, Scalar,
+};
 use elliptic_curve::{
     group::{ff::PrimeField, GroupEncoding},
     sec1::{self, ToEncodedPoint},
 };
-use p256::{
-    test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS},
-    AffinePoint, ProjectivePoint, Scalar,
-};
 use primeorder::{impl_projective_arithmetic_tests, Double};
 
 impl_projective_arithmetic_tests!(
@@ -20,6 +20,15 @@ impl_projective_arithmetic_tests!(
     MUL_TEST_VECTORS
 );
 
+#[cfg(feature = "ecdh")]
+impl_projective_arithmetic_tests!(
+    diffie_hellman_x: bign256::ecdh::diffie_hellman_x,
+    AffinePoint,
+    ProjectivePoint,
+    Scalar,
+    MUL_TEST_VECTORS
+);
+
 #[test]
 fn projective_identity_to_bytes() {
     // This is technically an invalid SEC1 encoding, but is preferable to panicking.
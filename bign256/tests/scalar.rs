@@ -3,7 +3,7 @@
 #![cfg(feature = "arithmetic")]
 
 use bign256::{Scalar, U256};
-use elliptic_curve::ops::{Invert, Reduce};
+use elliptic_curve::ops::Reduce;
 use proptest::prelude::*;
 
 prop_compose! {
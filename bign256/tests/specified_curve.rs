@@ -0,0 +1,43 @@
+//! Tests for keys which carry explicit domain parameters (`specifiedCurve`).
+
+#![cfg(all(feature = "pkcs8", feature = "arithmetic"))]
+
+use bign256::{elliptic_curve::sec1::ToEncodedPoint, PublicKey, SecretKey};
+use hex_literal::hex;
+use primeorder::specified_curve::{DecodeSpecifiedPrivateKey, DecodeSpecifiedPublicKey};
+
+/// DER-encoded PKCS#8 private key with explicit parameters.
+///
+/// Generated from the curve parameters, since OpenSSL doesn't support bign-curve256v1.
+const PKCS8_PRIVATE_KEY_DER: &[u8; 357] = include_bytes!("examples/pkcs8-private-key-explicit.der");
+
+/// DER-encoded SPKI public key with explicit parameters.
+const PKCS8_PUBLIC_KEY_DER: &[u8; 311] = include_bytes!("examples/pkcs8-public-key-explicit.der");
+
+/// Offset of the last byte of the `b` coefficient in [`PKCS8_PUBLIC_KEY_DER`].
+const EQUATION_B_OFFSET: usize = 137;
+
+/// Offset of the last byte of the `order` in [`PKCS8_PUBLIC_KEY_DER`].
+const ORDER_OFFSET: usize = 239;
+
+#[test]
+fn decode_keys_with_explicit_params() {
+    let secret_key = SecretKey::from_specified_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+    let public_key = PublicKey::from_specified_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+
+    let expected_scalar = hex!("b3b987ec3ef9789ab5308f2c393dbe1723ab31d26a76c5dbd16c1bd1bd0dd92c");
+    assert_eq!(secret_key.to_bytes().as_slice(), &expected_scalar[..]);
+    assert_eq!(
+        secret_key.public_key().to_encoded_point(false),
+        public_key.to_encoded_point(false)
+    );
+}
+
+#[test]
+fn reject_mismatched_params() {
+    for offset in [EQUATION_B_OFFSET, ORDER_OFFSET] {
+        let mut der = *PKCS8_PUBLIC_KEY_DER;
+        der[offset] ^= 1;
+        assert!(PublicKey::from_specified_public_key_der(&der).is_err());
+    }
+}
@@ -5,12 +5,19 @@ use crate::{FieldElement, Scalar};
 use elliptic_curve::{CurveArithmetic, PrimeCurveArithmetic};
 use primeorder::{point_arithmetic, PrimeCurveParams};
 
+#[cfg(feature = "precomputed-tables")]
+use {once_cell::sync::Lazy, primeorder::BasepointTable};
+
 /// Elliptic curve point in affine coordinates.
 pub type AffinePoint = primeorder::AffinePoint<BrainpoolP256r1>;
 
 /// Elliptic curve point in projective coordinates.
 pub type ProjectivePoint = primeorder::ProjectivePoint<BrainpoolP256r1>;
 
+/// Lazily computed basepoint table.
+#[cfg(feature = "precomputed-tables")]
+static BASEPOINT_TABLE: Lazy<BasepointTable<BrainpoolP256r1, 32>> = Lazy::new(BasepointTable::new);
+
 /// Primitive scalar type.
 pub type ScalarPrimitive = elliptic_curve::ScalarPrimitive<BrainpoolP256r1>;
 
@@ -36,6 +43,11 @@ impl PrimeCurveParams for BrainpoolP256r1 {
         FieldElement::from_hex("8bd2aeb9cb7e57cb2c4b482ffc81b7afb9de27e1e3bd23c23a4453bd9ace3262"),
         FieldElement::from_hex("547ef835c3dac4fd97f8461a14611dc9c27745132ded8e545c1d54c72f046997"),
     );
+
+    #[cfg(feature = "precomputed-tables")]
+    fn mul_by_generator(k: &Scalar) -> ProjectivePoint {
+        BASEPOINT_TABLE.mul(k)
+    }
 }
 
 impl From<ScalarPrimitive> for Scalar {
@@ -132,6 +144,4 @@ mod tests {
             assert_eq!(p, p_ref);
         }
     }
-
-
 }
@@ -0,0 +1,24 @@
+//! Hashing to brainpoolP256r1 using the simplified SWU map.
+
+use super::BrainpoolP256r1;
+use crate::{FieldElement, Scalar};
+use elliptic_curve::consts::U48;
+use primeorder::hash2curve::SswuParams;
+
+impl SswuParams for BrainpoolP256r1 {
+    /// Z = -2
+    const Z: FieldElement = FieldElement::from_u64(2).neg();
+
+    const SUITE_ID_RO: &'static str = "brainpoolP256r1_XMD:SHA-256_SSWU_RO_";
+    const SUITE_ID_NU: &'static str = "brainpoolP256r1_XMD:SHA-256_SSWU_NU_";
+}
+
+primeorder::impl_hash2curve!(BrainpoolP256r1, FieldElement, Scalar, U48);
+
+#[cfg(test)]
+mod tests {
+    use super::super::{AffinePoint, BrainpoolP256r1};
+    use super::{FieldElement, Scalar};
+
+    primeorder::impl_hash2curve_tests!(BrainpoolP256r1, AffinePoint, FieldElement, Scalar);
+}
@@ -5,12 +5,19 @@ use crate::{FieldElement, Scalar};
 use elliptic_curve::{CurveArithmetic, PrimeCurveArithmetic};
 use primeorder::{point_arithmetic, PrimeCurveParams};
 
+#[cfg(feature = "precomputed-tables")]
+use {once_cell::sync::Lazy, primeorder::BasepointTable};
+
 /// Elliptic curve point in affine coordinates.
 pub type AffinePoint = primeorder::AffinePoint<BrainpoolP256t1>;
 
 /// Elliptic curve point in projective coordinates.
 pub type ProjectivePoint = primeorder::ProjectivePoint<BrainpoolP256t1>;
 
+/// Lazily computed basepoint table.
+#[cfg(feature = "precomputed-tables")]
+static BASEPOINT_TABLE: Lazy<BasepointTable<BrainpoolP256t1, 32>> = Lazy::new(BasepointTable::new);
+
 /// Primitive scalar type.
 pub type ScalarPrimitive = elliptic_curve::ScalarPrimitive<BrainpoolP256t1>;
 
@@ -36,6 +43,11 @@ impl PrimeCurveParams for BrainpoolP256t1 {
         FieldElement::from_hex("a3e8eb3cc1cfe7b7732213b23a656149afa142c47aafbc2b79a191562e1305f4"),
         FieldElement::from_hex("2d996c823439c56d7f7b22e14644417e69bcb6de39d027001dabe8f35b25c9be"),
     );
+
+    #[cfg(feature = "precomputed-tables")]
+    fn mul_by_generator(k: &Scalar) -> ProjectivePoint {
+        BASEPOINT_TABLE.mul(k)
+    }
 }
 
 impl From<ScalarPrimitive> for Scalar {
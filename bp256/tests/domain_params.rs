@@ -0,0 +1,32 @@
+//! Domain parameter audit.
+
+#![cfg(feature = "wip-arithmetic-do-not-use")]
+
+use primeorder::{
+    dev::{AuditReport, TwistSecurity},
+    impl_domain_params_tests,
+};
+
+/// Expected report for both Brainpool curves.
+const REPORT: AuditReport = AuditReport {
+    twist_security: TwistSecurity::AtLeast {
+        bits: 16,
+        unfactored_bits: 172,
+    },
+    embedding_degree: None,
+    cm_discriminant: None,
+};
+
+mod r1 {
+    use super::*;
+    use bp256::BrainpoolP256r1;
+
+    impl_domain_params_tests!(BrainpoolP256r1, REPORT);
+}
+
+mod t1 {
+    use super::*;
+    use bp256::BrainpoolP256t1;
+
+    impl_domain_params_tests!(BrainpoolP256t1, REPORT);
+}
@@ -2,3 +2,9 @@
 
 pub(crate) mod field;
 pub(crate) mod scalar;
+
+#[cfg(all(
+    feature = "precomputed-tables",
+    not(any(feature = "critical-section", feature = "std"))
+))]
+compile_error!("`precomputed-tables` feature requires either `critical-section` or `std`");
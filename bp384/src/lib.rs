@@ -30,7 +30,10 @@ pub use crate::arithmetic::scalar::Scalar;
 #[cfg(feature = "pkcs8")]
 pub use elliptic_curve::pkcs8;
 
-use elliptic_curve::array::{typenum::U48, Array};
+use elliptic_curve::array::{
+    typenum::{U48, U96},
+    Array,
+};
 
 #[cfg(feature = "wip-arithmetic-do-not-use")]
 pub(crate) use crate::arithmetic::field::FieldElement;
@@ -38,5 +41,8 @@ pub(crate) use crate::arithmetic::field::FieldElement;
 /// Byte representation of a base/scalar field element of a given curve.
 pub type FieldBytes = Array<u8, U48>;
 
+/// Bytes used by a wide reduction: twice the width of [`FieldBytes`].
+pub type WideBytes = Array<u8, U96>;
+
 const ORDER_HEX: &str = "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b31f166e6cac0425a7cf3ab6af6b7fc3103b883202e9046565";
 const ORDER: U384 = U384::from_be_hex(ORDER_HEX);
@@ -6,6 +6,9 @@ pub mod ecdsa;
 #[cfg(feature = "wip-arithmetic-do-not-use")]
 mod arithmetic;
 
+#[cfg(feature = "hash2curve")]
+mod hash2curve;
+
 #[cfg(feature = "wip-arithmetic-do-not-use")]
 pub use {
     self::arithmetic::{AffinePoint, ProjectivePoint},
@@ -5,12 +5,19 @@ use crate::{FieldElement, Scalar};
 use elliptic_curve::{CurveArithmetic, PrimeCurveArithmetic};
 use primeorder::{point_arithmetic, PrimeCurveParams};
 
+#[cfg(feature = "precomputed-tables")]
+use {once_cell::sync::Lazy, primeorder::BasepointTable};
+
 /// Elliptic curve point in affine coordinates.
 pub type AffinePoint = primeorder::AffinePoint<BrainpoolP384r1>;
 
 /// Elliptic curve point in projective coordinates.
 pub type ProjectivePoint = primeorder::ProjectivePoint<BrainpoolP384r1>;
 
+/// Lazily computed basepoint table.
+#[cfg(feature = "precomputed-tables")]
+static BASEPOINT_TABLE: Lazy<BasepointTable<BrainpoolP384r1, 48>> = Lazy::new(BasepointTable::new);
+
 /// Primitive scalar type.
 pub type ScalarPrimitive = elliptic_curve::ScalarPrimitive<BrainpoolP384r1>;
 
@@ -36,6 +43,11 @@ impl PrimeCurveParams for BrainpoolP384r1 {
         FieldElement::from_hex("1d1c64f068cf45ffa2a63a81b7c13f6b8847a3e77ef14fe3db7fcafe0cbd10e8e826e03436d646aaef87b2e247d4af1e"),
         FieldElement::from_hex("8abe1d7520f9c2a45cb1eb8e95cfd55262b70b29feec5864e19c054ff99129280e4646217791811142820341263c5315"),
     );
+
+    #[cfg(feature = "precomputed-tables")]
+    fn mul_by_generator(k: &Scalar) -> ProjectivePoint {
+        BASEPOINT_TABLE.mul(k)
+    }
 }
 
 impl From<ScalarPrimitive> for Scalar {
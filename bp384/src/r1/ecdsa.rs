@@ -16,3 +16,46 @@ impl ecdsa::EcdsaCurve for BrainpoolP384r1 {
 impl ecdsa::hazmat::DigestPrimitive for BrainpoolP384r1 {
     type Digest = sha2::Sha384;
 }
+
+/// ECDSA/brainpoolP384r1 signing key with the countermeasures enabled by the
+/// `side-channel-hardening` and `fault-countermeasures` features, see
+/// [`primeorder::ecdsa`].
+#[cfg(all(
+    feature = "sha384",
+    feature = "wip-arithmetic-do-not-use",
+    any(feature = "fault-countermeasures", feature = "side-channel-hardening")
+))]
+pub type HardenedSigningKey = primeorder::ecdsa::HardenedSigningKey<BrainpoolP384r1>;
+
+/// ECDSA/brainpoolP384r1 verification key which verifies signatures in
+/// variable time, see [`primeorder::ecdsa::VartimeVerifyingKey`].
+#[cfg(feature = "wip-arithmetic-do-not-use")]
+pub type VartimeVerifyingKey = primeorder::ecdsa::VartimeVerifyingKey<BrainpoolP384r1>;
+
+#[cfg(all(test, feature = "sha384", feature = "wip-arithmetic-do-not-use"))]
+mod tests {
+    primeorder::impl_vartime_verifying_key_tests!(super::BrainpoolP384r1);
+
+    #[cfg(any(feature = "fault-countermeasures", feature = "side-channel-hardening"))]
+    #[test]
+    fn hardened_signing_key() {
+        use super::{BrainpoolP384r1, HardenedSigningKey, Signature};
+        use ecdsa::{
+            signature::{RandomizedSigner, Signer, Verifier},
+            SigningKey,
+        };
+        use elliptic_curve::{NonZeroScalar, Scalar};
+        use rand_core::OsRng;
+
+        let msg = b"example";
+        let d = NonZeroScalar::new(Scalar::<BrainpoolP384r1>::from(42u64)).unwrap();
+        let signing_key = SigningKey::<BrainpoolP384r1>::from(d);
+        let hardened = HardenedSigningKey::from(signing_key.clone());
+
+        let signature: Signature = hardened.sign(msg);
+        assert_eq!(signature, signing_key.sign(msg));
+
+        let signature: Signature = hardened.sign_with_rng(&mut OsRng, msg);
+        assert!(signing_key.verifying_key().verify(msg, &signature).is_ok());
+    }
+}
@@ -0,0 +1,24 @@
+//! Hashing to brainpoolP384r1 using the simplified SWU map.
+
+use super::BrainpoolP384r1;
+use crate::{FieldElement, Scalar};
+use elliptic_curve::consts::U72;
+use primeorder::hash2curve::SswuParams;
+
+impl SswuParams for BrainpoolP384r1 {
+    /// Z = -5
+    const Z: FieldElement = FieldElement::from_u64(5).neg();
+
+    const SUITE_ID_RO: &'static str = "brainpoolP384r1_XMD:SHA-384_SSWU_RO_";
+    const SUITE_ID_NU: &'static str = "brainpoolP384r1_XMD:SHA-384_SSWU_NU_";
+}
+
+primeorder::impl_hash2curve!(BrainpoolP384r1, FieldElement, Scalar, U72);
+
+#[cfg(test)]
+mod tests {
+    use super::super::{AffinePoint, BrainpoolP384r1};
+    use super::{FieldElement, Scalar};
+
+    primeorder::impl_hash2curve_tests!(BrainpoolP384r1, AffinePoint, FieldElement, Scalar);
+}
@@ -5,12 +5,19 @@ use crate::{FieldElement, Scalar};
 use elliptic_curve::{CurveArithmetic, PrimeCurveArithmetic};
 use primeorder::{point_arithmetic, PrimeCurveParams};
 
+#[cfg(feature = "precomputed-tables")]
+use {once_cell::sync::Lazy, primeorder::BasepointTable};
+
 /// Elliptic curve point in affine coordinates.
 pub type AffinePoint = primeorder::AffinePoint<BrainpoolP384t1>;
 
 /// Elliptic curve point in projective coordinates.
 pub type ProjectivePoint = primeorder::ProjectivePoint<BrainpoolP384t1>;
 
+/// Lazily computed basepoint table.
+#[cfg(feature = "precomputed-tables")]
+static BASEPOINT_TABLE: Lazy<BasepointTable<BrainpoolP384t1, 48>> = Lazy::new(BasepointTable::new);
+
 /// Primitive scalar type.
 pub type ScalarPrimitive = elliptic_curve::ScalarPrimitive<BrainpoolP384t1>;
 
@@ -36,6 +43,11 @@ impl PrimeCurveParams for BrainpoolP384t1 {
         FieldElement::from_hex("18de98b02db9a306f2afcd7235f72a819b80ab12ebd653172476fecd462aabffc4ff191b946a5f54d8d0aa2f418808cc"),
         FieldElement::from_hex("25ab056962d30651a114afd2755ad336747f93475b7a1fca3b88f2b6a208ccfe469408584dc2b2912675bf5b9e582928"),
     );
+
+    #[cfg(feature = "precomputed-tables")]
+    fn mul_by_generator(k: &Scalar) -> ProjectivePoint {
+        BASEPOINT_TABLE.mul(k)
+    }
 }
 
 impl From<ScalarPrimitive> for Scalar {
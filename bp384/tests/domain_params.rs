@@ -0,0 +1,32 @@
+//! Domain parameter audit.
+
+#![cfg(feature = "wip-arithmetic-do-not-use")]
+
+use primeorder::{
+    dev::{AuditReport, TwistSecurity},
+    impl_domain_params_tests,
+};
+
+/// Expected report for both Brainpool curves.
+const REPORT: AuditReport = AuditReport {
+    twist_security: TwistSecurity::AtLeast {
+        bits: 8,
+        unfactored_bits: 354,
+    },
+    embedding_degree: None,
+    cm_discriminant: None,
+};
+
+mod r1 {
+    use super::*;
+    use bp384::BrainpoolP384r1;
+
+    impl_domain_params_tests!(BrainpoolP384r1, REPORT);
+}
+
+mod t1 {
+    use super::*;
+    use bp384::BrainpoolP384t1;
+
+    impl_domain_params_tests!(BrainpoolP384t1, REPORT);
+}
@@ -0,0 +1,48 @@
+//! Tests for keys which carry explicit domain parameters (`specifiedCurve`).
+
+#![cfg(all(feature = "pkcs8", feature = "wip-arithmetic-do-not-use"))]
+
+use bp384::{
+    elliptic_curve::{sec1::ToEncodedPoint, PublicKey},
+    r1::SecretKey,
+};
+use hex_literal::hex;
+use primeorder::specified_curve::{DecodeSpecifiedPrivateKey, DecodeSpecifiedPublicKey};
+
+/// DER-encoded PKCS#8 private key with explicit parameters.
+///
+/// Generated via `openssl ecparam -name brainpoolP384r1 -genkey -param_enc explicit`.
+const PKCS8_PRIVATE_KEY_DER: &[u8; 505] = include_bytes!("examples/pkcs8-private-key-explicit.der");
+
+/// DER-encoded SPKI public key with explicit parameters.
+const PKCS8_PUBLIC_KEY_DER: &[u8; 441] = include_bytes!("examples/pkcs8-public-key-explicit.der");
+
+/// Offset of the last byte of the `b` coefficient in [`PKCS8_PUBLIC_KEY_DER`].
+const EQUATION_B_OFFSET: usize = 187;
+
+/// Offset of the last byte of the `order` in [`PKCS8_PUBLIC_KEY_DER`].
+const ORDER_OFFSET: usize = 337;
+
+#[test]
+fn decode_keys_with_explicit_params() {
+    let secret_key = SecretKey::from_specified_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+    let public_key =
+        PublicKey::<bp384::BrainpoolP384r1>::from_specified_public_key_der(PKCS8_PUBLIC_KEY_DER)
+            .unwrap();
+
+    let expected_scalar = hex!("13f5be9e2ae5b1db38795efada9f84834e29d3c87460610147ca29e73583050f19f332406f87b5225f811b9c16689ff0");
+    assert_eq!(secret_key.to_bytes().as_slice(), &expected_scalar[..]);
+    assert_eq!(
+        secret_key.public_key().to_encoded_point(false),
+        public_key.to_encoded_point(false)
+    );
+}
+
+#[test]
+fn reject_mismatched_params() {
+    for offset in [EQUATION_B_OFFSET, ORDER_OFFSET] {
+        let mut der = *PKCS8_PUBLIC_KEY_DER;
+        der[offset] ^= 1;
+        assert!(PublicKey::<bp384::BrainpoolP384r1>::from_specified_public_key_der(&der).is_err());
+    }
+}
@@ -14,6 +14,9 @@ use elliptic_curve::{
     Error, Result,
 };
 
+#[cfg(feature = "alloc")]
+use {alloc::vec::Vec, elliptic_curve::ops::BatchInvert};
+
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
 
@@ -80,6 +83,73 @@ impl AffinePoint {
     pub(crate) const fn new(x: FieldElement, y: FieldElement) -> Self {
         Self { x, y, infinity: 0 }
     }
+
+    /// Computes `lhs[i] + rhs[i]` for every `i`, sharing a single field
+    /// inversion between all of the additions (Montgomery's trick).
+    ///
+    /// This is considerably cheaper than adding the points in projective
+    /// coordinates and normalizing the results when affine outputs are needed.
+    /// Doublings, additions of the identity and of a point and its negation
+    /// are all handled in constant time.
+    ///
+    /// # Panics
+    ///
+    /// If `lhs` and `rhs` have different lengths.
+    #[cfg(feature = "alloc")]
+    pub fn batch_add(lhs: &[Self], rhs: &[Self]) -> Vec<Self> {
+        assert_eq!(lhs.len(), rhs.len(), "length mismatch");
+
+        // Batch inversion fails for an empty batch
+        if lhs.is_empty() {
+            return Vec::new();
+        }
+
+        let mut nums = Vec::with_capacity(lhs.len());
+        let mut dens = Vec::with_capacity(lhs.len());
+
+        for (p, q) in lhs.iter().zip(rhs) {
+            let dx = q.x + p.x.negate(1);
+            let dy = q.y + p.y.negate(1);
+            let doubling = dx.normalizes_to_zero() & dy.normalizes_to_zero();
+
+            // Slope of the tangent `3x² / 2y` when doubling (a = 0), and of
+            // the chord `(y2 - y1) / (x2 - x1)` otherwise
+            let num = FieldElement::conditional_select(&dy, &p.x.square().mul_single(3), doubling);
+            let den = FieldElement::conditional_select(&dx, &p.y.double(), doubling);
+
+            // Even a single zero value will fail inversion for the entire
+            // batch. Such additions result in the identity or one of the
+            // inputs, which is selected below.
+            nums.push(num);
+            dens.push(FieldElement::conditional_select(
+                &den.normalize_weak(),
+                &FieldElement::ONE,
+                den.normalizes_to_zero(),
+            ));
+        }
+
+        // This is safe to unwrap since we assured that all elements are non-zero
+        let invs = <FieldElement as BatchInvert<[_]>>::batch_invert(&dens).unwrap();
+
+        lhs.iter()
+            .zip(rhs)
+            .zip(nums.iter().zip(invs.iter()))
+            .map(|((p, q), (num, inv))| {
+                let lambda = num * inv;
+                let x = lambda.square() + p.x.negate(1) + q.x.negate(1);
+                let y = lambda * (p.x + x.negate(5)) + p.y.negate(1);
+
+                let same_x = (q.x + p.x.negate(1)).normalizes_to_zero();
+                let same_y = (q.y + p.y.negate(1)).normalizes_to_zero();
+
+                let mut sum = Self::new(x.normalize(), y.normalize());
+                sum.conditional_assign(&Self::IDENTITY, same_x & !same_y);
+                sum.conditional_assign(q, p.is_identity());
+                sum.conditional_assign(p, q.is_identity());
+                sum
+            })
+            .collect()
+    }
 }
 
 impl PrimeCurveAffine for AffinePoint {
@@ -378,6 +448,32 @@ mod tests {
     const COMPRESSED_BASEPOINT: &[u8] =
         &hex!("0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798");
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_add() {
+        use crate::{ProjectivePoint, Scalar};
+        use alloc::vec::Vec;
+
+        let points = (1..6u64)
+            .map(|i| (ProjectivePoint::GENERATOR * Scalar::from(i)).to_affine())
+            .chain([AffinePoint::IDENTITY, -AffinePoint::GENERATOR])
+            .collect::<Vec<_>>();
+
+        let (lhs, rhs): (Vec<_>, Vec<_>) = points
+            .iter()
+            .flat_map(|p| points.iter().map(move |q| (*p, *q)))
+            .unzip();
+
+        let sums = AffinePoint::batch_add(&lhs, &rhs);
+        assert_eq!(sums.len(), lhs.len());
+
+        for ((p, q), sum) in lhs.iter().zip(&rhs).zip(&sums) {
+            assert_eq!(*sum, (ProjectivePoint::from(*p) + q).to_affine());
+        }
+
+        assert!(AffinePoint::batch_add(&[], &[]).is_empty());
+    }
+
     #[test]
     fn uncompressed_round_trip() {
         let pubkey = EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap();
@@ -14,6 +14,14 @@ cfg_if! {
     }
 }
 
+#[cfg(all(test, target_pointer_width = "64"))]
+#[allow(dead_code)]
+mod field_10x26;
+
+#[cfg(all(test, target_pointer_width = "32"))]
+#[allow(dead_code)]
+mod field_5x52;
+
 cfg_if! {
     if #[cfg(debug_assertions)] {
         mod field_impl;
@@ -31,13 +39,14 @@ cfg_if! {
     }
 }
 
-use crate::FieldBytes;
+use crate::{FieldBytes, U256};
 use core::{
     iter::{Product, Sum},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 use elliptic_curve::{
-    ff::{self, Field, PrimeField},
+    bigint::{ArrayEncoding, Uint},
+    ff::{Field, PrimeField},
     ops::Invert,
     rand_core::RngCore,
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
@@ -51,6 +60,11 @@ use num_bigint::{BigUint, ToBigUint};
 #[derive(Clone, Copy, Debug)]
 pub struct FieldElement(FieldElementImpl);
 
+primefield::impl_batch_invert!(FieldElement);
+
+/// Field modulus as an integer.
+const MODULUS: U256 = U256::from_be_hex(<FieldElement as PrimeField>::MODULUS);
+
 impl FieldElement {
     /// Zero element.
     pub const ZERO: Self = Self(FieldElementImpl::ZERO);
@@ -201,6 +215,20 @@ impl FieldElement {
         CtOption::new(res, !self.normalizes_to_zero())
     }
 
+    /// Returns the multiplicative inverse of self in variable time, if self is
+    /// non-zero. The result is normalized.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This method should not be used with secret values, as its variable-time
+    /// operation can potentially leak secrets through sidechannels.
+    pub fn invert_vartime(&self) -> CtOption<Self> {
+        match primefield::invert_vartime(&U256::from_be_slice(&self.to_bytes()), &MODULUS) {
+            Some(inverse) => Self::from_bytes(&inverse.to_be_byte_array()),
+            None => CtOption::new(Self::ZERO, Choice::from(0)),
+        }
+    }
+
     /// Returns the square root of self mod p, or `None` if no square root exists.
     /// The result has magnitude 1, but is not normalized.
     pub fn sqrt(&self) -> CtOption<Self> {
@@ -240,6 +268,27 @@ impl FieldElement {
         CtOption::new(res, is_root)
     }
 
+    /// Compute the Legendre symbol of `self`.
+    ///
+    /// Returns `1` if `self` is a nonzero square, `-1` if it's a non-square,
+    /// and `0` if it's zero.
+    pub fn legendre(&self) -> i8 {
+        primefield::legendre(self, &MODULUS)
+    }
+
+    /// Is `self` a square (including zero)?
+    pub fn is_square(&self) -> Choice {
+        primefield::is_square(self, &MODULUS)
+    }
+
+    /// Raises self to the power `exp` in constant time, using a fixed window.
+    /// Unlike `pow_vartime`, this may be used with secret exponents.
+    /// The result has magnitude 1, but is not normalized.
+    pub fn pow_uint<const LIMBS: usize>(&self, exp: &Uint<LIMBS>) -> Self {
+        primefield::pow(self, exp)
+    }
+
+    /// Returns the field modulus as a [`BigUint`].
     #[cfg(test)]
     pub fn modulus_as_biguint() -> BigUint {
         Self::ONE.negate(1).to_biguint().unwrap() + 1.to_biguint().unwrap()
@@ -252,6 +301,10 @@ impl Invert for FieldElement {
     fn invert(&self) -> CtOption<Self> {
         self.invert()
     }
+
+    fn invert_vartime(&self) -> CtOption<Self> {
+        self.invert_vartime()
+    }
 }
 
 impl Field for FieldElement {
@@ -288,7 +341,7 @@ impl Field for FieldElement {
     }
 
     fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
-        ff::helpers::sqrt_ratio_generic(num, div)
+        primefield::sqrt_ratio(num, div, &MODULUS)
     }
 }
 
@@ -513,14 +566,15 @@ mod tests {
     use elliptic_curve::ff::{Field, PrimeField};
     use elliptic_curve::ops::BatchInvert;
     use num_bigint::{BigUint, ToBigUint};
+    use primefield::{impl_field_differential_tests, impl_field_sqrt_differential_tests};
     use proptest::prelude::*;
     use rand_core::OsRng;
 
-    use super::FieldElement;
+    use super::{field_10x26::FieldElement10x26, field_5x52::FieldElement5x52, FieldElement};
     use crate::{
         arithmetic::dev::{biguint_to_bytes, bytes_to_biguint},
         test_vectors::field::DBL_TEST_VECTORS,
-        FieldBytes,
+        FieldBytes, U256,
     };
 
     #[cfg(feature = "alloc")]
@@ -547,6 +601,9 @@ mod tests {
         0x7fffffffffffffff,
     ];
 
+    impl_field_differential_tests!(FieldElement);
+    impl_field_sqrt_differential_tests!(FieldElement);
+
     #[test]
     fn two_inv_constant() {
         assert_eq!(
@@ -712,6 +769,26 @@ mod tests {
         assert_eq!(res, expected);
     }
 
+    #[test]
+    fn batch_invert_in_place() {
+        let k: FieldElement = FieldElement::random(&mut OsRng);
+        let l: FieldElement = FieldElement::random(&mut OsRng);
+
+        let expected = [k.invert().unwrap(), FieldElement::ZERO, l.invert().unwrap()];
+        let mut field_elements = [k, FieldElement::ZERO, l];
+        assert!(!bool::from(FieldElement::batch_invert_array(
+            &mut field_elements
+        )));
+        assert_eq!(field_elements, expected);
+
+        #[cfg(feature = "alloc")]
+        {
+            let mut field_elements = vec![k, l];
+            assert!(bool::from(FieldElement::batch_invert(&mut field_elements)));
+            assert_eq!(field_elements, [expected[0], expected[2]]);
+        }
+    }
+
     #[test]
     fn sqrt() {
         let one = FieldElement::ONE;
@@ -811,6 +888,36 @@ mod tests {
             assert_eq!(res_test, res_ref);
         }
 
+        #[test]
+        fn fuzzy_limb_backends(
+            a in field_element(),
+            b in field_element()
+        ) {
+            let (a_bytes, b_bytes) = (a.to_bytes(), b.to_bytes());
+            let (a26, b26) = (
+                FieldElement10x26::from_bytes(&a_bytes).unwrap(),
+                FieldElement10x26::from_bytes(&b_bytes).unwrap(),
+            );
+            let (a52, b52) = (
+                FieldElement5x52::from_bytes(&a_bytes).unwrap(),
+                FieldElement5x52::from_bytes(&b_bytes).unwrap(),
+            );
+
+            let results = [
+                (a26.add(&b26), a52.add(&b52), a + b),
+                (a26.mul(&b26), a52.mul(&b52), a * b),
+                (a26.square(), a52.square(), a.square()),
+                (a26.negate(1), a52.negate(1), a.negate(1)),
+                (a26.mul_single(3), a52.mul_single(3), a.mul_single(3)),
+            ];
+
+            for (r26, r52, expected) in results {
+                let expected = expected.normalize().to_bytes();
+                assert_eq!(r26.normalize().to_bytes(), expected);
+                assert_eq!(r52.normalize().to_bytes(), expected);
+            }
+        }
+
         #[test]
         fn fuzzy_sqrt(
             a in field_element()
@@ -839,5 +946,66 @@ mod tests {
             let m = FieldElement::modulus_as_biguint();
             assert_eq!((&inv_bi * &a_bi) % &m, 1.to_biguint().unwrap());
         }
+
+        #[test]
+        fn fuzzy_invert_vartime(
+            a in field_element()
+        ) {
+            let inv: Option<FieldElement> = a.invert().map(|inv| inv.normalize()).into();
+            let inv_vartime: Option<FieldElement> = a.invert_vartime().into();
+            assert_eq!(inv, inv_vartime);
+        }
+
+        #[test]
+        fn fuzzy_legendre(
+            a in field_element()
+        ) {
+            let m = FieldElement::modulus_as_biguint();
+            let a_bi = a.to_biguint().unwrap();
+            let euler = a_bi.modpow(&((&m - 1u32) >> 1), &m);
+            let expected = if euler == 0u32.into() {
+                0
+            } else if euler == 1u32.into() {
+                1
+            } else {
+                -1
+            };
+            assert_eq!(a.legendre(), expected);
+            assert_eq!(bool::from(a.is_square()), expected >= 0);
+        }
+
+        #[test]
+        fn fuzzy_pow(
+            a in field_element(),
+            e in any::<[u8; 32]>()
+        ) {
+            let m = FieldElement::modulus_as_biguint();
+            let expected = a.to_biguint().unwrap().modpow(&bytes_to_biguint(&e), &m);
+            let actual = a.pow_uint(&U256::from_be_slice(&e));
+            assert_eq!(actual.to_biguint().unwrap(), expected);
+        }
+
+        #[test]
+        fn fuzzy_sqrt_ratio(
+            num in field_element(),
+            div in field_element()
+        ) {
+            let (is_square, root) = FieldElement::sqrt_ratio(&num, &div);
+
+            match Option::<FieldElement>::from(div.invert()) {
+                Some(div_inv) => {
+                    let ratio = (num * div_inv).normalize();
+                    assert_eq!(bool::from(is_square), bool::from(ratio.is_square()));
+
+                    let expected = if bool::from(is_square) {
+                        ratio
+                    } else {
+                        (ratio * FieldElement::ROOT_OF_UNITY).normalize()
+                    };
+                    assert_eq!(root.square().normalize(), expected);
+                }
+                None => assert_eq!(bool::from(is_square), bool::from(num.is_zero())),
+            }
+        }
     }
 }
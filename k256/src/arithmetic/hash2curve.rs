@@ -289,7 +289,6 @@ mod tests {
     #[test]
     fn hash_to_curve() {
         use elliptic_curve::hash2curve::{self, ExpandMsgXmd};
-        use hex_literal::hex;
         use sha2::Sha256;
 
         struct TestVector {
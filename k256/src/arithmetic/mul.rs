@@ -55,6 +55,22 @@ use elliptic_curve::{
 #[cfg(feature = "precomputed-tables")]
 use once_cell::sync::Lazy;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(any(feature = "alloc", feature = "side-channel-hardening"))]
+use elliptic_curve::bigint::U256;
+
+#[cfg(feature = "side-channel-hardening")]
+use {
+    crate::{arithmetic::FieldElement, ORDER},
+    elliptic_curve::{
+        bigint::{ArrayEncoding, U384},
+        rand_core::CryptoRngCore,
+        Field,
+    },
+};
+
 /// Lookup table containing precomputed values `[p, 2p, 3p, ..., 8p]`
 #[derive(Copy, Clone, Default)]
 struct LookupTable([ProjectivePoint; 8]);
@@ -249,17 +265,23 @@ impl<const D: usize> Radix16Decomposition<D> {
     /// Assumes `x < 2^(4*(D-1))`.
     fn new(x: &Scalar) -> Self {
         debug_assert!((x >> (4 * (D - 1))).is_zero().unwrap_u8() == 1);
+        Self::from_be_bytes(&x.to_bytes())
+    }
 
+    /// Same as [`Radix16Decomposition::new`], for an integer given by its
+    /// big-endian encoding.
+    /// Assumes the integer is less than `2^(4*(D-1))`.
+    fn from_be_bytes(bytes: &[u8]) -> Self {
         // The resulting decomposition can be negative, so, despite the limit on `x`,
         // we need an additional byte to store the carry.
         let mut output = [0i8; D];
 
         // Step 1: change radix.
         // Convert from radix 256 (bytes) to radix 16 (nibbles)
-        let bytes = x.to_bytes();
         for i in 0..(D - 1) / 2 {
-            output[2 * i] = (bytes[31 - i] & 0xf) as i8;
-            output[2 * i + 1] = ((bytes[31 - i] >> 4) & 0xf) as i8;
+            let byte = bytes[bytes.len() - 1 - i];
+            output[2 * i] = (byte & 0xf) as i8;
+            output[2 * i + 1] = ((byte >> 4) & 0xf) as i8;
         }
 
         // Step 2: recenter coefficients from [0,16) to [-8,8)
@@ -361,26 +383,91 @@ fn lincomb(
     acc
 }
 
-/// Lazily computed basepoint table.
-#[cfg(feature = "precomputed-tables")]
-static GEN_LOOKUP_TABLE: Lazy<[LookupTable; 33]> = Lazy::new(precompute_gen_lookup_table);
+/// Precomputed table of multiples of a fixed point, used to accelerate
+/// repeated scalar multiplication of that point.
+///
+/// Building the table is considerably more expensive than a single scalar
+/// multiplication, but pays off when the same point is multiplied many times,
+/// e.g. when verifying many signatures under the same public key, or when
+/// committing with fixed Pedersen generators.
+#[derive(Clone)]
+pub struct FixedBaseTable([LookupTable; 33]);
+
+impl FixedBaseTable {
+    /// Compute the table for the given point.
+    pub fn new(point: &ProjectivePoint) -> Self {
+        let mut p = *point;
+        let mut res = [LookupTable::default(); 33];
+
+        for i in 0..33 {
+            res[i] = LookupTable::from(&p);
+            // We are storing tables spaced by two radix steps,
+            // to decrease the size of the precomputed data.
+            for _ in 0..8 {
+                p = p.double();
+            }
+        }
 
-#[cfg(feature = "precomputed-tables")]
-fn precompute_gen_lookup_table() -> [LookupTable; 33] {
-    let mut gen = ProjectivePoint::GENERATOR;
-    let mut res = [LookupTable::default(); 33];
-
-    for i in 0..33 {
-        res[i] = LookupTable::from(&gen);
-        // We are storing tables spaced by two radix steps,
-        // to decrease the size of the precomputed data.
-        for _ in 0..8 {
-            gen = gen.double();
+        Self(res)
+    }
+
+    /// Calculates `k * P` in constant time, where `P` is the point the table
+    /// was computed for.
+    pub fn mul(&self, k: &Scalar) -> ProjectivePoint {
+        Self::lincomb(&[(self, *k)])
+    }
+
+    /// Calculates `k * P` in constant time, where `P` is the point the table
+    /// was computed for, randomising the computation on every call.
+    ///
+    /// The entries of the table are fixed, so unlike
+    /// [`ProjectivePoint::mul_blinded`] neither the scalar nor the coordinates
+    /// can be blinded. Instead, `k` is split into `(k - m) + m` for a random
+    /// scalar `m`, so the table entries which are selected differ between
+    /// calls.
+    #[cfg(feature = "side-channel-hardening")]
+    pub fn mul_blinded(&self, k: &Scalar, rng: &mut impl CryptoRngCore) -> ProjectivePoint {
+        let m = Scalar::random(rng);
+        Self::lincomb(&[(self, *k - m), (self, m)])
+    }
+
+    /// Calculates `k1 * P1 + ... + kn * Pn` in constant time, where `Pi` is
+    /// the point the `i`-th table was computed for.
+    pub fn lincomb(terms: &[(&Self, Scalar)]) -> ProjectivePoint {
+        let mut acc = ProjectivePoint::IDENTITY;
+        let mut acc2 = ProjectivePoint::IDENTITY;
+
+        for (table, k) in terms {
+            let digits = Radix16Decomposition::<65>::new(k);
+            let table = &table.0;
+
+            acc += &table[32].select(digits.0[64]);
+            for i in (0..32).rev() {
+                acc2 += &table[i].select(digits.0[i * 2 + 1]);
+                acc += &table[i].select(digits.0[i * 2]);
+            }
         }
+
+        // This is the price of halving the precomputed table size (from 60kb to 30kb)
+        // The performance hit is minor, about 3%.
+        for _ in 0..4 {
+            acc2 = acc2.double();
+        }
+        acc + acc2
+    }
+}
+
+impl From<&ProjectivePoint> for FixedBaseTable {
+    fn from(point: &ProjectivePoint) -> Self {
+        Self::new(point)
     }
-    res
 }
 
+/// Lazily computed basepoint table.
+#[cfg(feature = "precomputed-tables")]
+static GEN_LOOKUP_TABLE: Lazy<FixedBaseTable> =
+    Lazy::new(|| FixedBaseTable::new(&ProjectivePoint::GENERATOR));
+
 impl MulByGenerator for ProjectivePoint {
     /// Calculates `k * G`, where `G` is the generator.
     #[cfg(not(feature = "precomputed-tables"))]
@@ -391,26 +478,124 @@ impl MulByGenerator for ProjectivePoint {
     /// Calculates `k * G`, where `G` is the generator.
     #[cfg(feature = "precomputed-tables")]
     fn mul_by_generator(k: &Scalar) -> ProjectivePoint {
-        let digits = Radix16Decomposition::<65>::new(k);
-        let table = *GEN_LOOKUP_TABLE;
-        let mut acc = table[32].select(digits.0[64]);
-        let mut acc2 = ProjectivePoint::IDENTITY;
-        for i in (0..32).rev() {
-            acc2 += &table[i].select(digits.0[i * 2 + 1]);
-            acc += &table[i].select(digits.0[i * 2]);
+        GEN_LOOKUP_TABLE.mul(k).fault_check()
+    }
+}
+
+impl ProjectivePoint {
+    /// Computes the multi-scalar multiplication `x1 * k1 + ... + xn * kn`
+    /// in constant time.
+    ///
+    /// This is equivalent to [`LinearCombination`]: with the endomorphism,
+    /// each term costs fewer table lookups with interleaved multiplication
+    /// than with the constant-time bucket method, for any number of terms.
+    /// It is provided for parity with the other curve crates.
+    ///
+    /// # Panics
+    ///
+    /// If `points` and `scalars` have different lengths.
+    #[cfg(feature = "alloc")]
+    pub fn msm(points: &[Self], scalars: &[Scalar]) -> Self {
+        assert_eq!(points.len(), scalars.len(), "length mismatch");
+        Self::lincomb(terms(points, scalars).as_slice())
+    }
+
+    /// Computes the multi-scalar multiplication `x1 * k1 + ... + xn * kn`
+    /// using the bucket method (a.k.a. Pippenger's algorithm) on the
+    /// endomorphism-decomposed scalars.
+    ///
+    /// Fewer than [`primeorder::pippenger::MIN_TERMS`] terms are computed
+    /// with [`LinearCombination`] instead.
+    ///
+    /// <div class="warning">
+    /// This operation is variable time with respect to both the points and
+    /// the scalars. It must only be used with public inputs.
+    /// </div>
+    ///
+    /// # Panics
+    ///
+    /// If `points` and `scalars` have different lengths.
+    #[cfg(feature = "alloc")]
+    pub fn msm_vartime(points: &[Self], scalars: &[Scalar]) -> Self {
+        assert_eq!(points.len(), scalars.len(), "length mismatch");
+
+        if points.len() < primeorder::pippenger::MIN_TERMS {
+            return Self::lincomb(terms(points, scalars).as_slice());
         }
-        // This is the price of halving the precomputed table size (from 60kb to 30kb)
-        // The performance hit is minor, about 3%.
-        for _ in 0..4 {
-            acc2 = acc2.double();
+
+        let (points, scalars) = glv_terms(points, scalars);
+        primeorder::pippenger::msm_vartime(&points, &scalars, 128)
+    }
+}
+
+/// Pair up points and scalars for [`LinearCombination`].
+#[cfg(feature = "alloc")]
+fn terms(points: &[ProjectivePoint], scalars: &[Scalar]) -> Vec<(ProjectivePoint, Scalar)> {
+    points
+        .iter()
+        .copied()
+        .zip(scalars.iter().copied())
+        .collect()
+}
+
+/// Split every term `k * x` of a multi-scalar multiplication into
+/// `r1 * x + r2 * (lambda * x)` with `r1, r2 < 2^128`, negating the points
+/// where needed. This operation is constant time.
+#[cfg(feature = "alloc")]
+fn glv_terms(points: &[ProjectivePoint], scalars: &[Scalar]) -> (Vec<ProjectivePoint>, Vec<U256>) {
+    let mut xs = Vec::with_capacity(2 * points.len());
+    let mut ks = Vec::with_capacity(2 * points.len());
+
+    for (x, k) in points.iter().zip(scalars) {
+        let (r1, r2) = decompose_scalar(k);
+
+        for (x, r) in [(*x, r1), (x.endomorphism(), r2)] {
+            let neg = r.is_high();
+            xs.push(ProjectivePoint::conditional_select(&x, &-x, neg));
+            ks.push(U256::from(Scalar::conditional_select(&r, &-r, neg)));
         }
-        acc + acc2
     }
+
+    (xs, ks)
 }
 
 #[inline(always)]
 fn mul(x: &ProjectivePoint, k: &Scalar) -> ProjectivePoint {
-    ProjectivePoint::lincomb(&[(*x, *k)])
+    ProjectivePoint::lincomb(&[(*x, *k)]).fault_check()
+}
+
+impl ProjectivePoint {
+    /// Calculates `k * self` in constant time, randomising the computation on
+    /// every call using `rng` to protect `k` against differential power
+    /// analysis and template attacks.
+    ///
+    /// The scalar is replaced by `k + r * n`, where `n` is the group order and
+    /// `r` is a random 64-bit integer, and the projective coordinates of
+    /// `self` are multiplied by a random nonzero field element. Since the
+    /// blinded scalar is no longer reduced, the endomorphism is not used.
+    #[cfg(feature = "side-channel-hardening")]
+    pub fn mul_blinded(&self, k: &Scalar, rng: &mut impl CryptoRngCore) -> Self {
+        // k + r * n < 2^320, which takes 80 digits plus one for the carry
+        let r = U384::from_u64(rng.next_u64());
+        let k = U256::from(k).resize::<{ U384::LIMBS }>();
+        let blinded = k.wrapping_add(&r.wrapping_mul(&ORDER.resize::<{ U384::LIMBS }>()));
+        let digits = Radix16Decomposition::<81>::from_be_bytes(&blinded.to_be_byte_array());
+
+        let lambda = FieldElement::random(rng);
+        let lambda =
+            FieldElement::conditional_select(&lambda, &FieldElement::ONE, lambda.is_zero());
+        let table = LookupTable::from(&self.rescale(&lambda));
+
+        let mut acc = table.select(digits.0[80]);
+        for i in (0..80).rev() {
+            for _j in 0..4 {
+                acc = acc.double();
+            }
+
+            acc += &table.select(digits.0[i]);
+        }
+        acc.fault_check()
+    }
 }
 
 impl Mul<Scalar> for ProjectivePoint {
@@ -475,6 +660,63 @@ mod tests {
         assert_eq!(reference, test);
     }
 
+    #[cfg(feature = "side-channel-hardening")]
+    #[test]
+    fn test_mul_blinded() {
+        let x = ProjectivePoint::random(&mut OsRng);
+        let k = Scalar::random(&mut OsRng);
+
+        let table = FixedBaseTable::new(&x);
+
+        for k in [k, Scalar::ZERO, Scalar::ONE, -Scalar::ONE] {
+            assert_eq!(x.mul_blinded(&k, &mut OsRng), x * k);
+            assert_eq!(table.mul_blinded(&k, &mut OsRng), x * k);
+        }
+
+        assert_eq!(
+            ProjectivePoint::IDENTITY.mul_blinded(&k, &mut OsRng),
+            ProjectivePoint::IDENTITY
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_msm() {
+        for n in [0, 1, 2, 3, 40] {
+            let points = (0..n)
+                .map(|_| ProjectivePoint::random(&mut OsRng))
+                .collect::<Vec<_>>();
+            let scalars = (0..n)
+                .map(|_| Scalar::random(&mut OsRng))
+                .collect::<Vec<_>>();
+
+            let reference = points
+                .iter()
+                .zip(&scalars)
+                .map(|(x, k)| x * k)
+                .sum::<ProjectivePoint>();
+
+            assert_eq!(ProjectivePoint::msm(&points, &scalars), reference);
+            assert_eq!(ProjectivePoint::msm_vartime(&points, &scalars), reference);
+        }
+    }
+
+    #[test]
+    fn test_fixed_base_table() {
+        let x = ProjectivePoint::random(&mut OsRng);
+        let y = ProjectivePoint::random(&mut OsRng);
+        let k = Scalar::random(&mut OsRng);
+        let l = Scalar::random(&mut OsRng);
+
+        let (tx, ty) = (FixedBaseTable::new(&x), FixedBaseTable::new(&y));
+        assert_eq!(tx.mul(&k), x * k);
+        assert_eq!(tx.mul(&-k), x * -k);
+        assert_eq!(
+            FixedBaseTable::lincomb(&[(&tx, k), (&ty, l)]),
+            x * k + y * l
+        );
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn test_lincomb_slice() {
@@ -226,6 +226,41 @@ impl ProjectivePoint {
         }
     }
 
+    /// Returns a different representation of the same point, with all of its
+    /// coordinates multiplied by `lambda`, which must be nonzero.
+    #[cfg(feature = "side-channel-hardening")]
+    pub(super) fn rescale(&self, lambda: &FieldElement) -> Self {
+        Self {
+            x: self.x * lambda,
+            y: self.y * lambda,
+            z: self.z * lambda,
+        }
+    }
+
+    /// Returns `self`, or the identity if `self` is not on the curve.
+    ///
+    /// With the `fault-countermeasures` feature, this is applied to the result
+    /// of every multiplication by a secret scalar, so that a point corrupted
+    /// by an injected fault is never released. The identity is rejected by
+    /// `ecdh::diffie_hellman` and the signature
+    /// schemes. Without the feature, this is a no-op.
+    #[inline(always)]
+    pub(super) fn fault_check(self) -> Self {
+        #[cfg(feature = "fault-countermeasures")]
+        {
+            // Y²Z = X³ + bZ³
+            let lhs = self.y.square() * &self.z;
+            let rhs = self.x.square() * &self.x
+                + &(self.z.square() * &self.z).mul_single(CURVE_EQUATION_B_SINGLE);
+            let is_on_curve = self.z.normalizes_to_zero() | lhs.normalize().ct_eq(&rhs.normalize());
+
+            Self::conditional_select(&Self::IDENTITY, &self, is_on_curve)
+        }
+
+        #[cfg(not(feature = "fault-countermeasures"))]
+        self
+    }
+
     /// Check whether `self` is equal to an affine point.
     ///
     /// This is a lot faster than first converting `self` to an `AffinePoint` and then doing the
@@ -924,4 +959,23 @@ mod tests {
             AffinePoint::GENERATOR.neg()
         );
     }
+
+    #[cfg(feature = "fault-countermeasures")]
+    #[test]
+    fn fault_check() {
+        use super::FieldElement;
+
+        let p = ProjectivePoint::GENERATOR * Scalar::from(3u32);
+        assert_eq!(p.fault_check(), p);
+        assert_eq!(
+            ProjectivePoint::IDENTITY.fault_check(),
+            ProjectivePoint::IDENTITY
+        );
+
+        let faulty = ProjectivePoint {
+            x: p.x + FieldElement::ONE,
+            ..p
+        };
+        assert_eq!(faulty.fault_check(), ProjectivePoint::IDENTITY);
+    }
 }
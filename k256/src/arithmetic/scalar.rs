@@ -12,8 +12,8 @@ use core::{
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Shr, ShrAssign, Sub, SubAssign},
 };
 use elliptic_curve::{
-    bigint::{prelude::*, Limb, Word, U256, U512},
-    ff::{self, Field, PrimeField},
+    bigint::{prelude::*, Limb, Uint, Word, U256, U512},
+    ff::{self, Field, FromUniformBytes, PrimeField},
     ops::{Invert, Reduce, ReduceNonZero},
     rand_core::{CryptoRngCore, RngCore},
     scalar::{FromUintUnchecked, IsHigh},
@@ -75,6 +75,8 @@ const FRAC_MODULUS_2: U256 = ORDER.shr_vartime(1);
 #[derive(Clone, Copy, Debug, Default, PartialOrd, Ord)]
 pub struct Scalar(pub(crate) U256);
 
+primefield::impl_batch_invert!(Scalar);
+
 impl Scalar {
     /// Zero scalar.
     pub const ZERO: Self = Self(U256::ZERO);
@@ -175,6 +177,12 @@ impl Scalar {
         CtOption::new(res, !self.is_zero())
     }
 
+    /// Raises the scalar to the power `exp` in constant time, using a fixed window.
+    /// Unlike `pow_vartime`, this may be used with secret exponents.
+    pub fn pow_uint<const LIMBS: usize>(&self, exp: &Uint<LIMBS>) -> Self {
+        primefield::pow(self, exp)
+    }
+
     /// Returns the scalar modulus as a `BigUint` object.
     #[cfg(test)]
     pub fn modulus_as_biguint() -> BigUint {
@@ -686,6 +694,12 @@ impl Reduce<U512> for Scalar {
     }
 }
 
+impl FromUniformBytes<64> for Scalar {
+    fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
+        Self::reduce(U512::from_le_slice(bytes))
+    }
+}
+
 impl ReduceNonZero<U256> for Scalar {
     fn reduce_nonzero(w: U256) -> Self {
         const ORDER_MINUS_ONE: U256 = ORDER.wrapping_sub(&U256::ONE);
@@ -802,6 +816,10 @@ mod tests {
     };
     use num_bigint::{BigUint, ToBigUint};
     use num_traits::Zero;
+    use primefield::{
+        impl_field_differential_tests, impl_field_pow_tests, impl_field_reduce_wide_tests,
+        impl_field_sqrt_differential_tests,
+    };
     use proptest::prelude::*;
     use rand_core::OsRng;
 
@@ -837,6 +855,11 @@ mod tests {
         0x03ffffffffffffff,
     ];
 
+    impl_field_pow_tests!(Scalar);
+    impl_field_reduce_wide_tests!(Scalar, U512, 64);
+    impl_field_differential_tests!(Scalar);
+    impl_field_sqrt_differential_tests!(Scalar);
+
     #[test]
     fn two_inv_constant() {
         assert_eq!(Scalar::from(2u32) * Scalar::TWO_INV, Scalar::ONE);
@@ -979,6 +1002,24 @@ mod tests {
         assert_eq!(res, expected);
     }
 
+    #[test]
+    fn batch_invert_in_place() {
+        let k: Scalar = Scalar::random(&mut OsRng);
+        let l: Scalar = Scalar::random(&mut OsRng);
+
+        let expected = [k.invert().unwrap(), Scalar::ZERO, l.invert().unwrap()];
+        let mut scalars = [k, Scalar::ZERO, l];
+        assert!(!bool::from(Scalar::batch_invert_array(&mut scalars)));
+        assert_eq!(scalars, expected);
+
+        #[cfg(feature = "alloc")]
+        {
+            let mut scalars = vec![k, l];
+            assert!(bool::from(Scalar::batch_invert(&mut scalars)));
+            assert_eq!(scalars, [expected[0], expected[2]]);
+        }
+    }
+
     #[test]
     fn negate() {
         let zero_neg = -Scalar::ZERO;
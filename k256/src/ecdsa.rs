@@ -102,6 +102,15 @@ pub use ecdsa_core::hazmat;
 
 use crate::Secp256k1;
 
+#[cfg(all(
+    feature = "ecdsa",
+    any(feature = "fault-countermeasures", feature = "side-channel-hardening")
+))]
+use {
+    crate::{ProjectivePoint, Scalar},
+    signature::rand_core::CryptoRngCore,
+};
+
 /// ECDSA/secp256k1 signature (fixed-size)
 pub type Signature = ecdsa_core::Signature<Secp256k1>;
 
@@ -120,6 +129,34 @@ pub type SigningKey = ecdsa_core::SigningKey<Secp256k1>;
 #[cfg(feature = "ecdsa")]
 pub type VerifyingKey = ecdsa_core::VerifyingKey<Secp256k1>;
 
+/// ECDSA/secp256k1 signing key with the countermeasures enabled by the
+/// `side-channel-hardening` and `fault-countermeasures` features, see
+/// [`primeorder::ecdsa`].
+#[cfg(all(
+    feature = "ecdsa",
+    any(feature = "fault-countermeasures", feature = "side-channel-hardening")
+))]
+pub type HardenedSigningKey = primeorder::ecdsa::HardenedSigningKey<Secp256k1>;
+
+#[cfg(all(
+    feature = "ecdsa",
+    any(feature = "fault-countermeasures", feature = "side-channel-hardening")
+))]
+impl primeorder::ecdsa::HardenedCurve for Secp256k1 {
+    fn mul_by_generator_with_rng(k: &Scalar, rng: &mut impl CryptoRngCore) -> ProjectivePoint {
+        #[cfg(feature = "side-channel-hardening")]
+        return ProjectivePoint::GENERATOR.mul_blinded(k, rng);
+
+        #[cfg(not(feature = "side-channel-hardening"))]
+        {
+            use elliptic_curve::ops::MulByGenerator;
+
+            let _ = rng;
+            ProjectivePoint::mul_by_generator(k)
+        }
+    }
+}
+
 #[cfg(feature = "sha256")]
 impl hazmat::DigestPrimitive for Secp256k1 {
     type Digest = sha2::Sha256;
@@ -127,6 +164,29 @@ impl hazmat::DigestPrimitive for Secp256k1 {
 
 #[cfg(all(test, feature = "ecdsa", feature = "arithmetic"))]
 mod tests {
+    #[cfg(any(feature = "fault-countermeasures", feature = "side-channel-hardening"))]
+    mod hardened {
+        use crate::ecdsa::{
+            signature::{RandomizedSigner, Signer, Verifier},
+            HardenedSigningKey, Signature, SigningKey,
+        };
+        use elliptic_curve::rand_core::OsRng;
+
+        #[test]
+        fn sign() {
+            let msg = b"example";
+            let signing_key = SigningKey::random(&mut OsRng);
+            let hardened = HardenedSigningKey::from(signing_key.clone());
+
+            let signature: Signature = hardened.sign(msg);
+            assert_eq!(signature, signing_key.sign(msg));
+
+            let signature: Signature = hardened.sign_with_rng(&mut OsRng, msg);
+            assert_eq!(signature.normalize_s(), signature);
+            assert!(signing_key.verifying_key().verify(msg, &signature).is_ok());
+        }
+    }
+
     mod normalize {
         use crate::ecdsa::Signature;
 
@@ -50,7 +50,9 @@ pub mod test_vectors;
 pub use elliptic_curve::{self, bigint::U256};
 
 #[cfg(feature = "arithmetic")]
-pub use arithmetic::{affine::AffinePoint, projective::ProjectivePoint, scalar::Scalar};
+pub use arithmetic::{
+    affine::AffinePoint, projective::ProjectivePoint, scalar::Scalar, FixedBaseTable,
+};
 
 #[cfg(feature = "expose-field")]
 pub use arithmetic::FieldElement;
@@ -116,6 +118,21 @@ impl pkcs8::AssociatedOid for Secp256k1 {
     const OID: pkcs8::ObjectIdentifier = pkcs8::ObjectIdentifier::new_unwrap("1.3.132.0.10");
 }
 
+#[cfg(all(feature = "arithmetic", feature = "pkcs8"))]
+impl primeorder::specified_curve::SpecifiedCurveParams for Secp256k1 {
+    fn field_params() -> (FieldBytes, FieldBytes, FieldBytes) {
+        use crate::arithmetic::FieldElement;
+        use elliptic_curve::ff::PrimeField;
+
+        let p = U256::from_be_hex(FieldElement::MODULUS);
+        (
+            p.to_be_byte_array(),
+            FieldBytes::default(),
+            U256::from_u8(7).to_be_byte_array(),
+        )
+    }
+}
+
 /// Compressed SEC1-encoded secp256k1 (K-256) curve point.
 pub type CompressedPoint = Array<u8, U33>;
 
@@ -21,7 +21,7 @@ use signature::{
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
 
-#[cfg(debug_assertions)]
+#[cfg(any(debug_assertions, feature = "fault-countermeasures"))]
 use signature::hazmat::PrehashVerifier;
 
 /// Taproot Schnorr signing key.
@@ -111,7 +111,9 @@ impl SigningKey {
         let s = Option::from(NonZeroScalar::new(s)).ok_or_else(Error::new)?;
         let sig = Signature { r, s };
 
-        #[cfg(debug_assertions)]
+        // Verify the signature before releasing it, so a fault injected while
+        // signing can't leak the secret key.
+        #[cfg(any(debug_assertions, feature = "fault-countermeasures"))]
         self.verifying_key.verify_prehash(msg, &sig)?;
 
         Ok(sig)
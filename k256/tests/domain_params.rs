@@ -0,0 +1,32 @@
+//! Domain parameter audit.
+
+#![cfg(feature = "arithmetic")]
+
+use hex_literal::hex;
+use k256::{
+    elliptic_curve::{sec1::ToEncodedPoint, Curve},
+    AffinePoint, Secp256k1,
+};
+use primeorder::dev::{AuditReport, DomainParams, TwistSecurity};
+
+#[test]
+fn domain_params() {
+    let generator = AffinePoint::GENERATOR.to_encoded_point(false);
+    let params = DomainParams::new(
+        &hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"),
+        &[0],
+        &[7],
+        generator.x().unwrap(),
+        generator.y().unwrap(),
+        &Secp256k1::ORDER.to_be_bytes(),
+    );
+
+    assert_eq!(
+        params.audit().unwrap(),
+        AuditReport {
+            twist_security: TwistSecurity::Exact(110),
+            embedding_degree: None,
+            cm_discriminant: Some(3),
+        }
+    );
+}
@@ -0,0 +1,28 @@
+//! Tests for keys which carry explicit domain parameters (`specifiedCurve`).
+
+#![cfg(all(feature = "pkcs8", feature = "arithmetic"))]
+
+use hex_literal::hex;
+use k256::{elliptic_curve::sec1::ToEncodedPoint, PublicKey, SecretKey};
+use primeorder::specified_curve::{DecodeSpecifiedPrivateKey, DecodeSpecifiedPublicKey};
+
+/// DER-encoded PKCS#8 private key with explicit parameters.
+///
+/// Generated via `openssl ecparam -name secp256k1 -genkey -param_enc explicit`.
+const PKCS8_PRIVATE_KEY_DER: &[u8; 357] = include_bytes!("examples/pkcs8-private-key-explicit.der");
+
+/// DER-encoded SPKI public key with explicit parameters.
+const PKCS8_PUBLIC_KEY_DER: &[u8; 311] = include_bytes!("examples/pkcs8-public-key-explicit.der");
+
+#[test]
+fn decode_keys_with_explicit_params() {
+    let secret_key = SecretKey::from_specified_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+    let public_key = PublicKey::from_specified_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+
+    let expected_scalar = hex!("0d7e1a08c350ed1f3d494384f1f2fab3f5b803d913a2ea963653e13bbea015cb");
+    assert_eq!(secret_key.to_bytes().as_slice(), &expected_scalar[..]);
+    assert_eq!(
+        secret_key.public_key().to_encoded_point(false),
+        public_key.to_encoded_point(false)
+    );
+}
@@ -0,0 +1,24 @@
+//! Hashing to P-192 using the simplified SWU map.
+
+use super::{field::FieldElement, scalar::Scalar};
+use crate::NistP192;
+use elliptic_curve::consts::U36;
+use primeorder::hash2curve::SswuParams;
+
+impl SswuParams for NistP192 {
+    /// Z = -5
+    const Z: FieldElement = FieldElement::from_u64(5).neg();
+
+    const SUITE_ID_RO: &'static str = "P192_XMD:SHA-256_SSWU_RO_";
+    const SUITE_ID_NU: &'static str = "P192_XMD:SHA-256_SSWU_NU_";
+}
+
+primeorder::impl_hash2curve!(NistP192, FieldElement, Scalar, U36);
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldElement, Scalar};
+    use crate::{AffinePoint, NistP192};
+
+    primeorder::impl_hash2curve_tests!(NistP192, AffinePoint, FieldElement, Scalar);
+}
@@ -21,16 +21,24 @@
 )]
 mod scalar_impl;
 
+#[cfg(all(test, target_pointer_width = "64"))]
+#[path = "scalar/p192_scalar_32.rs"]
+mod scalar_impl_32;
+
+#[cfg(all(test, target_pointer_width = "32"))]
+#[path = "scalar/p192_scalar_64.rs"]
+mod scalar_impl_64;
+
 use self::scalar_impl::*;
-use crate::{FieldBytes, FieldBytesEncoding, NistP192, ORDER_HEX, U192};
+use crate::{FieldBytes, FieldBytesEncoding, NistP192, WideBytes, ORDER_HEX, U192};
 use core::{
     fmt::{self, Debug},
     iter::{Product, Sum},
     ops::{AddAssign, MulAssign, Neg, Shr, ShrAssign, SubAssign},
 };
 use elliptic_curve::{
-    bigint::Limb,
-    ff::PrimeField,
+    bigint::{ArrayEncoding, Limb, U384},
+    ff::{FromUniformBytes, PrimeField},
     ops::{Invert, Reduce},
     scalar::{FromUintUnchecked, IsHigh},
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, CtOption},
@@ -188,6 +196,10 @@ impl Invert for Scalar {
     fn invert(&self) -> CtOption<Self> {
         self.invert()
     }
+
+    fn invert_vartime(&self) -> CtOption<Self> {
+        self.invert_vartime()
+    }
 }
 
 impl IsHigh for Scalar {
@@ -277,6 +289,25 @@ impl Reduce<U192> for Scalar {
     }
 }
 
+impl Reduce<U384> for Scalar {
+    type Bytes = WideBytes;
+
+    fn reduce(w: U384) -> Self {
+        primefield::reduce_wide(&w)
+    }
+
+    #[inline]
+    fn reduce_bytes(bytes: &WideBytes) -> Self {
+        Self::reduce(U384::from_be_byte_array(*bytes))
+    }
+}
+
+impl FromUniformBytes<48> for Scalar {
+    fn from_uniform_bytes(bytes: &[u8; 48]) -> Self {
+        primefield::reduce_le_bytes(bytes)
+    }
+}
+
 impl From<ScalarPrimitive<NistP192>> for Scalar {
     fn from(w: ScalarPrimitive<NistP192>) -> Self {
         Scalar::from(&w)
@@ -356,18 +387,47 @@ impl<'de> Deserialize<'de> for Scalar {
 #[cfg(test)]
 mod tests {
     use super::Scalar;
-    use elliptic_curve::ff::PrimeField;
+    use elliptic_curve::{bigint::U384, ff::PrimeField};
+    use primefield::{
+        impl_fiat_differential_tests, impl_field_differential_tests, impl_field_reduce_wide_tests,
+        impl_field_sqrt_differential_tests,
+    };
     use primeorder::{
-        impl_field_identity_tests, impl_field_invert_tests, impl_field_sqrt_tests,
+        impl_field_identity_tests, impl_field_invert_tests, impl_field_invert_vartime_tests,
+        impl_field_pow_tests, impl_field_sqrt_ratio_tests, impl_field_sqrt_tests,
         impl_primefield_tests,
     };
 
+    #[cfg(target_pointer_width = "64")]
+    use super::{scalar_impl as scalar_impl_64, scalar_impl_32};
+    #[cfg(target_pointer_width = "32")]
+    use super::{scalar_impl as scalar_impl_32, scalar_impl_64};
+
     /// t = (modulus - 1) >> S
     /// 0xffffffffffffffffffffffff99def836146bc9b1b4d2283
     const T: [u64; 3] = [0x6146bc9b1b4d2283, 0xfffffffff99def83, 0x0fffffffffffffff];
 
     impl_field_identity_tests!(Scalar);
     impl_field_invert_tests!(Scalar);
+    impl_field_invert_vartime_tests!(Scalar);
     impl_field_sqrt_tests!(Scalar);
+    impl_field_sqrt_ratio_tests!(Scalar);
+    impl_field_pow_tests!(Scalar);
+    impl_field_reduce_wide_tests!(Scalar, U384, 48);
+    impl_field_differential_tests!(Scalar);
+    impl_field_sqrt_differential_tests!(Scalar);
+    impl_fiat_differential_tests!(
+        Scalar,
+        [scalar_impl_32, scalar_impl_64],
+        fiat_p192_scalar_from_bytes,
+        fiat_p192_scalar_to_bytes,
+        fiat_p192_scalar_to_montgomery,
+        fiat_p192_scalar_from_montgomery,
+        fiat_p192_scalar_add,
+        fiat_p192_scalar_sub,
+        fiat_p192_scalar_mul,
+        fiat_p192_scalar_square,
+        fiat_p192_scalar_opp,
+    );
     impl_primefield_tests!(Scalar, T);
 }
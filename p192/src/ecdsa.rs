@@ -50,10 +50,20 @@ impl EcdsaCurve for NistP192 {
 #[cfg(feature = "ecdsa")]
 pub type VerifyingKey = ecdsa_core::VerifyingKey<NistP192>;
 
+/// ECDSA/P-192 verification key which verifies signatures in variable time,
+/// see [`primeorder::ecdsa::VartimeVerifyingKey`].
+#[cfg(feature = "ecdsa")]
+pub type VartimeVerifyingKey = primeorder::ecdsa::VartimeVerifyingKey<NistP192>;
+
 #[cfg(all(test, feature = "ecdsa"))]
 mod tests {
     mod verify {
         use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, NistP192};
         ecdsa_core::new_verification_test!(NistP192, ECDSA_TEST_VECTORS);
     }
+
+    mod vartime_verify {
+        use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, NistP192};
+        primeorder::impl_vartime_verifying_key_tests!(NistP192, ECDSA_TEST_VECTORS);
+    }
 }
@@ -35,7 +35,7 @@ pub use elliptic_curve::pkcs8;
 use elliptic_curve::{
     array::Array,
     bigint::{ArrayEncoding, U192},
-    consts::{U24, U25},
+    consts::{U24, U25, U48},
     FieldBytesEncoding,
 };
 
@@ -80,6 +80,9 @@ pub type EncodedPoint = elliptic_curve::sec1::EncodedPoint<NistP192>;
 /// scalar).
 pub type FieldBytes = elliptic_curve::FieldBytes<NistP192>;
 
+/// Bytes used by a wide reduction: twice the width of [`FieldBytes`].
+pub type WideBytes = Array<u8, U48>;
+
 impl FieldBytesEncoding<NistP192> for U192 {
     fn decode_field_bytes(field_bytes: &FieldBytes) -> Self {
         U192::from_be_byte_array(*field_bytes)
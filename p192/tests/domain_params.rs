@@ -0,0 +1,21 @@
+//! Domain parameter audit.
+
+#![cfg(feature = "arithmetic")]
+
+use p192::NistP192;
+use primeorder::{
+    dev::{AuditReport, TwistSecurity},
+    impl_domain_params_tests,
+};
+
+impl_domain_params_tests!(
+    NistP192,
+    AuditReport {
+        twist_security: TwistSecurity::AtLeast {
+            bits: 9,
+            unfactored_bits: 188,
+        },
+        embedding_degree: None,
+        cm_discriminant: None,
+    }
+);
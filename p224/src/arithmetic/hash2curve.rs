@@ -0,0 +1,24 @@
+//! Hashing to P-224 using the simplified SWU map.
+
+use super::{field::FieldElement, scalar::Scalar};
+use crate::NistP224;
+use elliptic_curve::consts::U42;
+use primeorder::hash2curve::SswuParams;
+
+impl SswuParams for NistP224 {
+    /// Z = 31
+    const Z: FieldElement = FieldElement::from_u64(31);
+
+    const SUITE_ID_RO: &'static str = "P224_XMD:SHA-224_SSWU_RO_";
+    const SUITE_ID_NU: &'static str = "P224_XMD:SHA-224_SSWU_NU_";
+}
+
+primeorder::impl_hash2curve!(NistP224, FieldElement, Scalar, U42);
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldElement, Scalar};
+    use crate::{AffinePoint, NistP224};
+
+    primeorder::impl_hash2curve_tests!(NistP224, AffinePoint, FieldElement, Scalar);
+}
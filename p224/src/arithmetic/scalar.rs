@@ -21,16 +21,24 @@
 )]
 mod scalar_impl;
 
+#[cfg(all(test, target_pointer_width = "64"))]
+#[path = "scalar/p224_scalar_32.rs"]
+mod scalar_impl_32;
+
+#[cfg(all(test, target_pointer_width = "32"))]
+#[path = "scalar/p224_scalar_64.rs"]
+mod scalar_impl_64;
+
 use self::scalar_impl::*;
-use crate::{FieldBytes, FieldBytesEncoding, NistP224, SecretKey, Uint, ORDER_HEX};
+use crate::{FieldBytes, FieldBytesEncoding, NistP224, SecretKey, Uint, WideBytes, ORDER_HEX};
 use core::{
     fmt::{self, Debug},
     iter::{Product, Sum},
     ops::{AddAssign, MulAssign, Neg, Shr, ShrAssign, SubAssign},
 };
 use elliptic_curve::{
-    bigint::Limb,
-    ff::PrimeField,
+    bigint::{ArrayEncoding, Limb, U448},
+    ff::{FromUniformBytes, PrimeField},
     ops::{Invert, Reduce},
     scalar::{FromUintUnchecked, IsHigh},
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, CtOption},
@@ -172,6 +180,10 @@ impl Invert for Scalar {
     fn invert(&self) -> CtOption<Self> {
         self.invert()
     }
+
+    fn invert_vartime(&self) -> CtOption<Self> {
+        self.invert_vartime()
+    }
 }
 
 impl IsHigh for Scalar {
@@ -266,6 +278,25 @@ impl Reduce<Uint> for Scalar {
     }
 }
 
+impl Reduce<U448> for Scalar {
+    type Bytes = WideBytes;
+
+    fn reduce(w: U448) -> Self {
+        primefield::reduce_wide(&w)
+    }
+
+    #[inline]
+    fn reduce_bytes(bytes: &WideBytes) -> Self {
+        Self::reduce(U448::from_be_byte_array(*bytes))
+    }
+}
+
+impl FromUniformBytes<56> for Scalar {
+    fn from_uniform_bytes(bytes: &[u8; 56]) -> Self {
+        primefield::reduce_le_bytes(bytes)
+    }
+}
+
 impl From<ScalarPrimitive<NistP224>> for Scalar {
     fn from(w: ScalarPrimitive<NistP224>) -> Self {
         Scalar::from(&w)
@@ -357,12 +388,22 @@ impl<'de> Deserialize<'de> for Scalar {
 #[cfg(test)]
 mod tests {
     use super::Scalar;
-    use elliptic_curve::PrimeField;
+    use elliptic_curve::{bigint::U448, PrimeField};
+    use primefield::{
+        impl_fiat_differential_tests, impl_field_differential_tests, impl_field_reduce_wide_tests,
+        impl_field_sqrt_differential_tests,
+    };
     use primeorder::{
-        impl_field_identity_tests, impl_field_invert_tests, impl_field_sqrt_tests,
+        impl_field_identity_tests, impl_field_invert_tests, impl_field_invert_vartime_tests,
+        impl_field_pow_tests, impl_field_sqrt_ratio_tests, impl_field_sqrt_tests,
         impl_primefield_tests,
     };
 
+    #[cfg(target_pointer_width = "64")]
+    use super::{scalar_impl as scalar_impl_64, scalar_impl_32};
+    #[cfg(target_pointer_width = "32")]
+    use super::{scalar_impl as scalar_impl_32, scalar_impl_64};
+
     /// t = (modulus - 1) >> S
     const T: [u64; 4] = [
         0x84f74a5157170a8f,
@@ -373,6 +414,25 @@ mod tests {
 
     impl_field_identity_tests!(Scalar);
     impl_field_invert_tests!(Scalar);
+    impl_field_invert_vartime_tests!(Scalar);
     impl_field_sqrt_tests!(Scalar);
+    impl_field_sqrt_ratio_tests!(Scalar);
+    impl_field_pow_tests!(Scalar);
+    impl_field_reduce_wide_tests!(Scalar, U448, 56);
+    impl_field_differential_tests!(Scalar);
+    impl_field_sqrt_differential_tests!(Scalar);
+    impl_fiat_differential_tests!(
+        Scalar,
+        [scalar_impl_32, scalar_impl_64],
+        fiat_p224_scalar_from_bytes,
+        fiat_p224_scalar_to_bytes,
+        fiat_p224_scalar_to_montgomery,
+        fiat_p224_scalar_from_montgomery,
+        fiat_p224_scalar_add,
+        fiat_p224_scalar_sub,
+        fiat_p224_scalar_mul,
+        fiat_p224_scalar_square,
+        fiat_p224_scalar_opp,
+    );
     impl_primefield_tests!(Scalar, T);
 }
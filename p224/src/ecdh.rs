@@ -24,24 +24,29 @@
 //! let bob_public = PublicKey::from_sec1_bytes(bob_pk_bytes.as_ref())
 //!     .expect("bob's public key is invalid!"); // In real usage, don't panic, handle this!
 //!
-//! let alice_shared = alice_secret.diffie_hellman(&bob_public);
+//! let alice_shared = alice_secret.diffie_hellman(&bob_public)
+//!     .expect("shared secret is the identity!"); // In real usage, don't panic, handle this!
 //!
 //! // Bob decodes Alice's serialized public key and computes the same shared secret
 //! let alice_public = PublicKey::from_sec1_bytes(alice_pk_bytes.as_ref())
 //!     .expect("alice's public key is invalid!"); // In real usage, don't panic, handle this!
 //!
-//! let bob_shared = bob_secret.diffie_hellman(&alice_public);
+//! let bob_shared = bob_secret.diffie_hellman(&alice_public)
+//!     .expect("shared secret is the identity!"); // In real usage, don't panic, handle this!
 //!
 //! // Both participants arrive on the same shared secret
 //! assert_eq!(alice_shared.raw_secret_bytes(), bob_shared.raw_secret_bytes());
 //! ```
 
-pub use elliptic_curve::ecdh::diffie_hellman;
+pub use primeorder::ecdh::{diffie_hellman, diffie_hellman_x};
+
+#[cfg(feature = "side-channel-hardening")]
+pub use primeorder::ecdh::{diffie_hellman_blinded, diffie_hellman_x_blinded};
 
 use crate::NistP224;
 
 /// NIST P-224 Ephemeral Diffie-Hellman Secret.
-pub type EphemeralSecret = elliptic_curve::ecdh::EphemeralSecret<NistP224>;
+pub type EphemeralSecret = primeorder::ecdh::EphemeralSecret<NistP224>;
 
 /// Shared secret value computed via ECDH key agreement.
 pub type SharedSecret = elliptic_curve::ecdh::SharedSecret<NistP224>;
@@ -56,10 +56,24 @@ impl EcdsaCurve for NistP224 {
 #[cfg(feature = "ecdsa")]
 pub type SigningKey = ecdsa_core::SigningKey<NistP224>;
 
+/// ECDSA/P-224 signing key with the countermeasures enabled by the
+/// `side-channel-hardening` and `fault-countermeasures` features, see
+/// [`primeorder::ecdsa`].
+#[cfg(all(
+    feature = "ecdsa",
+    any(feature = "fault-countermeasures", feature = "side-channel-hardening")
+))]
+pub type HardenedSigningKey = primeorder::ecdsa::HardenedSigningKey<NistP224>;
+
 /// ECDSA/P-224 verification key (i.e. public key)
 #[cfg(feature = "ecdsa")]
 pub type VerifyingKey = ecdsa_core::VerifyingKey<NistP224>;
 
+/// ECDSA/P-224 verification key which verifies signatures in variable time,
+/// see [`primeorder::ecdsa::VartimeVerifyingKey`].
+#[cfg(feature = "ecdsa")]
+pub type VartimeVerifyingKey = primeorder::ecdsa::VartimeVerifyingKey<NistP224>;
+
 #[cfg(feature = "sha224")]
 impl ecdsa_core::hazmat::DigestPrimitive for NistP224 {
     type Digest = sha2::Sha224;
@@ -105,6 +119,11 @@ mod tests {
         ecdsa_core::new_verification_test!(NistP224, ECDSA_TEST_VECTORS);
     }
 
+    mod vartime_verify {
+        use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, NistP224};
+        primeorder::impl_vartime_verifying_key_tests!(NistP224, ECDSA_TEST_VECTORS);
+    }
+
     mod wycheproof {
         use crate::NistP224;
         ecdsa_core::new_wycheproof_test!(wycheproof, "wycheproof", NistP224);
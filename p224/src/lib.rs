@@ -37,7 +37,7 @@ pub use elliptic_curve::pkcs8;
 
 use elliptic_curve::{
     array::Array,
-    consts::{U28, U29},
+    consts::{U28, U29, U56},
     FieldBytesEncoding,
 };
 
@@ -100,6 +100,9 @@ pub type EncodedPoint = elliptic_curve::sec1::EncodedPoint<NistP224>;
 /// scalar).
 pub type FieldBytes = elliptic_curve::FieldBytes<NistP224>;
 
+/// Bytes used by a wide reduction: twice the width of [`FieldBytes`].
+pub type WideBytes = Array<u8, U56>;
+
 impl FieldBytesEncoding<NistP224> for Uint {}
 
 /// Non-zero NIST P-256 scalar field element.
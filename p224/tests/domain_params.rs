@@ -0,0 +1,21 @@
+//! Domain parameter audit.
+
+#![cfg(feature = "arithmetic")]
+
+use p224::NistP224;
+use primeorder::{
+    dev::{AuditReport, TwistSecurity},
+    impl_domain_params_tests,
+};
+
+impl_domain_params_tests!(
+    NistP224,
+    AuditReport {
+        twist_security: TwistSecurity::AtLeast {
+            bits: 13,
+            unfactored_bits: 166,
+        },
+        embedding_degree: None,
+        cm_discriminant: None,
+    }
+);
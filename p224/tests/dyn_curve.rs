@@ -0,0 +1,106 @@
+//! Tests for `primeorder::dyn_curve::DynCurve`, using P-224 as a reference.
+//!
+//! P-224 has `p ≡ 1 mod 2^96`, so decompressing points exercises the general
+//! case of the square root algorithm.
+
+#![cfg(feature = "ecdsa")]
+
+use elliptic_curve::{bigint::U256, sec1::ToEncodedPoint};
+use hex_literal::hex;
+use p224::{
+    ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey},
+    ProjectivePoint,
+};
+use primeorder::dyn_curve::DynCurve;
+
+const P: [u8; 28] = hex!("ffffffffffffffffffffffffffffffff000000000000000000000001");
+const A: [u8; 28] = hex!("fffffffffffffffffffffffffffffffefffffffffffffffffffffffe");
+const B: [u8; 28] = hex!("b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4");
+const N: [u8; 28] = hex!("ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d");
+const G: [u8; 29] = hex!("02b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21");
+
+const SECRET_KEYS: [[u8; 28]; 3] = [
+    hex!("0000000000000000000000000000000000000000000000000000002a"),
+    hex!("3f0c488e987c80be0fee521f8d90be6034ec69ae11ca72aa777481e8"),
+    hex!("ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3c"),
+];
+
+fn curve() -> DynCurve<{ U256::LIMBS }> {
+    DynCurve::new(&P, &A, &B, &G, &N).unwrap()
+}
+
+#[test]
+fn field_size() {
+    assert_eq!(curve().field_size(), 28);
+}
+
+#[test]
+fn public_key() {
+    let curve = curve();
+
+    for secret in &SECRET_KEYS {
+        let sk = SigningKey::from_slice(secret).unwrap();
+        let mut pk = [0u8; 57];
+        curve.public_key(secret, &mut pk).unwrap();
+
+        let expected = sk.verifying_key().to_encoded_point(false);
+        assert_eq!(&pk[..], expected.as_bytes());
+    }
+}
+
+#[test]
+fn diffie_hellman_compressed() {
+    let curve = curve();
+    let sk_a = SigningKey::from_slice(&SECRET_KEYS[1]).unwrap();
+
+    for secret in &SECRET_KEYS {
+        let sk_b = SigningKey::from_slice(secret).unwrap();
+        let pk_b = sk_b.verifying_key().to_encoded_point(true);
+        let expected = (ProjectivePoint::from(*sk_b.verifying_key().as_affine())
+            * **sk_a.as_nonzero_scalar())
+        .to_encoded_point(false);
+
+        let mut shared_secret = [0u8; 28];
+        curve
+            .diffie_hellman(&SECRET_KEYS[1], pk_b.as_bytes(), &mut shared_secret)
+            .unwrap();
+        assert_eq!(&shared_secret[..], expected.x().unwrap().as_slice());
+    }
+}
+
+#[test]
+fn verify_prehash() {
+    let curve = curve();
+    let prehash = hex!("8a4c8f7fe8e3d1ec7b3a0a1f04c1b0bd8ea3a0f4c4e6e5d1f1c0b9a8e7d6c5b4");
+
+    for secret in &SECRET_KEYS {
+        let sk = SigningKey::from_slice(secret).unwrap();
+        let signature: Signature = sk.sign_prehash(&prehash).unwrap();
+        let (r, s) = signature.split_bytes();
+        let pk = sk.verifying_key().to_encoded_point(true);
+
+        assert!(curve
+            .verify_prehash(pk.as_bytes(), &prehash, &r, &s)
+            .is_ok());
+
+        // Only the leftmost 224 bits of the digest are used
+        let mut tampered = prehash;
+        tampered[31] ^= 1;
+        assert!(curve
+            .verify_prehash(pk.as_bytes(), &tampered, &r, &s)
+            .is_ok());
+        tampered[0] ^= 1;
+        assert!(curve
+            .verify_prehash(pk.as_bytes(), &tampered, &r, &s)
+            .is_err());
+    }
+}
+
+#[test]
+fn rejects_invalid_secret_keys() {
+    let curve = curve();
+    let mut pk = [0u8; 57];
+
+    assert!(curve.public_key(&[0u8; 28], &mut pk).is_err());
+    assert!(curve.public_key(&N, &mut pk).is_err());
+}
@@ -1,10 +1,14 @@
 //! secp256r1 scalar arithmetic benchmarks
 
 use criterion::{
-    criterion_group, criterion_main, measurement::Measurement, BenchmarkGroup, Criterion,
+    black_box, criterion_group, criterion_main, measurement::Measurement, BenchmarkGroup,
+    BenchmarkId, Criterion,
 };
 use hex_literal::hex;
-use p256::{elliptic_curve::group::ff::PrimeField, ProjectivePoint, Scalar};
+use p256::{
+    elliptic_curve::{group::ff::PrimeField, ops::LinearCombination},
+    ProjectivePoint, Scalar,
+};
 
 fn test_scalar_x() -> Scalar {
     Scalar::from_repr(
@@ -61,6 +65,37 @@ fn bench_point(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_point_msm(c: &mut Criterion) {
+    let mut group = c.benchmark_group("multi-scalar multiplication");
+    let x = test_scalar_x();
+
+    for n in [16, 64, 256] {
+        let points = (1..=n)
+            .map(|i| ProjectivePoint::GENERATOR * Scalar::from(i as u64))
+            .collect::<Vec<_>>();
+        let scalars = (1..=n)
+            .map(|i| x * Scalar::from(i as u64))
+            .collect::<Vec<_>>();
+        let terms = points
+            .iter()
+            .copied()
+            .zip(scalars.iter().copied())
+            .collect::<Vec<_>>();
+
+        group.bench_with_input(BenchmarkId::new("lincomb", n), &terms, |b, terms| {
+            b.iter(|| ProjectivePoint::lincomb(black_box(terms.as_slice())))
+        });
+        group.bench_with_input(BenchmarkId::new("msm", n), &n, |b, _| {
+            b.iter(|| ProjectivePoint::msm(black_box(&points), black_box(&scalars)))
+        });
+        group.bench_with_input(BenchmarkId::new("msm_vartime", n), &n, |b, _| {
+            b.iter(|| ProjectivePoint::msm_vartime(black_box(&points), black_box(&scalars)))
+        });
+    }
+
+    group.finish();
+}
+
 fn bench_scalar(c: &mut Criterion) {
     let mut group = c.benchmark_group("scalar operations");
     bench_scalar_sub(&mut group);
@@ -71,5 +106,5 @@ fn bench_scalar(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_point, bench_scalar);
+criterion_group!(benches, bench_point, bench_point_msm, bench_scalar);
 criterion_main!(benches);
@@ -193,6 +193,21 @@ impl FieldElement {
         CtOption::new(self.invert_unchecked(), !self.is_zero())
     }
 
+    /// Returns the multiplicative inverse of self in variable time, if self is
+    /// non-zero.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This method should not be used with secret values, as its variable-time
+    /// operation can potentially leak secrets through sidechannels.
+    pub fn invert_vartime(&self) -> CtOption<Self> {
+        let inverse = primefield::invert_vartime(&self.to_canonical().0, &MODULUS.0);
+        CtOption::new(
+            Self::from_uint_unchecked(inverse.unwrap_or(U256::ZERO)),
+            Choice::from(u8::from(inverse.is_some())),
+        )
+    }
+
     /// Returns the multiplicative inverse of self.
     ///
     /// Does not check that self is non-zero.
@@ -350,6 +365,10 @@ impl Invert for FieldElement {
     fn invert(&self) -> CtOption<Self> {
         self.invert()
     }
+
+    fn invert_vartime(&self) -> CtOption<Self> {
+        self.invert_vartime()
+    }
 }
 
 impl Add<FieldElement> for FieldElement {
@@ -505,6 +524,8 @@ mod tests {
     use super::FieldElement;
     use crate::{test_vectors::field::DBL_TEST_VECTORS, FieldBytes};
     use core::ops::Mul;
+    use hex_literal::hex;
+    use primefield::{impl_field_differential_tests, impl_field_sqrt_differential_tests};
 
     #[cfg(target_pointer_width = "64")]
     use crate::U256;
@@ -525,6 +546,34 @@ mod tests {
         assert_eq!(one.mul(&one), one);
     }
 
+    /// The 32-bit and 64-bit backends can't be compiled side by side, so check
+    /// that whichever one the target selects reaches the same value after a
+    /// long chain of mixed operations.
+    #[test]
+    fn backend_cross_check() {
+        let mut x = FieldElement::from_u64(3);
+        let mut y = FieldElement::from_u64(7);
+
+        for _ in 0..1000 {
+            let t = x.multiply(&y).add(&x.square()).sub(&y);
+            y = x.neg().double().add(&t);
+            x = FieldElement::from_bytes(t.to_bytes()).unwrap();
+        }
+
+        assert_eq!(
+            x.to_bytes(),
+            FieldBytes::from(hex!(
+                "623778e6124c378633e05c9c0b807c50a37453a466778a7f1e9d0e9f0e9e3954"
+            ))
+        );
+        assert_eq!(
+            y.to_bytes(),
+            FieldBytes::from(hex!(
+                "cd72af20fb3df0dca4df87092b618c8e7150dd23a92150ac086390bb8da8c1c0"
+            ))
+        );
+    }
+
     #[test]
     fn from_bytes() {
         assert_eq!(
@@ -615,6 +664,18 @@ mod tests {
         assert_eq!(two * &inv_two, one);
     }
 
+    #[test]
+    fn invert_vartime() {
+        assert!(bool::from(FieldElement::ZERO.invert_vartime().is_none()));
+
+        let mut x = FieldElement::ONE;
+        for _ in 0..32 {
+            assert_eq!(x.invert_vartime().unwrap(), x.invert().unwrap());
+            assert_eq!((-x).invert_vartime().unwrap(), (-x).invert().unwrap());
+            x = x.square() + &x + &FieldElement::ONE;
+        }
+    }
+
     #[test]
     fn sqrt() {
         let one = FieldElement::ONE;
@@ -623,6 +684,9 @@ mod tests {
         assert_eq!(four.sqrt().unwrap(), two);
     }
 
+    impl_field_differential_tests!(FieldElement);
+    impl_field_sqrt_differential_tests!(FieldElement);
+
     #[cfg(target_pointer_width = "64")]
     proptest! {
         /// This checks behaviour well within the field ranges, because it doesn't set the
@@ -5,15 +5,15 @@
 mod scalar_impl;
 
 use self::scalar_impl::barrett_reduce;
-use crate::{FieldBytes, NistP256, SecretKey, ORDER_HEX};
+use crate::{FieldBytes, NistP256, SecretKey, WideBytes, ORDER_HEX};
 use core::{
     fmt::{self, Debug},
     iter::{Product, Sum},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Shr, ShrAssign, Sub, SubAssign},
 };
 use elliptic_curve::{
-    bigint::{prelude::*, Limb, U256},
-    group::ff::{self, Field, PrimeField},
+    bigint::{prelude::*, Limb, U256, U512},
+    group::ff::{self, Field, FromUniformBytes, PrimeField},
     ops::{Invert, Reduce, ReduceNonZero},
     rand_core::RngCore,
     scalar::{FromUintUnchecked, IsHigh},
@@ -661,6 +661,25 @@ impl Reduce<U256> for Scalar {
     }
 }
 
+impl Reduce<U512> for Scalar {
+    type Bytes = WideBytes;
+
+    fn reduce(w: U512) -> Self {
+        primefield::reduce_wide(&w)
+    }
+
+    #[inline]
+    fn reduce_bytes(bytes: &WideBytes) -> Self {
+        Self::reduce(U512::from_be_byte_array(*bytes))
+    }
+}
+
+impl FromUniformBytes<64> for Scalar {
+    fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
+        primefield::reduce_le_bytes(bytes)
+    }
+}
+
 impl ReduceNonZero<U256> for Scalar {
     fn reduce_nonzero(w: U256) -> Self {
         const ORDER_MINUS_ONE: U256 = NistP256::ORDER.wrapping_sub(&U256::ONE);
@@ -740,7 +759,14 @@ impl<'de> Deserialize<'de> for Scalar {
 mod tests {
     use super::Scalar;
     use crate::{FieldBytes, SecretKey};
-    use elliptic_curve::group::ff::{Field, PrimeField};
+    use elliptic_curve::{
+        bigint::U512,
+        group::ff::{Field, PrimeField},
+    };
+    use primefield::{
+        impl_field_differential_tests, impl_field_reduce_wide_tests,
+        impl_field_sqrt_differential_tests,
+    };
     use primeorder::{
         impl_field_identity_tests, impl_field_invert_tests, impl_field_sqrt_tests,
         impl_primefield_tests,
@@ -757,6 +783,9 @@ mod tests {
     impl_field_identity_tests!(Scalar);
     impl_field_invert_tests!(Scalar);
     impl_field_sqrt_tests!(Scalar);
+    impl_field_reduce_wide_tests!(Scalar, U512, 64);
+    impl_field_differential_tests!(Scalar);
+    impl_field_sqrt_differential_tests!(Scalar);
     impl_primefield_tests!(Scalar, T);
 
     #[test]
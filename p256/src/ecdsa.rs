@@ -58,10 +58,24 @@ impl EcdsaCurve for NistP256 {
 #[cfg(feature = "ecdsa")]
 pub type SigningKey = ecdsa_core::SigningKey<NistP256>;
 
+/// ECDSA/P-256 signing key with the countermeasures enabled by the
+/// `side-channel-hardening` and `fault-countermeasures` features, see
+/// [`primeorder::ecdsa`].
+#[cfg(all(
+    feature = "ecdsa",
+    any(feature = "fault-countermeasures", feature = "side-channel-hardening")
+))]
+pub type HardenedSigningKey = primeorder::ecdsa::HardenedSigningKey<NistP256>;
+
 /// ECDSA/P-256 verification key (i.e. public key)
 #[cfg(feature = "ecdsa")]
 pub type VerifyingKey = ecdsa_core::VerifyingKey<NistP256>;
 
+/// ECDSA/P-256 verification key which verifies signatures in variable time,
+/// see [`primeorder::ecdsa::VartimeVerifyingKey`].
+#[cfg(feature = "ecdsa")]
+pub type VartimeVerifyingKey = primeorder::ecdsa::VartimeVerifyingKey<NistP256>;
+
 #[cfg(feature = "sha256")]
 impl ecdsa_core::hazmat::DigestPrimitive for NistP256 {
     type Digest = sha2::Sha256;
@@ -165,6 +179,11 @@ mod tests {
         ecdsa_core::new_verification_test!(NistP256, ECDSA_TEST_VECTORS);
     }
 
+    mod vartime_verify {
+        use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, NistP256};
+        primeorder::impl_vartime_verifying_key_tests!(NistP256, ECDSA_TEST_VECTORS);
+    }
+
     mod wycheproof {
         use crate::NistP256;
         ecdsa_core::new_wycheproof_test!(wycheproof, "wycheproof", NistP256);
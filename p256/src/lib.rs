@@ -49,7 +49,12 @@ pub use arithmetic::field::FieldElement;
 #[cfg(feature = "pkcs8")]
 pub use elliptic_curve::pkcs8;
 
-use elliptic_curve::{array::Array, bigint::ArrayEncoding, consts::U33, FieldBytesEncoding};
+use elliptic_curve::{
+    array::Array,
+    bigint::ArrayEncoding,
+    consts::{U33, U64},
+    FieldBytesEncoding,
+};
 
 /// Order of NIST P-256's elliptic curve group (i.e. scalar modulus) serialized
 /// as hexadecimal.
@@ -143,6 +148,9 @@ pub type EncodedPoint = elliptic_curve::sec1::EncodedPoint<NistP256>;
 /// Byte array containing a serialized field element value (base field or scalar).
 pub type FieldBytes = elliptic_curve::FieldBytes<NistP256>;
 
+/// Bytes used by a wide reduction: twice the width of [`FieldBytes`].
+pub type WideBytes = Array<u8, U64>;
+
 impl FieldBytesEncoding<NistP256> for U256 {
     fn decode_field_bytes(field_bytes: &FieldBytes) -> Self {
         U256::from_be_byte_array(*field_bytes)
@@ -0,0 +1,120 @@
+//! Domain parameter audit.
+
+#![cfg(feature = "arithmetic")]
+
+use hex_literal::hex;
+use p256::NistP256;
+use primeorder::{
+    dev::{AuditError, AuditReport, DomainParams, TwistSecurity},
+    impl_domain_params_tests,
+};
+
+const P: [u8; 32] = hex!("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+const A: [u8; 32] = hex!("ffffffff00000001000000000000000000000000fffffffffffffffffffffffc");
+const B: [u8; 32] = hex!("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b");
+const GX: [u8; 32] = hex!("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296");
+const GY: [u8; 32] = hex!("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5");
+const N: [u8; 32] = hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+
+const REPORT: AuditReport = AuditReport {
+    twist_security: TwistSecurity::Exact(120),
+    embedding_degree: None,
+    cm_discriminant: None,
+};
+
+impl_domain_params_tests!(NistP256, REPORT);
+
+#[test]
+fn from_curve_matches_hex_params() {
+    assert_eq!(
+        DomainParams::from_curve::<NistP256>(),
+        DomainParams::new(&P, &A, &B, &GX, &GY, &N)
+    );
+}
+
+#[test]
+fn reject_invalid_params() {
+    /// Flip the least significant bit of a parameter.
+    fn tweak(mut bytes: [u8; 32]) -> [u8; 32] {
+        bytes[31] ^= 1;
+        bytes
+    }
+
+    let audit = |p: &[u8], a: &[u8], b: &[u8], gx: &[u8], gy: &[u8], n: &[u8]| {
+        DomainParams::new(p, a, b, gx, gy, n).audit()
+    };
+
+    assert_eq!(audit(&P, &A, &B, &GX, &GY, &N), Ok(REPORT));
+    assert_eq!(
+        audit(&tweak(P), &A, &B, &GX, &GY, &N),
+        Err(AuditError::FieldModulusNotPrime)
+    );
+    assert_eq!(
+        audit(&P, &A, &P, &GX, &GY, &N),
+        Err(AuditError::FieldElementNotReduced)
+    );
+    assert_eq!(
+        audit(&P, &[0], &[0], &GX, &GY, &N),
+        Err(AuditError::SingularCurve)
+    );
+    assert_eq!(
+        audit(&P, &A, &tweak(B), &GX, &GY, &N),
+        Err(AuditError::GeneratorNotOnCurve)
+    );
+    assert_eq!(
+        audit(&P, &A, &B, &GX, &GY, &tweak(N)),
+        Err(AuditError::OrderNotPrime)
+    );
+
+    // Prime, but far from p + 1 (secp256k1's group order).
+    let n = hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+    assert_eq!(
+        audit(&P, &A, &B, &GX, &GY, &n),
+        Err(AuditError::OrderOutsideHasseBound)
+    );
+}
+
+#[test]
+fn reject_wrong_order() {
+    // Largest prime below n, which is within the Hasse bound.
+    let mut n = N;
+    loop {
+        let (last, borrow) = n[31].overflowing_sub(2);
+        n[31] = last;
+        assert!(!borrow);
+
+        match DomainParams::new(&P, &A, &B, &GX, &GY, &n).audit() {
+            Err(AuditError::OrderNotPrime) => continue,
+            result => {
+                assert_eq!(result, Err(AuditError::GeneratorOrderMismatch));
+                break;
+            }
+        }
+    }
+}
+
+#[test]
+fn reject_order_with_overflowing_trace() {
+    use primeorder::elliptic_curve::bigint::U640;
+
+    // For n = 2^639 + p + 1 + s, the trace is t = 2^639 + s, so t² wraps
+    // around to s² ≤ 4p modulo 2^640.
+    let mut p = [0u8; 80];
+    p[48..].copy_from_slice(&P);
+    let base = U640::ONE
+        .wrapping_shl_vartime(639)
+        .wrapping_add(&U640::from_be_slice(&p))
+        .wrapping_add(&U640::ONE);
+
+    for s in (1u64..).step_by(2) {
+        let n = base.wrapping_add(&U640::from_u64(s)).to_be_bytes();
+
+        match DomainParams::new(&P, &A, &B, &GX, &GY, &n).audit() {
+            Err(AuditError::OrderNotPrime) => continue,
+            result => {
+                assert_eq!(result, Err(AuditError::OrderOutsideHasseBound));
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Tests for `primeorder::dyn_curve::DynCurve`, using P-256 as a reference.
+
+#![cfg(feature = "ecdsa")]
+
+use elliptic_curve::{bigint::U64, ops::Reduce, sec1::ToEncodedPoint};
+use hex_literal::hex;
+use p256::{
+    ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey},
+    NonZeroScalar, ProjectivePoint, U256,
+};
+use primeorder::dyn_curve::DynCurve;
+use proptest::prelude::*;
+
+const P: [u8; 32] = hex!("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+const A: [u8; 32] = hex!("ffffffff00000001000000000000000000000000fffffffffffffffffffffffc");
+const B: [u8; 32] = hex!("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b");
+const N: [u8; 32] = hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+const G: [u8; 65] = hex!(
+    "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296
+     4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"
+);
+
+fn curve() -> DynCurve<{ U256::LIMBS }> {
+    DynCurve::new(&P, &A, &B, &G, &N).unwrap()
+}
+
+prop_compose! {
+    fn signing_key()(bytes in any::<[u8; 32]>()) -> SigningKey {
+        <NonZeroScalar as Reduce<U256>>::reduce_bytes(&bytes.into()).into()
+    }
+}
+
+#[test]
+fn rejects_invalid_params() {
+    // Generator not on the curve
+    let mut g = G;
+    g[64] ^= 1;
+    assert!(DynCurve::<{ U256::LIMBS }>::new(&P, &A, &B, &g, &N).is_err());
+
+    // Compressed generator
+    assert!(DynCurve::<{ U256::LIMBS }>::new(&P, &A, &B, &G[..33], &N).is_err());
+    let mut g = [0u8; 33];
+    g.copy_from_slice(&G[..33]);
+    g[0] = 0x03;
+    assert!(DynCurve::<{ U256::LIMBS }>::new(&P, &A, &B, &g, &N).is_ok());
+
+    // Wrong order
+    let mut n = N;
+    n[31] ^= 2;
+    assert!(DynCurve::<{ U256::LIMBS }>::new(&P, &A, &B, &G, &n).is_err());
+
+    // Too large for the number of limbs
+    assert!(DynCurve::<{ U256::LIMBS }>::new(&[&[1], &P[..]].concat(), &A, &B, &G, &N).is_err());
+
+    // Smaller than `MIN_BITS`, besides having cofactor 2: the generator has
+    // prime order n = 0x7f13, but the curve has 2n points.
+    assert!(DynCurve::<{ U64::LIMBS }>::new(
+        &hex!("ffef"),
+        &hex!("b8b3"),
+        &hex!("f927"),
+        &hex!("04751ce2d9"),
+        &hex!("7f13")
+    )
+    .is_err());
+}
+
+#[cfg(feature = "pkcs8")]
+#[test]
+fn from_specified() {
+    use p256::pkcs8::der::Decode;
+    use primeorder::specified_curve::SpecifiedCurve;
+
+    let der = include_bytes!("examples/pkcs8-public-key-explicit.der");
+    let mut params = SpecifiedCurve::from_der(&der[17..267]).unwrap();
+    let curve = DynCurve::<{ U256::LIMBS }>::from_specified(&params).unwrap();
+    assert_eq!(curve.order().as_ref(), &U256::from_be_slice(&N));
+
+    params.cofactor = None;
+    assert!(DynCurve::<{ U256::LIMBS }>::from_specified(&params).is_ok());
+
+    params.cofactor = Some(&[2]);
+    assert!(DynCurve::<{ U256::LIMBS }>::from_specified(&params).is_err());
+}
+
+proptest! {
+    #[test]
+    fn public_key(sk in signing_key()) {
+        let secret = sk.to_bytes();
+        let mut pk = [0u8; 65];
+        curve().public_key(&secret, &mut pk).unwrap();
+        let expected = sk.verifying_key().to_encoded_point(false);
+        prop_assert_eq!(&pk[..], expected.as_bytes());
+    }
+
+    #[test]
+    fn diffie_hellman(sk_a in signing_key(), sk_b in signing_key()) {
+        let pk_b = sk_b.verifying_key().to_encoded_point(true);
+        let expected = (ProjectivePoint::from(*sk_b.verifying_key().as_affine())
+            * **sk_a.as_nonzero_scalar())
+        .to_encoded_point(false);
+
+        let secret = sk_a.to_bytes();
+        let mut shared_secret = [0u8; 32];
+        curve()
+            .diffie_hellman(&secret, pk_b.as_bytes(), &mut shared_secret)
+            .unwrap();
+        prop_assert_eq!(&shared_secret[..], expected.x().unwrap().as_slice());
+    }
+
+    #[test]
+    fn verify_prehash(sk in signing_key(), prehash in any::<[u8; 32]>()) {
+        let signature: Signature = sk.sign_prehash(&prehash).unwrap();
+        let (r, s) = signature.split_bytes();
+        let pk = sk.verifying_key().to_encoded_point(false);
+        let curve = curve();
+
+        prop_assert!(curve.verify_prehash(pk.as_bytes(), &prehash, &r, &s).is_ok());
+
+        let mut tampered = prehash;
+        tampered[0] ^= 1;
+        prop_assert!(curve.verify_prehash(pk.as_bytes(), &tampered, &r, &s).is_err());
+        prop_assert!(curve.verify_prehash(pk.as_bytes(), &prehash, &s, &r).is_err());
+    }
+}
@@ -24,3 +24,24 @@ proptest! {
         prop_assert_eq!(sk.verifying_key(), &recovered_vk);
     }
 }
+
+#[cfg(any(feature = "fault-countermeasures", feature = "side-channel-hardening"))]
+proptest! {
+    #[test]
+    fn hardened_signing_key(sk in signing_key()) {
+        use p256::ecdsa::{
+            signature::{RandomizedSigner, Signer, Verifier},
+            HardenedSigningKey, Signature,
+        };
+        use rand_core::OsRng;
+
+        let msg = b"example";
+        let hardened = HardenedSigningKey::from(sk.clone());
+
+        let signature: Signature = hardened.sign(msg);
+        prop_assert_eq!(signature, sk.sign(msg));
+
+        let signature: Signature = hardened.sign_with_rng(&mut OsRng, msg);
+        prop_assert!(sk.verifying_key().verify(msg, &signature).is_ok());
+    }
+}
@@ -8,9 +8,12 @@ use elliptic_curve::{
 };
 use p256::{
     test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS},
-    AffinePoint, ProjectivePoint, Scalar,
+    AffinePoint, NistP256, ProjectivePoint, Scalar,
 };
-use primeorder::{impl_projective_arithmetic_tests, Double};
+use primeorder::{impl_projective_arithmetic_tests, Double, FixedBaseTable};
+
+#[cfg(feature = "side-channel-hardening")]
+use rand_core::OsRng;
 
 impl_projective_arithmetic_tests!(
     AffinePoint,
@@ -20,8 +23,90 @@ impl_projective_arithmetic_tests!(
     MUL_TEST_VECTORS
 );
 
+#[cfg(feature = "ecdh")]
+impl_projective_arithmetic_tests!(
+    diffie_hellman_x: p256::ecdh::diffie_hellman_x,
+    AffinePoint,
+    ProjectivePoint,
+    Scalar,
+    MUL_TEST_VECTORS
+);
+
 #[test]
 fn projective_identity_to_bytes() {
     // This is technically an invalid SEC1 encoding, but is preferable to panicking.
     assert_eq!([0; 33], ProjectivePoint::IDENTITY.to_bytes().as_slice());
 }
+
+#[test]
+fn fixed_base_table() {
+    let x = ProjectivePoint::GENERATOR * Scalar::from(3u64);
+    let y = ProjectivePoint::GENERATOR * Scalar::from(5u64);
+    let tx = FixedBaseTable::<NistP256, 32>::new(&x);
+    let ty = FixedBaseTable::<NistP256, 32>::from(&y);
+
+    for (k, _, _) in MUL_TEST_VECTORS.iter() {
+        let k = Scalar::from_repr((*k).into()).unwrap();
+        let l = -k.square();
+
+        assert_eq!(tx.mul(&k), x * k);
+        assert_eq!(
+            FixedBaseTable::lincomb(&[(&tx, k), (&ty, l)]),
+            x * k + y * l
+        );
+    }
+}
+
+#[cfg(feature = "side-channel-hardening")]
+#[test]
+fn mul_blinded() {
+    let x = ProjectivePoint::GENERATOR * Scalar::from(3u64);
+    let tx = FixedBaseTable::<NistP256, 32>::new(&x);
+
+    for (k, _, _) in MUL_TEST_VECTORS.iter() {
+        let k = Scalar::from_repr((*k).into()).unwrap();
+        assert_eq!(x.mul_blinded(&k, &mut OsRng), x * k);
+        assert_eq!(tx.mul_blinded(&k, &mut OsRng), x * k);
+    }
+
+    for k in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE] {
+        assert_eq!(x.mul_blinded(&k, &mut OsRng), x * k);
+        assert_eq!(tx.mul_blinded(&k, &mut OsRng), x * k);
+    }
+
+    assert_eq!(
+        ProjectivePoint::IDENTITY.mul_blinded(&Scalar::ONE, &mut OsRng),
+        ProjectivePoint::IDENTITY
+    );
+}
+
+#[cfg(all(feature = "ecdh", feature = "side-channel-hardening"))]
+#[test]
+fn diffie_hellman_blinded() {
+    use elliptic_curve::NonZeroScalar;
+    use p256::ecdh::{diffie_hellman, diffie_hellman_blinded, diffie_hellman_x_blinded};
+
+    for (k, _, _) in MUL_TEST_VECTORS.iter() {
+        let k = NonZeroScalar::from_repr((*k).into()).unwrap();
+        let peer = (ProjectivePoint::GENERATOR * Scalar::from(7u64) * *k).to_affine();
+        let expected = diffie_hellman(k, peer).unwrap();
+
+        let shared = diffie_hellman_blinded(k, peer, &mut OsRng).unwrap();
+        assert_eq!(shared.raw_secret_bytes(), expected.raw_secret_bytes());
+
+        let bytes = peer.to_encoded_point(true);
+        let shared = diffie_hellman_x_blinded(k, bytes.as_bytes(), &mut OsRng).unwrap();
+        assert_eq!(shared.raw_secret_bytes(), expected.raw_secret_bytes());
+    }
+}
+
+#[cfg(feature = "ecdh")]
+#[test]
+fn diffie_hellman_rejects_identity() {
+    use elliptic_curve::NonZeroScalar;
+    use p256::ecdh::diffie_hellman;
+
+    let k = NonZeroScalar::<NistP256>::from_repr(MUL_TEST_VECTORS[0].0.into()).unwrap();
+    assert!(diffie_hellman(k, AffinePoint::GENERATOR).is_ok());
+    assert!(diffie_hellman(k, AffinePoint::IDENTITY).is_err());
+}
@@ -0,0 +1,93 @@
+//! Tests for keys which carry explicit domain parameters (`specifiedCurve`).
+
+#![cfg(all(feature = "pkcs8", feature = "arithmetic"))]
+
+use hex_literal::hex;
+use p256::{
+    elliptic_curve::sec1::ToEncodedPoint,
+    pkcs8::{der::Decode, DecodePublicKey},
+    NistP256, PublicKey, SecretKey,
+};
+use primeorder::specified_curve::{
+    DecodeSpecifiedPrivateKey, DecodeSpecifiedPublicKey, SpecifiedCurve,
+};
+
+/// DER-encoded PKCS#8 private key with explicit parameters.
+///
+/// Generated via `openssl ec -param_enc explicit` from `pkcs8-private-key.pem`.
+const PKCS8_PRIVATE_KEY_DER: &[u8; 381] = include_bytes!("examples/pkcs8-private-key-explicit.der");
+
+/// DER-encoded SPKI public key with explicit parameters.
+const PKCS8_PUBLIC_KEY_DER: &[u8; 335] = include_bytes!("examples/pkcs8-public-key-explicit.der");
+
+/// Offset of the last byte of the `b` coefficient in [`PKCS8_PUBLIC_KEY_DER`].
+const EQUATION_B_OFFSET: usize = 138;
+
+/// Offset of the last byte of the `order` in [`PKCS8_PUBLIC_KEY_DER`].
+const ORDER_OFFSET: usize = 263;
+
+#[test]
+fn parse_specified_curve() {
+    let params = SpecifiedCurve::from_der(&PKCS8_PUBLIC_KEY_DER[17..267]).unwrap();
+    assert_eq!(
+        params.p,
+        hex!("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff")
+    );
+    assert_eq!(
+        params.order,
+        hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551")
+    );
+    assert_eq!(params.cofactor, Some(&[1u8][..]));
+    assert!(params.is_curve::<NistP256>());
+}
+
+#[test]
+fn decode_public_key_with_explicit_params() {
+    let public_key = PublicKey::from_specified_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+    let expected_sec1_point = hex!("041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7FC481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F");
+    assert_eq!(
+        public_key.to_encoded_point(false).as_bytes(),
+        &expected_sec1_point[..]
+    );
+}
+
+#[test]
+fn decode_secret_key_with_explicit_params() {
+    let secret_key = SecretKey::from_specified_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+    let expected_scalar = hex!("69624171561A63340DE0E7D869F2A05492558E1A04868B6A9F854A866788188D");
+    assert_eq!(secret_key.to_bytes().as_slice(), &expected_scalar[..]);
+}
+
+#[test]
+fn decode_named_curve_keys() {
+    let public_key =
+        PublicKey::from_specified_public_key_der(include_bytes!("examples/pkcs8-public-key.der"))
+            .unwrap();
+    let secret_key =
+        SecretKey::from_specified_pkcs8_der(include_bytes!("examples/pkcs8-private-key.der"))
+            .unwrap();
+    assert_eq!(secret_key.public_key(), public_key);
+    assert_eq!(
+        public_key,
+        PublicKey::from_public_key_der(include_bytes!("examples/pkcs8-public-key.der")).unwrap()
+    );
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn decode_ecdsa_keys_with_explicit_params() {
+    use p256::ecdsa::{SigningKey, VerifyingKey};
+
+    let signing_key = SigningKey::from_specified_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+    let verifying_key = VerifyingKey::from_specified_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+    assert_eq!(signing_key.verifying_key(), &verifying_key);
+}
+
+#[test]
+fn reject_mismatched_params() {
+    for offset in [EQUATION_B_OFFSET, ORDER_OFFSET] {
+        let mut der = *PKCS8_PUBLIC_KEY_DER;
+        der[offset] ^= 1;
+        assert!(PublicKey::from_specified_public_key_der(&der).is_err());
+    }
+}
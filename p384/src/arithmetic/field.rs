@@ -23,6 +23,14 @@
 #[cfg_attr(target_pointer_width = "64", path = "field/p384_64.rs")]
 mod field_impl;
 
+#[cfg(all(test, target_pointer_width = "64"))]
+#[path = "field/p384_32.rs"]
+mod field_impl_32;
+
+#[cfg(all(test, target_pointer_width = "32"))]
+#[path = "field/p384_64.rs"]
+mod field_impl_64;
+
 use self::field_impl::*;
 use crate::{FieldBytes, NistP384};
 use core::{
@@ -167,17 +175,31 @@ impl Invert for FieldElement {
     fn invert(&self) -> CtOption<Self> {
         self.invert()
     }
+
+    fn invert_vartime(&self) -> CtOption<Self> {
+        self.invert_vartime()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::FieldElement;
     use elliptic_curve::ff::PrimeField;
+    use primefield::{
+        impl_fiat_differential_tests, impl_field_differential_tests,
+        impl_field_sqrt_differential_tests,
+    };
     use primeorder::{
-        impl_field_identity_tests, impl_field_invert_tests, impl_field_sqrt_tests,
+        impl_field_identity_tests, impl_field_invert_tests, impl_field_invert_vartime_tests,
+        impl_field_pow_tests, impl_field_sqrt_ratio_tests, impl_field_sqrt_tests,
         impl_primefield_tests,
     };
 
+    #[cfg(target_pointer_width = "64")]
+    use super::{field_impl as field_impl_64, field_impl_32};
+    #[cfg(target_pointer_width = "32")]
+    use super::{field_impl as field_impl_32, field_impl_64};
+
     /// t = (modulus - 1) >> S
     const T: [u64; 6] = [
         0x000000007fffffff,
@@ -190,6 +212,24 @@ mod tests {
 
     impl_field_identity_tests!(FieldElement);
     impl_field_invert_tests!(FieldElement);
+    impl_field_invert_vartime_tests!(FieldElement);
     impl_field_sqrt_tests!(FieldElement);
+    impl_field_sqrt_ratio_tests!(FieldElement);
+    impl_field_pow_tests!(FieldElement);
+    impl_field_differential_tests!(FieldElement);
+    impl_field_sqrt_differential_tests!(FieldElement);
+    impl_fiat_differential_tests!(
+        FieldElement,
+        [field_impl_32, field_impl_64],
+        fiat_p384_from_bytes,
+        fiat_p384_to_bytes,
+        fiat_p384_to_montgomery,
+        fiat_p384_from_montgomery,
+        fiat_p384_add,
+        fiat_p384_sub,
+        fiat_p384_mul,
+        fiat_p384_square,
+        fiat_p384_opp,
+    );
     impl_primefield_tests!(FieldElement, T);
 }
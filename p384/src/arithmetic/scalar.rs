@@ -21,16 +21,34 @@
 )]
 mod scalar_impl;
 
+#[cfg(all(test, target_pointer_width = "64"))]
+#[path = "scalar/p384_scalar_32.rs"]
+#[allow(
+    clippy::identity_op,
+    clippy::too_many_arguments,
+    clippy::unnecessary_cast
+)]
+mod scalar_impl_32;
+
+#[cfg(all(test, target_pointer_width = "32"))]
+#[path = "scalar/p384_scalar_64.rs"]
+#[allow(
+    clippy::identity_op,
+    clippy::too_many_arguments,
+    clippy::unnecessary_cast
+)]
+mod scalar_impl_64;
+
 use self::scalar_impl::*;
-use crate::{FieldBytes, NistP384, SecretKey, ORDER_HEX, U384};
+use crate::{FieldBytes, NistP384, SecretKey, WideBytes, ORDER_HEX, U384};
 use core::{
     fmt::{self, Debug},
     iter::{Product, Sum},
     ops::{AddAssign, MulAssign, Neg, Shr, ShrAssign, SubAssign},
 };
 use elliptic_curve::{
-    bigint::{ArrayEncoding, Limb},
-    ff::PrimeField,
+    bigint::{ArrayEncoding, Limb, U768},
+    ff::{FromUniformBytes, PrimeField},
     ops::{Invert, Reduce},
     scalar::{FromUintUnchecked, IsHigh},
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, CtOption},
@@ -202,6 +220,10 @@ impl Invert for Scalar {
     fn invert(&self) -> CtOption<Self> {
         self.invert()
     }
+
+    fn invert_vartime(&self) -> CtOption<Self> {
+        self.invert_vartime()
+    }
 }
 
 impl IsHigh for Scalar {
@@ -290,6 +312,25 @@ impl Reduce<U384> for Scalar {
     }
 }
 
+impl Reduce<U768> for Scalar {
+    type Bytes = WideBytes;
+
+    fn reduce(w: U768) -> Self {
+        primefield::reduce_wide(&w)
+    }
+
+    #[inline]
+    fn reduce_bytes(bytes: &WideBytes) -> Self {
+        Self::reduce(U768::from_be_byte_array(*bytes))
+    }
+}
+
+impl FromUniformBytes<96> for Scalar {
+    fn from_uniform_bytes(bytes: &[u8; 96]) -> Self {
+        primefield::reduce_le_bytes(bytes)
+    }
+}
+
 impl From<ScalarPrimitive<NistP384>> for Scalar {
     fn from(w: ScalarPrimitive<NistP384>) -> Self {
         Scalar::from(&w)
@@ -382,12 +423,22 @@ impl<'de> Deserialize<'de> for Scalar {
 mod tests {
     use super::Scalar;
     use crate::FieldBytes;
-    use elliptic_curve::ff::PrimeField;
+    use elliptic_curve::{bigint::U768, ff::PrimeField};
+    use primefield::{
+        impl_fiat_differential_tests, impl_field_differential_tests, impl_field_reduce_wide_tests,
+        impl_field_sqrt_differential_tests,
+    };
     use primeorder::{
-        impl_field_identity_tests, impl_field_invert_tests, impl_field_sqrt_tests,
+        impl_field_identity_tests, impl_field_invert_tests, impl_field_invert_vartime_tests,
+        impl_field_pow_tests, impl_field_sqrt_ratio_tests, impl_field_sqrt_tests,
         impl_primefield_tests,
     };
 
+    #[cfg(target_pointer_width = "64")]
+    use super::{scalar_impl as scalar_impl_64, scalar_impl_32};
+    #[cfg(target_pointer_width = "32")]
+    use super::{scalar_impl as scalar_impl_32, scalar_impl_64};
+
     /// t = (modulus - 1) >> S
     const T: [u64; 6] = [
         0x76760cb5666294b9,
@@ -400,7 +451,26 @@ mod tests {
 
     impl_field_identity_tests!(Scalar);
     impl_field_invert_tests!(Scalar);
+    impl_field_invert_vartime_tests!(Scalar);
     impl_field_sqrt_tests!(Scalar);
+    impl_field_sqrt_ratio_tests!(Scalar);
+    impl_field_pow_tests!(Scalar);
+    impl_field_reduce_wide_tests!(Scalar, U768, 96);
+    impl_field_differential_tests!(Scalar);
+    impl_field_sqrt_differential_tests!(Scalar);
+    impl_fiat_differential_tests!(
+        Scalar,
+        [scalar_impl_32, scalar_impl_64],
+        fiat_p384_scalar_from_bytes,
+        fiat_p384_scalar_to_bytes,
+        fiat_p384_scalar_to_montgomery,
+        fiat_p384_scalar_from_montgomery,
+        fiat_p384_scalar_add,
+        fiat_p384_scalar_sub,
+        fiat_p384_scalar_mul,
+        fiat_p384_scalar_square,
+        fiat_p384_scalar_opp,
+    );
     impl_primefield_tests!(Scalar, T);
 
     #[test]
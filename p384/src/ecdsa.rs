@@ -56,10 +56,24 @@ impl EcdsaCurve for NistP384 {
 #[cfg(feature = "ecdsa")]
 pub type SigningKey = ecdsa_core::SigningKey<NistP384>;
 
+/// ECDSA/P-384 signing key with the countermeasures enabled by the
+/// `side-channel-hardening` and `fault-countermeasures` features, see
+/// [`primeorder::ecdsa`].
+#[cfg(all(
+    feature = "ecdsa",
+    any(feature = "fault-countermeasures", feature = "side-channel-hardening")
+))]
+pub type HardenedSigningKey = primeorder::ecdsa::HardenedSigningKey<NistP384>;
+
 /// ECDSA/P-384 verification key (i.e. public key)
 #[cfg(feature = "ecdsa")]
 pub type VerifyingKey = ecdsa_core::VerifyingKey<NistP384>;
 
+/// ECDSA/P-384 verification key which verifies signatures in variable time,
+/// see [`primeorder::ecdsa::VartimeVerifyingKey`].
+#[cfg(feature = "ecdsa")]
+pub type VartimeVerifyingKey = primeorder::ecdsa::VartimeVerifyingKey<NistP384>;
+
 #[cfg(feature = "sha384")]
 impl ecdsa_core::hazmat::DigestPrimitive for NistP384 {
     type Digest = sha2::Sha384;
@@ -174,6 +188,11 @@ mod tests {
         ecdsa_core::new_verification_test!(NistP384, ECDSA_TEST_VECTORS);
     }
 
+    mod vartime_verify {
+        use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, NistP384};
+        primeorder::impl_vartime_verifying_key_tests!(NistP384, ECDSA_TEST_VECTORS);
+    }
+
     mod wycheproof {
         use crate::NistP384;
         ecdsa_core::new_wycheproof_test!(wycheproof, "wycheproof", NistP384);
@@ -42,7 +42,12 @@ pub use arithmetic::field::FieldElement;
 #[cfg(feature = "pkcs8")]
 pub use elliptic_curve::pkcs8;
 
-use elliptic_curve::{array::Array, bigint::ArrayEncoding, consts::U49, FieldBytesEncoding};
+use elliptic_curve::{
+    array::Array,
+    bigint::ArrayEncoding,
+    consts::{U49, U96},
+    FieldBytesEncoding,
+};
 
 /// Order of NIST P-384's elliptic curve group (i.e. scalar modulus) in hexadecimal.
 const ORDER_HEX: &str = "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973";
@@ -96,6 +101,9 @@ pub type EncodedPoint = elliptic_curve::sec1::EncodedPoint<NistP384>;
 /// scalar).
 pub type FieldBytes = elliptic_curve::FieldBytes<NistP384>;
 
+/// Bytes used by a wide reduction: twice the width of [`FieldBytes`].
+pub type WideBytes = Array<u8, U96>;
+
 impl FieldBytesEncoding<NistP384> for U384 {
     fn decode_field_bytes(field_bytes: &FieldBytes) -> Self {
         U384::from_be_byte_array(*field_bytes)
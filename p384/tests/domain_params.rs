@@ -0,0 +1,18 @@
+//! Domain parameter audit.
+
+#![cfg(feature = "arithmetic")]
+
+use p384::NistP384;
+use primeorder::{
+    dev::{AuditReport, TwistSecurity},
+    impl_domain_params_tests,
+};
+
+impl_domain_params_tests!(
+    NistP384,
+    AuditReport {
+        twist_security: TwistSecurity::Exact(192),
+        embedding_degree: None,
+        cm_discriminant: None,
+    }
+);
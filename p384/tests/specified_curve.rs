@@ -0,0 +1,43 @@
+//! Tests for keys which carry explicit domain parameters (`specifiedCurve`).
+
+#![cfg(all(feature = "pkcs8", feature = "arithmetic"))]
+
+use hex_literal::hex;
+use p384::{elliptic_curve::sec1::ToEncodedPoint, PublicKey, SecretKey};
+use primeorder::specified_curve::{DecodeSpecifiedPrivateKey, DecodeSpecifiedPublicKey};
+
+/// DER-encoded PKCS#8 private key with explicit parameters.
+///
+/// Generated via `openssl ecparam -name secp384r1 -genkey -param_enc explicit`.
+const PKCS8_PRIVATE_KEY_DER: &[u8; 528] = include_bytes!("examples/pkcs8-private-key-explicit.der");
+
+/// DER-encoded SPKI public key with explicit parameters.
+const PKCS8_PUBLIC_KEY_DER: &[u8; 464] = include_bytes!("examples/pkcs8-public-key-explicit.der");
+
+/// Offset of the last byte of the `b` coefficient in [`PKCS8_PUBLIC_KEY_DER`].
+const EQUATION_B_OFFSET: usize = 187;
+
+/// Offset of the last byte of the `order` in [`PKCS8_PUBLIC_KEY_DER`].
+const ORDER_OFFSET: usize = 360;
+
+#[test]
+fn decode_keys_with_explicit_params() {
+    let secret_key = SecretKey::from_specified_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+    let public_key = PublicKey::from_specified_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+
+    let expected_scalar = hex!("0904cb57489a9f9f4561a348664b6f73aa02c3a64ce24db387a5d751ba622f7794b903d00b956f10f71b779465ac3303");
+    assert_eq!(secret_key.to_bytes().as_slice(), &expected_scalar[..]);
+    assert_eq!(
+        secret_key.public_key().to_encoded_point(false),
+        public_key.to_encoded_point(false)
+    );
+}
+
+#[test]
+fn reject_mismatched_params() {
+    for offset in [EQUATION_B_OFFSET, ORDER_OFFSET] {
+        let mut der = *PKCS8_PUBLIC_KEY_DER;
+        der[offset] ^= 1;
+        assert!(PublicKey::from_specified_public_key_der(&der).is_err());
+    }
+}
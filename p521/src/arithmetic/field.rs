@@ -51,6 +51,8 @@ pub(crate) const MODULUS: U576 = U576::from_be_hex("00000000000001ffffffffffffff
 #[derive(Clone, Copy)]
 pub struct FieldElement(pub(crate) fiat_p521_tight_field_element);
 
+primefield::impl_batch_invert!(FieldElement);
+
 impl FieldElement {
     /// Zero element.
     pub const ZERO: Self = Self::from_u64(0);
@@ -249,6 +251,23 @@ impl FieldElement {
         CtOption::new(self.invert_unchecked(), !self.is_zero())
     }
 
+    /// Compute [`FieldElement`] inversion in variable time: `1 / self`.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This method should not be used with secret values, as its variable-time
+    /// operation can potentially leak secrets through sidechannels.
+    pub fn invert_vartime(&self) -> CtOption<Self> {
+        let inverse = primefield::invert_vartime(
+            &<U576 as FieldBytesEncoding<NistP521>>::decode_field_bytes(&self.to_bytes()),
+            &MODULUS,
+        );
+        CtOption::new(
+            Self::from_uint_unchecked(inverse.unwrap_or(U576::ZERO)),
+            Choice::from(u8::from(inverse.is_some())),
+        )
+    }
+
     /// Returns the multiplicative inverse of self.
     ///
     /// Does not check that self is non-zero.
@@ -642,6 +661,10 @@ impl Invert for FieldElement {
     fn invert(&self) -> CtOption<Self> {
         self.invert()
     }
+
+    fn invert_vartime(&self) -> CtOption<Self> {
+        self.invert_vartime()
+    }
 }
 
 #[cfg(test)]
@@ -650,8 +673,9 @@ mod tests {
     use elliptic_curve::ff::PrimeField;
     use hex_literal::hex;
     use primefield::{
-        impl_field_identity_tests, impl_field_invert_tests, impl_field_sqrt_tests,
-        impl_primefield_tests,
+        impl_field_batch_invert_tests, impl_field_differential_tests, impl_field_identity_tests,
+        impl_field_invert_tests, impl_field_invert_vartime_tests,
+        impl_field_sqrt_differential_tests, impl_field_sqrt_tests, impl_primefield_tests,
     };
 
     /// t = (modulus - 1) >> S
@@ -669,7 +693,11 @@ mod tests {
 
     impl_field_identity_tests!(FieldElement);
     impl_field_invert_tests!(FieldElement);
+    impl_field_invert_vartime_tests!(FieldElement);
+    impl_field_batch_invert_tests!(FieldElement);
     impl_field_sqrt_tests!(FieldElement);
+    impl_field_differential_tests!(FieldElement);
+    impl_field_sqrt_differential_tests!(FieldElement);
     impl_primefield_tests!(FieldElement, T);
 
     /// Regression test for RustCrypto/elliptic-curves#965
@@ -15,15 +15,15 @@
 mod scalar_impl;
 
 use self::scalar_impl::*;
-use crate::{FieldBytes, NistP521, SecretKey, U576};
+use crate::{FieldBytes, NistP521, SecretKey, WideBytes, U576};
 use core::{
     iter::{Product, Sum},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Shr, ShrAssign, SubAssign},
 };
 use elliptic_curve::{
     array::Array,
-    bigint::{self, Integer},
-    ff::{self, Field, PrimeField},
+    bigint::{self, ArrayEncoding, Integer, U1024},
+    ff::{self, Field, FromUniformBytes, PrimeField},
     ops::{Invert, Reduce},
     rand_core::RngCore,
     scalar::{FromUintUnchecked, IsHigh},
@@ -74,6 +74,8 @@ use super::util::{u32x18_to_u64x9, u64x9_to_u32x18};
 #[derive(Clone, Copy, Debug, PartialOrd, Ord)]
 pub struct Scalar(fiat_p521_scalar_montgomery_domain_field_element);
 
+primefield::impl_batch_invert!(Scalar);
+
 impl Scalar {
     /// Zero element.
     pub const ZERO: Self = Self::from_u64(0);
@@ -228,6 +230,20 @@ impl Scalar {
         CtOption::new(self.invert_unchecked(), !self.is_zero())
     }
 
+    /// Compute [`Scalar`] inversion in variable time: `1 / self`.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This method should not be used with secret values, as its variable-time
+    /// operation can potentially leak secrets through sidechannels.
+    pub fn invert_vartime(&self) -> CtOption<Self> {
+        let inverse = primefield::invert_vartime(&self.to_canonical(), &NistP521::ORDER);
+        CtOption::new(
+            Self::from_uint_unchecked(inverse.unwrap_or(U576::ZERO)),
+            Choice::from(u8::from(inverse.is_some())),
+        )
+    }
+
     /// Compute [`Scalar`] inversion: `1 / self`.
     ///
     /// Does not check that self is non-zero.
@@ -516,6 +532,10 @@ impl Invert for Scalar {
     fn invert(&self) -> CtOption<Self> {
         self.invert()
     }
+
+    fn invert_vartime(&self) -> CtOption<Self> {
+        self.invert_vartime()
+    }
 }
 
 impl IsHigh for Scalar {
@@ -605,6 +625,25 @@ impl Reduce<U576> for Scalar {
     }
 }
 
+impl Reduce<U1024> for Scalar {
+    type Bytes = WideBytes;
+
+    fn reduce(w: U1024) -> Self {
+        primefield::reduce_wide(&w)
+    }
+
+    #[inline]
+    fn reduce_bytes(bytes: &WideBytes) -> Self {
+        Self::reduce(U1024::from_be_byte_array(*bytes))
+    }
+}
+
+impl FromUniformBytes<132> for Scalar {
+    fn from_uniform_bytes(bytes: &[u8; 132]) -> Self {
+        primefield::reduce_le_bytes(bytes)
+    }
+}
+
 impl From<ScalarPrimitive<NistP521>> for Scalar {
     fn from(w: ScalarPrimitive<NistP521>) -> Self {
         Scalar::from(&w)
@@ -690,8 +729,12 @@ impl<'de> Deserialize<'de> for Scalar {
 #[cfg(test)]
 mod tests {
     use super::Scalar;
-    use elliptic_curve::PrimeField;
-    use primefield::{impl_field_identity_tests, impl_field_invert_tests, impl_primefield_tests};
+    use elliptic_curve::{bigint::U1024, PrimeField};
+    use primefield::{
+        impl_field_batch_invert_tests, impl_field_differential_tests, impl_field_identity_tests,
+        impl_field_invert_tests, impl_field_invert_vartime_tests, impl_field_reduce_wide_tests,
+        impl_primefield_tests,
+    };
 
     /// t = (modulus - 1) >> S
     const T: [u64; 9] = [
@@ -708,5 +751,9 @@ mod tests {
 
     impl_field_identity_tests!(Scalar);
     impl_field_invert_tests!(Scalar);
+    impl_field_invert_vartime_tests!(Scalar);
+    impl_field_batch_invert_tests!(Scalar);
+    impl_field_reduce_wide_tests!(Scalar, U1024, 132);
+    impl_field_differential_tests!(Scalar);
     impl_primefield_tests!(Scalar, T);
 }
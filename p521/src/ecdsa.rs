@@ -56,10 +56,24 @@ impl EcdsaCurve for NistP521 {
 #[cfg(feature = "ecdsa")]
 pub type SigningKey = ecdsa_core::SigningKey<NistP521>;
 
+/// ECDSA/P-521 signing key with the countermeasures enabled by the
+/// `side-channel-hardening` and `fault-countermeasures` features, see
+/// [`primeorder::ecdsa`].
+#[cfg(all(
+    feature = "ecdsa",
+    any(feature = "fault-countermeasures", feature = "side-channel-hardening")
+))]
+pub type HardenedSigningKey = primeorder::ecdsa::HardenedSigningKey<NistP521>;
+
 /// ECDSA/P-521 verification key (i.e. public key)
 #[cfg(feature = "ecdsa")]
 pub type VerifyingKey = ecdsa_core::VerifyingKey<NistP521>;
 
+/// ECDSA/P-521 verification key which verifies signatures in variable time,
+/// see [`primeorder::ecdsa::VartimeVerifyingKey`].
+#[cfg(feature = "ecdsa")]
+pub type VartimeVerifyingKey = primeorder::ecdsa::VartimeVerifyingKey<NistP521>;
+
 #[cfg(feature = "sha512")]
 impl ecdsa_core::hazmat::DigestPrimitive for NistP521 {
     type Digest = sha2::Sha512;
@@ -97,6 +111,11 @@ mod tests {
         ecdsa_core::new_verification_test!(NistP521, ECDSA_TEST_VECTORS);
     }
 
+    mod vartime_verify {
+        use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, NistP521};
+        primeorder::impl_vartime_verifying_key_tests!(NistP521, ECDSA_TEST_VECTORS);
+    }
+
     mod wycheproof {
         use crate::NistP521;
         ecdsa_core::new_wycheproof_test!(wycheproof, "wycheproof", NistP521);
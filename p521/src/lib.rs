@@ -48,7 +48,11 @@ pub use elliptic_curve::{self, bigint::U576};
 #[cfg(feature = "pkcs8")]
 pub use elliptic_curve::pkcs8;
 
-use elliptic_curve::{array::Array, consts::U66, FieldBytesEncoding};
+use elliptic_curve::{
+    array::Array,
+    consts::{U128, U66},
+    FieldBytesEncoding,
+};
 
 /// NIST P-521 elliptic curve.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
@@ -99,6 +103,10 @@ pub type EncodedPoint = elliptic_curve::sec1::EncodedPoint<NistP521>;
 /// scalar).
 pub type FieldBytes = elliptic_curve::FieldBytes<NistP521>;
 
+/// Bytes used by a wide reduction: a 1024-bit integer, at least 128 bits
+/// wider than the order.
+pub type WideBytes = Array<u8, U128>;
+
 impl FieldBytesEncoding<NistP521> for U576 {}
 
 /// Non-zero NIST P-521 scalar field element.
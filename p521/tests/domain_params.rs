@@ -0,0 +1,18 @@
+//! Domain parameter audit.
+
+#![cfg(feature = "arithmetic")]
+
+use p521::NistP521;
+use primeorder::{
+    dev::{AuditReport, TwistSecurity},
+    impl_domain_params_tests,
+};
+
+impl_domain_params_tests!(
+    NistP521,
+    AuditReport {
+        twist_security: TwistSecurity::Exact(230),
+        embedding_degree: None,
+        cm_discriminant: None,
+    }
+);
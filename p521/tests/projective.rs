@@ -19,3 +19,12 @@ impl_projective_arithmetic_tests!(
     ADD_TEST_VECTORS,
     MUL_TEST_VECTORS
 );
+
+#[cfg(feature = "ecdh")]
+impl_projective_arithmetic_tests!(
+    diffie_hellman_x: p521::ecdh::diffie_hellman_x,
+    AffinePoint,
+    ProjectivePoint,
+    Scalar,
+    MUL_TEST_VECTORS
+);
@@ -0,0 +1,43 @@
+//! Tests for keys which carry explicit domain parameters (`specifiedCurve`).
+
+#![cfg(all(feature = "pkcs8", feature = "arithmetic"))]
+
+use hex_literal::hex;
+use p521::{elliptic_curve::sec1::ToEncodedPoint, PublicKey, SecretKey};
+use primeorder::specified_curve::{DecodeSpecifiedPrivateKey, DecodeSpecifiedPublicKey};
+
+/// DER-encoded PKCS#8 private key with explicit parameters.
+///
+/// Generated via `openssl ecparam -name secp521r1 -genkey -param_enc explicit`.
+const PKCS8_PRIVATE_KEY_DER: &[u8; 692] = include_bytes!("examples/pkcs8-private-key-explicit.der");
+
+/// DER-encoded SPKI public key with explicit parameters.
+const PKCS8_PUBLIC_KEY_DER: &[u8; 609] = include_bytes!("examples/pkcs8-public-key-explicit.der");
+
+/// Offset of the last byte of the `b` coefficient in [`PKCS8_PUBLIC_KEY_DER`].
+const EQUATION_B_OFFSET: usize = 241;
+
+/// Offset of the last byte of the `order` in [`PKCS8_PUBLIC_KEY_DER`].
+const ORDER_OFFSET: usize = 468;
+
+#[test]
+fn decode_keys_with_explicit_params() {
+    let secret_key = SecretKey::from_specified_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+    let public_key = PublicKey::from_specified_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+
+    let expected_scalar = hex!("01dbb0e7e7c19d4beeff7e10bcbe319ec943d349febfe2a047ae1ca8ab87db922675959e87165e80038485f3a0da447a1ed7237a385622c64a2cc3ebce0d0f41ae9f");
+    assert_eq!(secret_key.to_bytes().as_slice(), &expected_scalar[..]);
+    assert_eq!(
+        secret_key.public_key().to_encoded_point(false),
+        public_key.to_encoded_point(false)
+    );
+}
+
+#[test]
+fn reject_mismatched_params() {
+    for offset in [EQUATION_B_OFFSET, ORDER_OFFSET] {
+        let mut der = *PKCS8_PUBLIC_KEY_DER;
+        der[offset] ^= 1;
+        assert!(PublicKey::from_specified_public_key_der(&der).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+//! Batch inversion using Montgomery's trick.
+//!
+//! See: <https://iacr.org/archive/pkc2004/29470042/29470042.pdf> section 2.2.
+
+use elliptic_curve::{ff::Field, subtle::Choice};
+
+#[cfg(feature = "alloc")]
+use alloc::vec;
+
+/// Invert all elements of `elements` in place in constant time, using
+/// `scratch` to hold the partial products.
+///
+/// Zero elements are left as zero. Returns a truthy [`Choice`] if all of the
+/// elements were nonzero.
+///
+/// # Panics
+///
+/// If `elements` and `scratch` have different lengths.
+pub fn batch_invert_with_scratch<F: Field>(elements: &mut [F], scratch: &mut [F]) -> Choice {
+    assert_eq!(elements.len(), scratch.len());
+
+    let mut acc = F::ONE;
+    let mut all_nonzero = Choice::from(1);
+
+    for (element, partial) in elements.iter().zip(scratch.iter_mut()) {
+        let is_zero = element.is_zero();
+        all_nonzero &= !is_zero;
+        *partial = acc;
+        acc = F::conditional_select(&(acc * element), &acc, is_zero);
+    }
+
+    // `acc` is a product of nonzero elements, so it's always invertible.
+    let mut acc = acc.invert().unwrap();
+
+    for (element, partial) in elements.iter_mut().zip(scratch.iter()).rev() {
+        let is_zero = element.is_zero();
+        let inverse = acc * partial;
+        acc = F::conditional_select(&(acc * *element), &acc, is_zero);
+        *element = F::conditional_select(&inverse, element, is_zero);
+    }
+
+    all_nonzero
+}
+
+/// Invert all elements of an array in place in constant time.
+///
+/// See [`batch_invert_with_scratch`].
+pub fn batch_invert_array<F: Field, const N: usize>(elements: &mut [F; N]) -> Choice {
+    let mut scratch = [F::ONE; N];
+    batch_invert_with_scratch(elements, &mut scratch)
+}
+
+/// Invert all elements of a slice in place in constant time.
+///
+/// See [`batch_invert_with_scratch`].
+#[cfg(feature = "alloc")]
+pub fn batch_invert<F: Field>(elements: &mut [F]) -> Choice {
+    let mut scratch = vec![F::ONE; elements.len()];
+    batch_invert_with_scratch(elements, &mut scratch)
+}
+
+/// Add inherent batch inversion methods to the given field element type.
+///
+/// `batch_invert` is only emitted when the `alloc` feature of this crate is
+/// enabled.
+#[macro_export]
+macro_rules! impl_batch_invert {
+    ($fe:tt) => {
+        impl $fe {
+            /// Invert all elements of an array in place in constant time using
+            /// Montgomery's trick, at the cost of a single inversion.
+            ///
+            /// Zero elements are left as zero. Returns a truthy `Choice` if
+            /// all of the elements were nonzero.
+            pub fn batch_invert_array<const N: usize>(
+                elements: &mut [Self; N],
+            ) -> $crate::elliptic_curve::subtle::Choice {
+                $crate::batch_invert_array(elements)
+            }
+
+            $crate::__impl_batch_invert_alloc!();
+        }
+    };
+}
+
+/// Emit the allocating `batch_invert` method for [`impl_batch_invert!`].
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_batch_invert_alloc {
+    () => {
+        /// Invert all elements of a slice in place in constant time using
+        /// Montgomery's trick, at the cost of a single inversion.
+        ///
+        /// Zero elements are left as zero. Returns a truthy `Choice` if all
+        /// of the elements were nonzero.
+        pub fn batch_invert(elements: &mut [Self]) -> $crate::elliptic_curve::subtle::Choice {
+            $crate::batch_invert(elements)
+        }
+    };
+}
+
+/// Emit the allocating `batch_invert` method for [`impl_batch_invert!`].
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_batch_invert_alloc {
+    () => {};
+}
@@ -0,0 +1,333 @@
+//! Development-related functionality.
+//!
+//! Provides a differential test harness which checks field implementations
+//! against reference arithmetic computed with [`num_bigint`], using
+//! [`proptest`] to generate inputs.
+
+pub use num_bigint;
+pub use proptest;
+
+use elliptic_curve::ff::PrimeField;
+use num_bigint::BigUint;
+use proptest::prelude::*;
+
+/// Get the modulus of `F` as a [`BigUint`].
+pub fn modulus<F: PrimeField>() -> BigUint {
+    BigUint::parse_bytes(F::MODULUS.as_bytes(), 16).expect("invalid modulus")
+}
+
+/// Convert a field element into a [`BigUint`].
+pub fn to_biguint<F: PrimeField>(x: &F) -> BigUint {
+    let repr = x.to_repr();
+
+    if is_le::<F>() {
+        BigUint::from_bytes_le(repr.as_ref())
+    } else {
+        BigUint::from_bytes_be(repr.as_ref())
+    }
+}
+
+/// Convert a [`BigUint`] into a field element, reducing it modulo the modulus
+/// of `F` first.
+pub fn from_biguint<F: PrimeField>(n: &BigUint) -> F {
+    F::from_repr(to_repr::<F>(&(n % modulus::<F>()))).unwrap()
+}
+
+/// Serialize `n` as a little-endian byte array, e.g. for passing to a
+/// fiat-crypto `from_bytes` function.
+///
+/// Panics if `n` doesn't fit in `N` bytes.
+pub fn to_le_bytes<const N: usize>(n: &BigUint) -> [u8; N] {
+    let bytes = n.to_bytes_le();
+    let mut out = [0u8; N];
+    out[..bytes.len()].copy_from_slice(&bytes);
+    out
+}
+
+/// Strategy for integers which fit in the byte length of [`PrimeField::Repr`].
+pub fn uint<F: PrimeField>() -> impl Strategy<Value = BigUint> {
+    let len = F::Repr::default().as_ref().len();
+    prop::collection::vec(any::<u8>(), len).prop_map(|bytes| BigUint::from_bytes_le(&bytes))
+}
+
+/// Strategy for candidate encodings of field elements: arbitrary integers,
+/// integers less than the modulus, and the values around the modulus.
+pub fn repr_uint<F: PrimeField>() -> impl Strategy<Value = BigUint> {
+    let p = modulus::<F>();
+
+    prop_oneof![
+        Just(&p - 1u32),
+        Just(p.clone()),
+        uint::<F>(),
+        uint::<F>().prop_map(move |n| n % &p),
+    ]
+}
+
+/// Strategy for field elements, including `0`, `1` and `-1`.
+///
+/// All elements are decoded from their canonical encoding, so implementations
+/// which reduce lazily (e.g. `k256`) receive fully normalized inputs.
+pub fn field_element<F: PrimeField>() -> impl Strategy<Value = F> {
+    prop_oneof![
+        1 => Just(F::ZERO),
+        1 => Just(F::ONE),
+        1 => Just(from_biguint::<F>(&(modulus::<F>() - 1u32))),
+        7 => uint::<F>().prop_map(|n| from_biguint::<F>(&n)),
+    ]
+}
+
+/// Check addition, subtraction, multiplication, squaring, doubling and
+/// negation against the reference implementation.
+pub fn check_arithmetic<F: PrimeField>(a: F, b: F) {
+    let p = modulus::<F>();
+    let x = to_biguint(&a);
+    let y = to_biguint(&b);
+
+    assert_eq!(to_biguint(&(a + b)), (&x + &y) % &p, "add");
+    assert_eq!(to_biguint(&(a - b)), (&x + &p - &y) % &p, "sub");
+    assert_eq!(to_biguint(&(a * b)), (&x * &y) % &p, "mul");
+    assert_eq!(to_biguint(&a.square()), (&x * &x) % &p, "square");
+    assert_eq!(to_biguint(&a.double()), (&x << 1) % &p, "double");
+    assert_eq!(to_biguint(&-a), (&p - &x) % &p, "neg");
+}
+
+/// Check inversion against the reference implementation.
+pub fn check_invert<F: PrimeField>(a: F) {
+    let p = modulus::<F>();
+    let x = to_biguint(&a);
+
+    match Option::<F>::from(a.invert()) {
+        Some(inv) => {
+            assert_ne!(x, BigUint::ZERO, "inverted zero");
+            assert_eq!(to_biguint(&inv), x.modpow(&(&p - 2u32), &p), "invert");
+        }
+        None => assert_eq!(x, BigUint::ZERO, "failed to invert nonzero element"),
+    }
+}
+
+/// Check square roots against the reference implementation, using Euler's
+/// criterion to decide which elements are squares.
+pub fn check_sqrt<F: PrimeField>(a: F) {
+    let p = modulus::<F>();
+    let x = to_biguint(&a);
+    let is_square = x.modpow(&(&p >> 1), &p) != &p - 1u32;
+
+    match Option::<F>::from(a.sqrt()) {
+        Some(root) => {
+            assert!(is_square, "square root of non-square");
+            let root = to_biguint(&root);
+            assert_eq!((&root * &root) % &p, x, "sqrt");
+        }
+        None => assert!(!is_square, "no square root of square"),
+    }
+}
+
+/// Check decoding of the integer `n` with [`PrimeField::from_repr`], which
+/// must succeed if and only if `n` is less than the modulus, and encoding with
+/// [`PrimeField::to_repr`].
+pub fn check_from_repr<F: PrimeField>(n: &BigUint) {
+    let repr = to_repr::<F>(n);
+
+    match Option::<F>::from(F::from_repr(repr)) {
+        Some(x) => {
+            assert!(n < &modulus::<F>(), "decoded non-canonical encoding");
+            assert_eq!(&to_biguint(&x), n, "from_repr");
+            assert_eq!(x.to_repr().as_ref(), repr.as_ref(), "to_repr");
+        }
+        None => assert!(n >= &modulus::<F>(), "failed to decode canonical encoding"),
+    }
+}
+
+/// Functions of a fiat-crypto Montgomery backend, for use with
+/// [`check_fiat_backend`].
+///
+/// `W` is the backend's word array type and `N` the length of its
+/// little-endian byte encoding.
+pub struct FiatBackend<W, const N: usize> {
+    /// Name of the backend, used in failure messages.
+    pub name: &'static str,
+    /// `from_bytes` function.
+    pub from_bytes: fn(&[u8; N]) -> W,
+    /// `to_bytes` function.
+    pub to_bytes: fn(&W) -> [u8; N],
+    /// `to_montgomery` function.
+    pub to_montgomery: fn(&W) -> W,
+    /// `from_montgomery` function.
+    pub from_montgomery: fn(&W) -> W,
+    /// `add` function.
+    pub add: fn(&W, &W) -> W,
+    /// `sub` function.
+    pub sub: fn(&W, &W) -> W,
+    /// `mul` function.
+    pub mul: fn(&W, &W) -> W,
+    /// `square` function.
+    pub square: fn(&W) -> W,
+    /// `opp` function.
+    pub opp: fn(&W) -> W,
+}
+
+/// Check the arithmetic of a fiat-crypto backend for the field `F` against
+/// the reference implementation.
+///
+/// The backend's functions are called through opaque function pointers, so
+/// each backend is compiled once rather than inlined into every test which
+/// checks it.
+#[inline(never)]
+pub fn check_fiat_backend<F: PrimeField, W, const N: usize>(
+    backend: &FiatBackend<W, N>,
+    a: F,
+    b: F,
+) {
+    let backend = core::hint::black_box(backend);
+    let name = backend.name;
+    let to_mont = |n: &BigUint| (backend.to_montgomery)(&(backend.from_bytes)(&to_le_bytes(n)));
+    let from_mont =
+        |w: W| BigUint::from_bytes_le(&(backend.to_bytes)(&(backend.from_montgomery)(&w)));
+
+    let p = modulus::<F>();
+    let x = to_biguint(&a);
+    let y = to_biguint(&b);
+    let a = to_mont(&x);
+    let b = to_mont(&y);
+
+    assert_eq!(from_mont(to_mont(&x)), x, "{}: montgomery roundtrip", name);
+    assert_eq!(
+        from_mont((backend.add)(&a, &b)),
+        (&x + &y) % &p,
+        "{}: add",
+        name
+    );
+    assert_eq!(
+        from_mont((backend.sub)(&a, &b)),
+        (&x + &p - &y) % &p,
+        "{}: sub",
+        name
+    );
+    assert_eq!(
+        from_mont((backend.mul)(&a, &b)),
+        (&x * &y) % &p,
+        "{}: mul",
+        name
+    );
+    assert_eq!(
+        from_mont((backend.square)(&a)),
+        (&x * &x) % &p,
+        "{}: square",
+        name
+    );
+    assert_eq!(
+        from_mont((backend.opp)(&a)),
+        (&p - &x) % &p,
+        "{}: opp",
+        name
+    );
+}
+
+/// Is [`PrimeField::Repr`] little-endian?
+fn is_le<F: PrimeField>() -> bool {
+    F::ONE.to_repr().as_ref()[0] == 1
+}
+
+/// Encode `n` as [`PrimeField::Repr`] without checking it's in range.
+fn to_repr<F: PrimeField>(n: &BigUint) -> F::Repr {
+    let mut repr = F::Repr::default();
+    let bytes = n.to_bytes_le();
+    repr.as_mut()[..bytes.len()].copy_from_slice(&bytes);
+
+    if !is_le::<F>() {
+        repr.as_mut().reverse();
+    }
+
+    repr
+}
+
+/// Implement differential tests of field arithmetic, inversion and encoding
+/// against `num-bigint`.
+#[macro_export]
+macro_rules! impl_field_differential_tests {
+    ($fe:ty) => {
+        $crate::dev::proptest::proptest! {
+            #[test]
+            fn differential_arithmetic(
+                a in $crate::dev::field_element::<$fe>(),
+                b in $crate::dev::field_element::<$fe>()
+            ) {
+                $crate::dev::check_arithmetic(a, b);
+            }
+
+            #[test]
+            fn differential_invert(a in $crate::dev::field_element::<$fe>()) {
+                $crate::dev::check_invert(a);
+            }
+
+            #[test]
+            fn differential_from_repr(n in $crate::dev::repr_uint::<$fe>()) {
+                $crate::dev::check_from_repr::<$fe>(&n);
+            }
+        }
+    };
+}
+
+/// Implement differential tests of square roots against `num-bigint`.
+#[macro_export]
+macro_rules! impl_field_sqrt_differential_tests {
+    ($fe:ty) => {
+        $crate::dev::proptest::proptest! {
+            #[test]
+            fn differential_sqrt(a in $crate::dev::field_element::<$fe>()) {
+                $crate::dev::check_sqrt(a);
+            }
+        }
+    };
+}
+
+/// Implement differential tests which cross-check fiat-crypto backends, e.g.
+/// the 32-bit and 64-bit ones, against each other and `num-bigint` in a
+/// single run.
+///
+/// Each backend is given as the name of a module in scope, followed by the
+/// names of its `from_bytes`, `to_bytes`, `to_montgomery`, `from_montgomery`,
+/// `add`, `sub`, `mul`, `square` and `opp` functions.
+#[macro_export]
+macro_rules! impl_fiat_differential_tests {
+    (
+        $fe:ty,
+        [$($backend:ident),+],
+        $from_bytes:ident,
+        $to_bytes:ident,
+        $to_mont:ident,
+        $from_mont:ident,
+        $add:ident,
+        $sub:ident,
+        $mul:ident,
+        $square:ident,
+        $opp:ident $(,)?
+    ) => {
+        $crate::dev::proptest::proptest! {
+            #[test]
+            fn differential_fiat_backends(
+                a in $crate::dev::field_element::<$fe>(),
+                b in $crate::dev::field_element::<$fe>()
+            ) {
+                $(
+                    $crate::dev::check_fiat_backend(
+                        &$crate::dev::FiatBackend {
+                            name: stringify!($backend),
+                            from_bytes: $backend::$from_bytes,
+                            to_bytes: $backend::$to_bytes,
+                            to_montgomery: $backend::$to_mont,
+                            from_montgomery: $backend::$from_mont,
+                            add: $backend::$add,
+                            sub: $backend::$sub,
+                            mul: $backend::$mul,
+                            square: $backend::$square,
+                            opp: $backend::$opp,
+                        },
+                        a,
+                        b,
+                    );
+                )+
+            }
+        }
+    };
+}
@@ -0,0 +1,66 @@
+//! Variable-time inversion.
+
+use elliptic_curve::bigint::Uint;
+
+/// Compute the inverse of `x` modulo the odd prime `modulus` in variable time
+/// using the binary extended Euclidean algorithm (Stein's algorithm).
+///
+/// `x` must be fully reduced, i.e. less than `modulus`. Returns `None` if `x`
+/// is zero.
+///
+/// <https://link.springer.com/article/10.1007/s13389-016-0135-4>
+///
+/// ⚠️ WARNING!
+///
+/// This function should not be used with secret values, as its variable-time
+/// operation can potentially leak secrets through sidechannels.
+pub fn invert_vartime<const LIMBS: usize>(
+    x: &Uint<LIMBS>,
+    modulus: &Uint<LIMBS>,
+) -> Option<Uint<LIMBS>> {
+    if x == &Uint::ZERO {
+        return None;
+    }
+
+    // (modulus + 1) / 2, i.e. the inverse of 2
+    let two_inv = modulus.wrapping_shr_vartime(1).wrapping_add(&Uint::ONE);
+
+    // Halve `n` modulo `modulus`.
+    let half = |n: Uint<LIMBS>| {
+        let shifted = n.wrapping_shr_vartime(1);
+        if n.bit_vartime(0) {
+            shifted.wrapping_add(&two_inv)
+        } else {
+            shifted
+        }
+    };
+
+    // Invariants: a * x = u and c * x = v (mod modulus)
+    let mut u = *x;
+    let mut v = *modulus;
+    let mut a = Uint::ONE;
+    let mut c = Uint::ZERO;
+
+    while u != Uint::ZERO {
+        while !u.bit_vartime(0) {
+            u = u.wrapping_shr_vartime(1);
+            a = half(a);
+        }
+
+        while !v.bit_vartime(0) {
+            v = v.wrapping_shr_vartime(1);
+            c = half(c);
+        }
+
+        if u >= v {
+            u = u.wrapping_sub(&v);
+            a = a.sub_mod(&c, modulus);
+        } else {
+            v = v.wrapping_sub(&u);
+            c = c.sub_mod(&a, modulus);
+        }
+    }
+
+    // `v` is now `gcd(x, modulus) = 1`
+    Some(c)
+}
@@ -8,6 +8,27 @@
 #![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "dev")]
+pub mod dev;
+
+mod batch;
+mod invert;
+mod pow;
+mod reduce;
+mod sqrt;
+
+#[cfg(feature = "alloc")]
+pub use batch::batch_invert;
+pub use batch::{batch_invert_array, batch_invert_with_scratch};
+pub use elliptic_curve;
+pub use invert::invert_vartime;
+pub use pow::pow;
+pub use reduce::{reduce_le_bytes, reduce_wide};
+pub use sqrt::{is_square, legendre, sqrt_ratio};
+
 /// Implements a field element type whose internal representation is in
 /// Montgomery form, providing a combination of trait impls and inherent impls
 /// which are `const fn` where possible.
@@ -26,6 +47,12 @@
 /// - `pub fn is_odd`
 /// - `pub fn is_zero`
 /// - `pub fn double`
+/// - `pub fn invert_vartime`
+/// - `pub fn legendre`
+/// - `pub fn is_square`
+/// - `pub fn pow_uint`
+/// - `pub fn batch_invert_array`
+/// - `pub fn batch_invert` (requires `alloc`)
 ///
 /// NOTE: field implementations must provide their own inherent impls of
 /// the following methods in order for the code generated by this macro to
@@ -269,11 +296,29 @@ macro_rules! impl_mont_field_element {
 
                 res
             }
+
+            /// Compute the multiplicative inverse `1 / self` in variable time.
+            ///
+            /// ⚠️ WARNING!
+            ///
+            /// This method should not be used with secret values, as its
+            /// variable-time operation can potentially leak secrets through
+            /// sidechannels.
+            pub fn invert_vartime(&self) -> $crate::elliptic_curve::subtle::CtOption<Self> {
+                let inverse = $crate::invert_vartime(&self.to_canonical(), &$modulus);
+
+                $crate::elliptic_curve::subtle::CtOption::new(
+                    Self::from_uint_unchecked(inverse.unwrap_or(<$uint>::ZERO)),
+                    $crate::elliptic_curve::subtle::Choice::from(u8::from(inverse.is_some())),
+                )
+            }
         }
 
         $crate::impl_mont_field_element_arithmetic!(
-            $fe, $bytes, $uint, $arr, $add, $sub, $mul, $neg
+            $fe, $bytes, $uint, $modulus, $arr, $add, $sub, $mul, $neg
         );
+
+        $crate::impl_batch_invert!($fe);
     };
 }
 
@@ -284,12 +329,40 @@ macro_rules! impl_mont_field_element_arithmetic {
         $fe:tt,
         $bytes:ty,
         $uint:ty,
+        $modulus:expr,
         $arr:ty,
         $add:ident,
         $sub:ident,
         $mul:ident,
         $neg:ident
     ) => {
+        impl $fe {
+            /// Compute the Legendre symbol of `self`.
+            ///
+            /// Returns `1` if `self` is a nonzero square, `-1` if it's a
+            /// non-square, and `0` if it's zero.
+            pub fn legendre(&self) -> i8 {
+                $crate::legendre(self, &$modulus)
+            }
+
+            /// Is `self` a square (including zero)?
+            pub fn is_square(&self) -> $crate::elliptic_curve::subtle::Choice {
+                $crate::is_square(self, &$modulus)
+            }
+
+            /// Raise `self` to the power `exp` in constant time, using a fixed
+            /// window.
+            ///
+            /// Unlike `pow_vartime`, this is constant-time with respect to the
+            /// exponent, so it can be used with secret exponents.
+            pub fn pow_uint<const LIMBS: usize>(
+                &self,
+                exp: &$crate::elliptic_curve::bigint::Uint<LIMBS>,
+            ) -> Self {
+                $crate::pow(self, exp)
+            }
+        }
+
         impl AsRef<$arr> for $fe {
             fn as_ref(&self) -> &$arr {
                 self.0.as_ref()
@@ -392,7 +465,7 @@ macro_rules! impl_mont_field_element_arithmetic {
             }
 
             fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
-                $crate::elliptic_curve::ff::helpers::sqrt_ratio_generic(num, div)
+                $crate::sqrt_ratio(num, div, &$modulus)
             }
         }
 
@@ -479,6 +552,291 @@ macro_rules! impl_mont_field_element_arithmetic {
     };
 }
 
+/// Implements a field element type in terms of [`ConstMontyForm`] from
+/// `crypto-bigint`, requiring only the modulus rather than a set of
+/// fiat-crypto generated arithmetic functions.
+///
+/// Provides the same API surface as [`impl_mont_field_element!`] (which it is
+/// implemented in terms of), along with the following which fiat-based fields
+/// have to supply by hand:
+///
+/// # Inherent impls
+/// - `pub fn invert`
+/// - `pub fn sqrt` (constant-time Tonelli-Shanks for any `p`)
+///
+/// # Trait impls
+/// - `Debug` (canonical hex)
+/// - `Invert`
+/// - `Ord`/`PartialOrd` (by canonical value)
+/// - `PrimeField`
+///
+/// The Montgomery parameters and all `PrimeField` constants are computed at
+/// compile time from the big endian hex `$modulus`. By default the
+/// multiplicative generator is the smallest quadratic non-residue; a
+/// different one may be given as a trailing `u64` argument, e.g. to keep the
+/// constants of an existing implementation, in which case it is checked to be
+/// a non-residue at compile time.
+///
+/// The field element type must be a newtype over `$uint`, declared by the
+/// caller.
+///
+/// [`ConstMontyForm`]: elliptic_curve::bigint::modular::ConstMontyForm
+#[macro_export]
+macro_rules! impl_monty_field_element {
+    ($curve:tt, $fe:tt, $bytes:ty, $uint:ty, $modulus:expr) => {
+        $crate::impl_monty_field_element!(@impl $curve, $fe, $bytes, $uint, $modulus, None);
+    };
+    ($curve:tt, $fe:tt, $bytes:ty, $uint:ty, $modulus:expr, $multiplicative_generator:expr) => {
+        $crate::impl_monty_field_element!(
+            @impl $curve, $fe, $bytes, $uint, $modulus, Some($multiplicative_generator)
+        );
+    };
+    (@impl $curve:tt, $fe:tt, $bytes:ty, $uint:ty, $modulus:expr, $generator:expr) => {
+        const _: () = {
+            use core::{
+                cmp::Ordering,
+                fmt,
+                iter::{Product, Sum},
+                ops::{AddAssign, MulAssign, Neg, SubAssign},
+            };
+            use $crate::elliptic_curve::{
+                bigint::{modular::ConstMontyForm, Word},
+                ff::PrimeField,
+                ops::Invert,
+                subtle::{Choice, ConstantTimeEq, CtOption},
+            };
+
+            $crate::elliptic_curve::bigint::impl_modulus!(Modulus, $uint, $modulus);
+
+            type Monty = ConstMontyForm<Modulus, { <$uint>::LIMBS }>;
+            type Words = [Word; <$uint>::LIMBS];
+
+            const MODULUS: $uint = <$uint>::from_be_hex($modulus);
+            const P_MINUS_1: $uint = MODULUS.wrapping_sub(&<$uint>::ONE);
+            const P_MINUS_2: $uint = P_MINUS_1.wrapping_sub(&<$uint>::ONE);
+
+            /// `p - 1 = 2^S * T` with `T` odd.
+            const S: u32 = P_MINUS_1.trailing_zeros();
+            const T_MINUS_1_OVER_2: $uint = P_MINUS_1.wrapping_shr_vartime(S + 1);
+
+            const fn monty(w: &Words) -> Monty {
+                Monty::from_montgomery(<$uint>::from_words(*w))
+            }
+
+            const fn fe_from_montgomery(w: &Words) -> Words {
+                monty(w).retrieve().to_words()
+            }
+
+            const fn fe_to_montgomery(w: &Words) -> Words {
+                Monty::new(&<$uint>::from_words(*w))
+                    .to_montgomery()
+                    .to_words()
+            }
+
+            const fn fe_add(a: &Words, b: &Words) -> Words {
+                monty(a).add(&monty(b)).to_montgomery().to_words()
+            }
+
+            const fn fe_sub(a: &Words, b: &Words) -> Words {
+                monty(a).sub(&monty(b)).to_montgomery().to_words()
+            }
+
+            const fn fe_mul(a: &Words, b: &Words) -> Words {
+                monty(a).mul(&monty(b)).to_montgomery().to_words()
+            }
+
+            const fn fe_neg(a: &Words) -> Words {
+                Monty::neg(&monty(a)).to_montgomery().to_words()
+            }
+
+            const fn fe_square(a: &Words) -> Words {
+                monty(a).square().to_montgomery().to_words()
+            }
+
+            /// Constant-time exponentiation by a `Uint` exponent.
+            const fn pow(a: &$fe, exp: &$uint) -> $fe {
+                $fe(monty(a.0.as_words()).pow(exp).to_montgomery())
+            }
+
+            /// Inversion via Fermat's little theorem, for computing constants.
+            const fn invert_const(a: &$fe) -> $fe {
+                pow(a, &P_MINUS_2)
+            }
+
+            /// Is `a` a quadratic non-residue, according to Euler's criterion?
+            const fn is_non_residue(a: &$fe) -> bool {
+                let euler = pow(a, &P_MINUS_1.wrapping_shr_vartime(1));
+                let minus_one = $fe::neg(&$fe::ONE);
+                matches!(euler.0.cmp_vartime(&minus_one.0), Ordering::Equal)
+            }
+
+            /// Use the given multiplicative generator, or otherwise find the
+            /// smallest quadratic non-residue by testing `2, 3, 4, ...`.
+            const fn multiplicative_generator(generator: Option<u64>) -> $fe {
+                match generator {
+                    Some(g) => {
+                        let g = $fe::from_u64(g);
+                        assert!(
+                            is_non_residue(&g),
+                            "multiplicative generator must be a quadratic non-residue"
+                        );
+                        g
+                    }
+                    None => {
+                        let mut g = 2;
+
+                        loop {
+                            let candidate = $fe::from_u64(g);
+
+                            if is_non_residue(&candidate) {
+                                return candidate;
+                            }
+
+                            g += 1;
+                        }
+                    }
+                }
+            }
+
+            $crate::impl_mont_field_element!(
+                $curve,
+                $fe,
+                $bytes,
+                $uint,
+                MODULUS,
+                Words,
+                fe_from_montgomery,
+                fe_to_montgomery,
+                fe_add,
+                fe_sub,
+                fe_mul,
+                fe_neg,
+                fe_square
+            );
+
+            impl $fe {
+                /// Compute multiplicative inverse: `1 / self`.
+                pub fn invert(&self) -> CtOption<Self> {
+                    let m = monty(self.0.as_words());
+                    $crate::elliptic_curve::bigint::Invert::invert(&m)
+                        .map(|m| Self(m.to_montgomery()))
+                }
+
+                /// Returns the square root of self mod p, or `None` if no square
+                /// root exists.
+                ///
+                /// Tonelli-Shanks algorithm as given in
+                /// <https://eprint.iacr.org/2012/685.pdf> (page 12, algorithm 5),
+                /// which is constant time in `self` for any `p`.
+                pub fn sqrt(&self) -> CtOption<Self> {
+                    use $crate::elliptic_curve::subtle::ConditionallySelectable;
+
+                    // w = self^((t - 1) // 2)
+                    let w = pow(self, &T_MINUS_1_OVER_2);
+
+                    let mut v = S;
+                    let mut x = *self * w;
+                    let mut b = x * w;
+                    let mut z = <Self as PrimeField>::ROOT_OF_UNITY;
+
+                    for max_v in (1..=S).rev() {
+                        let mut k = 1;
+                        let mut tmp = b.square();
+                        let mut j_less_than_v = Choice::from(1);
+
+                        for j in 2..max_v {
+                            let tmp_is_one = tmp.ct_eq(&Self::ONE);
+                            let squared = Self::conditional_select(&tmp, &z, tmp_is_one).square();
+                            tmp = Self::conditional_select(&squared, &tmp, tmp_is_one);
+                            let new_z = Self::conditional_select(&z, &squared, tmp_is_one);
+                            j_less_than_v &= !j.ct_eq(&v);
+                            k = u32::conditional_select(&j, &k, tmp_is_one);
+                            z = Self::conditional_select(&z, &new_z, j_less_than_v);
+                        }
+
+                        let result = x * z;
+                        x = Self::conditional_select(&result, &x, b.ct_eq(&Self::ONE));
+                        z = z.square();
+                        b *= z;
+                        v = k;
+                    }
+
+                    CtOption::new(x, x.square().ct_eq(self))
+                }
+            }
+
+            impl fmt::Debug for $fe {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(
+                        f,
+                        concat!(stringify!($fe), "(0x{:X})"),
+                        &self.to_canonical()
+                    )
+                }
+            }
+
+            impl PartialOrd for $fe {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for $fe {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.to_canonical().cmp(&other.to_canonical())
+                }
+            }
+
+            impl Invert for $fe {
+                type Output = CtOption<Self>;
+
+                fn invert(&self) -> CtOption<Self> {
+                    self.invert()
+                }
+
+                fn invert_vartime(&self) -> CtOption<Self> {
+                    self.invert_vartime()
+                }
+            }
+
+            impl PrimeField for $fe {
+                type Repr = $bytes;
+
+                const MODULUS: &'static str = $modulus;
+                const NUM_BITS: u32 = MODULUS.bits();
+                const CAPACITY: u32 = Self::NUM_BITS - 1;
+                const TWO_INV: Self = invert_const(&Self::from_u64(2));
+                const MULTIPLICATIVE_GENERATOR: Self = multiplicative_generator($generator);
+                const S: u32 = S;
+                const ROOT_OF_UNITY: Self = pow(
+                    &Self::MULTIPLICATIVE_GENERATOR,
+                    &P_MINUS_1.wrapping_shr_vartime(S),
+                );
+                const ROOT_OF_UNITY_INV: Self = invert_const(&Self::ROOT_OF_UNITY);
+                const DELTA: Self = pow(
+                    &Self::MULTIPLICATIVE_GENERATOR,
+                    &<$uint>::ONE.wrapping_shl_vartime(S),
+                );
+
+                #[inline]
+                fn from_repr(bytes: Self::Repr) -> CtOption<Self> {
+                    Self::from_bytes(&bytes)
+                }
+
+                #[inline]
+                fn to_repr(&self) -> Self::Repr {
+                    self.to_bytes()
+                }
+
+                #[inline]
+                fn is_odd(&self) -> Choice {
+                    self.is_odd()
+                }
+            }
+        };
+    };
+}
+
 /// Emit impls for a `core::ops` trait for all combinations of reference types,
 /// which thunk to the given function.
 #[macro_export]
@@ -612,6 +970,73 @@ macro_rules! impl_field_invert_tests {
     };
 }
 
+/// Implement field element batch inversion tests.
+#[macro_export]
+macro_rules! impl_field_batch_invert_tests {
+    ($fe:tt) => {
+        #[test]
+        fn batch_invert_array() {
+            let one = $fe::ONE;
+            let two = one + &one;
+            let three = two + &one;
+            let expected = [two.invert().unwrap(), three.invert().unwrap()];
+
+            let mut elements = [two, three];
+            assert!(bool::from($fe::batch_invert_array(&mut elements)));
+            assert_eq!(elements, expected);
+
+            let mut elements = [two, $fe::ZERO, three];
+            assert!(!bool::from($fe::batch_invert_array(&mut elements)));
+            assert_eq!(elements, [expected[0], $fe::ZERO, expected[1]]);
+
+            let mut elements: [$fe; 0] = [];
+            assert!(bool::from($fe::batch_invert_array(&mut elements)));
+        }
+
+        #[test]
+        #[cfg(feature = "alloc")]
+        fn batch_invert() {
+            let one = $fe::ONE;
+            let mut elements = [one; 16];
+            let mut expected = [one; 16];
+
+            for i in 1..elements.len() {
+                elements[i] = elements[i - 1] + &one;
+                expected[i] = elements[i].invert().unwrap();
+            }
+
+            elements[7] = $fe::ZERO;
+            expected[7] = $fe::ZERO;
+            let original = elements;
+
+            assert!(!bool::from($fe::batch_invert(&mut elements)));
+            assert_eq!(elements, expected);
+            assert!(!bool::from($fe::batch_invert(&mut elements)));
+            assert_eq!(elements, original);
+            assert!(bool::from($fe::batch_invert(&mut elements[8..])));
+            assert_eq!(elements[8..], expected[8..]);
+        }
+    };
+}
+
+/// Implement field element variable-time inversion tests.
+#[macro_export]
+macro_rules! impl_field_invert_vartime_tests {
+    ($fe:tt) => {
+        #[test]
+        fn invert_vartime() {
+            assert!(bool::from($fe::ZERO.invert_vartime().is_none()));
+
+            let mut x = $fe::ONE;
+            for _ in 0..32 {
+                assert_eq!(x.invert_vartime().unwrap(), x.invert().unwrap());
+                assert_eq!((-x).invert_vartime().unwrap(), (-x).invert().unwrap());
+                x = x.square() + &x + &$fe::ONE;
+            }
+        }
+    };
+}
+
 /// Implement field element square root tests.
 #[macro_export]
 macro_rules! impl_field_sqrt_tests {
@@ -627,6 +1052,145 @@ macro_rules! impl_field_sqrt_tests {
     };
 }
 
+/// Implement Legendre symbol, `is_square` and `sqrt_ratio` tests.
+#[macro_export]
+macro_rules! impl_field_sqrt_ratio_tests {
+    ($fe:tt) => {
+        #[test]
+        fn legendre() {
+            use $crate::elliptic_curve::ff::PrimeField;
+
+            assert_eq!($fe::ZERO.legendre(), 0);
+            assert!(bool::from($fe::ZERO.is_square()));
+
+            let mut x = $fe::ONE;
+            for _ in 0..32 {
+                let square = x.square();
+                let non_square = square * $fe::ROOT_OF_UNITY;
+                assert_eq!(square.legendre(), 1);
+                assert!(bool::from(square.is_square()));
+                assert_eq!(non_square.legendre(), -1);
+                assert!(!bool::from(non_square.is_square()));
+                assert_eq!(x.is_square().unwrap_u8(), x.sqrt().is_some().unwrap_u8());
+                x = x.square() + &x + &$fe::ONE;
+            }
+        }
+
+        #[test]
+        fn sqrt_ratio() {
+            use $crate::elliptic_curve::ff::{Field, PrimeField};
+
+            let (is_square, root) = $fe::sqrt_ratio(&$fe::ZERO, &$fe::ONE);
+            assert!(bool::from(is_square));
+            assert_eq!(root, $fe::ZERO);
+
+            let (is_square, root) = $fe::sqrt_ratio(&$fe::ONE, &$fe::ZERO);
+            assert!(!bool::from(is_square));
+            assert_eq!(root, $fe::ZERO);
+
+            let mut num = $fe::ONE;
+            let mut div = $fe::ROOT_OF_UNITY;
+            for _ in 0..32 {
+                let ratio = num * div.invert().unwrap();
+                let (is_square, root) = $fe::sqrt_ratio(&num, &div);
+                assert_eq!(is_square.unwrap_u8(), ratio.is_square().unwrap_u8());
+
+                if bool::from(is_square) {
+                    assert_eq!(root.square(), ratio);
+                } else {
+                    assert_eq!(root.square(), ratio * $fe::ROOT_OF_UNITY);
+                }
+
+                num = num.square() + &num + &$fe::ONE;
+                div = div.square() + &num;
+            }
+        }
+    };
+}
+
+/// Implement constant-time exponentiation tests.
+#[macro_export]
+macro_rules! impl_field_pow_tests {
+    ($fe:tt) => {
+        #[test]
+        fn pow() {
+            use $crate::elliptic_curve::{
+                bigint::{U256, U64},
+                ff::PrimeField,
+            };
+
+            let g = $fe::MULTIPLICATIVE_GENERATOR;
+            assert_eq!(g.pow_uint(&U64::ZERO), $fe::ONE);
+            assert_eq!(g.pow_uint(&U64::ONE), g);
+            assert_eq!($fe::ZERO.pow_uint(&U64::ONE), $fe::ZERO);
+
+            let mut bytes = [0u8; 32];
+            for i in 0..32u8 {
+                bytes[usize::from(i)] = i.wrapping_mul(0x9d) ^ 0xa5;
+                let exp = U256::from_le_slice(&bytes);
+                let words: [u64; 4] = core::array::from_fn(|j| {
+                    u64::from_le_bytes(bytes[(8 * j)..(8 * j + 8)].try_into().unwrap())
+                });
+
+                assert_eq!(g.pow_uint(&exp), g.pow_vartime(&words));
+                assert_eq!(
+                    g.pow_uint(&U64::from_u64(words[0])),
+                    g.pow_vartime(&[words[0]])
+                );
+            }
+        }
+    };
+}
+
+/// Implement `Reduce` and `FromUniformBytes` tests for wide inputs.
+#[macro_export]
+macro_rules! impl_field_reduce_wide_tests {
+    ($fe:tt, $wide:ty, $uniform_bytes:expr) => {
+        #[test]
+        fn reduce_wide() {
+            use $crate::elliptic_curve::ops::Reduce;
+
+            let radix = $fe::from(256u64);
+            let mut expected = $fe::ONE;
+            for i in 0..<$wide>::BITS / 8 {
+                let n = <$wide>::from_u8(0xa5).wrapping_shl_vartime(8 * i);
+                assert_eq!(
+                    <$fe as Reduce<$wide>>::reduce(n),
+                    expected * $fe::from(0xa5u64)
+                );
+                expected *= radix;
+            }
+
+            assert_eq!(
+                <$fe as Reduce<$wide>>::reduce(<$wide>::MAX),
+                expected - $fe::ONE
+            );
+        }
+
+        #[test]
+        fn from_uniform_bytes() {
+            use $crate::elliptic_curve::ff::FromUniformBytes;
+
+            let radix = $fe::from(256u64);
+            let mut expected = $fe::ONE;
+            for i in 0..$uniform_bytes {
+                let mut bytes = [0u8; $uniform_bytes];
+                bytes[i] = 0xa5;
+                assert_eq!(
+                    $fe::from_uniform_bytes(&bytes),
+                    expected * $fe::from(0xa5u64)
+                );
+                expected *= radix;
+            }
+
+            assert_eq!(
+                $fe::from_uniform_bytes(&[0xff; $uniform_bytes]),
+                expected - $fe::ONE
+            );
+        }
+    };
+}
+
 /// Implement tests for the `PrimeField` trait.
 #[macro_export]
 macro_rules! impl_primefield_tests {
@@ -0,0 +1,50 @@
+//! Constant-time exponentiation.
+
+use elliptic_curve::{
+    bigint::{Limb, Uint, Word},
+    ff::Field,
+    subtle::ConstantTimeEq,
+};
+
+/// Window size in bits. Must divide [`Limb::BITS`].
+const WINDOW: u32 = 4;
+
+/// Compute `base^exp` using a fixed window of [`WINDOW`] bits.
+///
+/// This performs the same sequence of operations for every `base` and `exp` of
+/// a given width, and selects table entries with a full constant-time scan, so
+/// it can be used with secret exponents.
+pub fn pow<F: Field, const LIMBS: usize>(base: &F, exp: &Uint<LIMBS>) -> F {
+    // table[i] = base^i
+    let mut table = [F::ONE; 1 << WINDOW];
+    let mut acc = F::ONE;
+    for entry in table.iter_mut().skip(1) {
+        acc *= base;
+        *entry = acc;
+    }
+
+    let mask: Word = (1 << WINDOW) - 1;
+    let mut res = F::ONE;
+
+    for word in exp.as_words().iter().rev() {
+        let mut shift = Limb::BITS;
+
+        while shift > 0 {
+            shift -= WINDOW;
+
+            for _ in 0..WINDOW {
+                res = res.square();
+            }
+
+            let index = (word >> shift) & mask;
+            let mut factor = F::ONE;
+            for (i, entry) in (0..).zip(table.iter()) {
+                factor.conditional_assign(entry, index.ct_eq(&i));
+            }
+
+            res *= factor;
+        }
+    }
+
+    res
+}
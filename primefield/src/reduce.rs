@@ -0,0 +1,44 @@
+//! Wide modular reduction.
+//!
+//! Reducing an integer at least 128 bits wider than the modulus produces a
+//! field element whose distribution is statistically indistinguishable from
+//! uniform, as required by [`ff::FromUniformBytes`].
+//!
+//! [`ff::FromUniformBytes`]: elliptic_curve::ff::FromUniformBytes
+
+use elliptic_curve::{
+    bigint::{Limb, Uint},
+    ff::PrimeField,
+};
+
+/// Reduce `n` modulo the modulus of `F` in constant time.
+///
+/// `n` may be of any width.
+pub fn reduce_wide<F: PrimeField, const LIMBS: usize>(n: &Uint<LIMBS>) -> F {
+    // 2^Limb::BITS
+    let radix = F::from(1 << (Limb::BITS / 2)).square();
+
+    n.as_words().iter().rev().fold(F::ZERO, |acc, &word| {
+        // `Word` is `u32` on 32-bit targets
+        #[allow(clippy::useless_conversion)]
+        let word = u64::from(word);
+        acc * radix + F::from(word)
+    })
+}
+
+/// Interpret `bytes` as a little-endian integer of any length and reduce it
+/// modulo the modulus of `F` in constant time.
+///
+/// This is the encoding [`ff::FromUniformBytes`] is specified in terms of.
+///
+/// [`ff::FromUniformBytes`]: elliptic_curve::ff::FromUniformBytes
+pub fn reduce_le_bytes<F: PrimeField>(bytes: &[u8]) -> F {
+    // 2^64
+    let radix = F::from(1 << 32).square();
+
+    bytes.chunks(8).rev().fold(F::ZERO, |acc, chunk| {
+        let mut word = [0u8; 8];
+        word[..chunk.len()].copy_from_slice(chunk);
+        acc * radix + F::from(u64::from_le_bytes(word))
+    })
+}
@@ -0,0 +1,196 @@
+//! Quadratic residuosity and square roots of ratios.
+//!
+//! These functions are generic over [`PrimeField`] and take the field modulus
+//! as a [`Uint`] so the fixed exponents they need can be derived from it.
+//! Exponentiation is by those public exponents only, and elements are compared
+//! by their canonical encodings, so all of them are constant time in their
+//! field element arguments.
+
+use elliptic_curve::{
+    bigint::Uint,
+    ff::{Field, PrimeField},
+    subtle::{Choice, ConstantTimeEq},
+};
+
+/// Compute the Legendre symbol of `x` using Euler's criterion.
+///
+/// Returns `1` if `x` is a nonzero square, `-1` if `x` is a non-square, and
+/// `0` if `x` is zero.
+///
+/// `modulus` must be the modulus of `F`.
+pub fn legendre<F: PrimeField, const LIMBS: usize>(x: &F, modulus: &Uint<LIMBS>) -> i8 {
+    let euler = euler(x, modulus);
+    let is_one = ct_eq(&euler, &F::ONE);
+    let is_minus_one = ct_eq(&euler, &-F::ONE);
+    is_one.unwrap_u8() as i8 - is_minus_one.unwrap_u8() as i8
+}
+
+/// Is `x` a square (including zero)?
+///
+/// `modulus` must be the modulus of `F`.
+pub fn is_square<F: PrimeField, const LIMBS: usize>(x: &F, modulus: &Uint<LIMBS>) -> Choice {
+    !ct_eq(&euler(x, modulus), &-F::ONE)
+}
+
+/// Compute the square root of `num / div`, following the contract of
+/// [`Field::sqrt_ratio`] with `G_S` = [`PrimeField::ROOT_OF_UNITY`].
+///
+/// The algorithm is selected by the residue of the modulus:
+///
+/// - `p ≡ 3 (mod 4)`: a single exponentiation by `(p - 3) / 4`
+/// - `p ≡ 5 (mod 8)`: a single exponentiation by `(p - 5) / 8`, corrected by a
+///   fourth root of unity
+/// - `p ≡ 1 (mod 8)`: the constant-time Tonelli-Shanks variant from
+///   [RFC 9380 Appendix F.2.1.1], which covers all of `p mod 16`
+///
+/// None of the variants inverts `div`.
+///
+/// `modulus` must be the modulus of `F`.
+///
+/// [RFC 9380 Appendix F.2.1.1]: https://www.rfc-editor.org/rfc/rfc9380.html#appendix-F.2.1.1
+pub fn sqrt_ratio<F: PrimeField, const LIMBS: usize>(
+    num: &F,
+    div: &F,
+    modulus: &Uint<LIMBS>,
+) -> (Choice, F) {
+    match modulus.as_words()[0] & 7 {
+        3 | 7 => sqrt_ratio_3mod4(num, div, modulus),
+        5 => sqrt_ratio_5mod8(num, div, modulus),
+        _ => sqrt_ratio_1mod8(num, div, modulus),
+    }
+}
+
+/// `sqrt_ratio` for `p ≡ 3 (mod 4)`, where `G_S = -1`.
+///
+/// <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-F.2.1.2>
+fn sqrt_ratio_3mod4<F: PrimeField, const LIMBS: usize>(
+    u: &F,
+    v: &F,
+    modulus: &Uint<LIMBS>,
+) -> (Choice, F) {
+    // c1 = (p - 3) / 4
+    let c1 = modulus.wrapping_shr_vartime(2);
+
+    // y = u * v * (u * v^3)^c1, so y^2 * v = u * (u / v)^((p - 1) / 2)
+    let uv = *u * v;
+    let y = pow_vartime(&(uv * v.square()), &c1) * uv;
+    let is_square = ct_eq(&(y.square() * v), u);
+    (is_square, y)
+}
+
+/// `sqrt_ratio` for `p ≡ 5 (mod 8)`, where `G_S` is a square root of `-1`.
+fn sqrt_ratio_5mod8<F: PrimeField, const LIMBS: usize>(
+    u: &F,
+    v: &F,
+    modulus: &Uint<LIMBS>,
+) -> (Choice, F) {
+    // c1 = (p - 5) / 8
+    let c1 = modulus.wrapping_shr_vartime(3);
+
+    // y = u * v^3 * (u * v^7)^c1, so y^2 * v = u * e where e is a fourth root
+    // of unity, and e = ±1 iff `u / v` is a square
+    let v2 = v.square();
+    let v3 = v2 * v;
+    let uv3 = *u * v3;
+    let y = pow_vartime(&(uv3 * v2.square()), &c1) * uv3;
+    let tv = y.square() * v;
+
+    // Multiplying by `i = ROOT_OF_UNITY` negates `y^2`, which fixes up the
+    // `e = -1` and `e = -i` cases.
+    let minus_u = -*u;
+    let is_square = ct_eq(&tv, u) | ct_eq(&tv, &minus_u);
+    let needs_i = ct_eq(&tv, &minus_u) | ct_eq(&tv, &-(*u * F::ROOT_OF_UNITY));
+    let y = F::conditional_select(&y, &(y * F::ROOT_OF_UNITY), needs_i);
+    (is_square, y)
+}
+
+/// `sqrt_ratio` for `p ≡ 1 (mod 8)`, where `G_S = Z = ROOT_OF_UNITY`.
+///
+/// <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-F.2.1.1>
+fn sqrt_ratio_1mod8<F: PrimeField, const LIMBS: usize>(
+    u: &F,
+    v: &F,
+    modulus: &Uint<LIMBS>,
+) -> (Choice, F) {
+    // `p - 1 = 2^c1 * c2` with `c2` odd
+    let c1 = F::S;
+    let c2 = modulus.wrapping_sub(&Uint::ONE).wrapping_shr_vartime(c1);
+    let c3 = c2.wrapping_shr_vartime(1);
+
+    // `Z` has order `2^c1`, so its exponents can be reduced modulo `2^c1`.
+    let mask = Uint::MAX.wrapping_shr_vartime(Uint::<LIMBS>::BITS - c1);
+    let c6 = pow_vartime(&F::ROOT_OF_UNITY, &c2.bitand(&mask));
+    let c7 = pow_vartime(
+        &F::ROOT_OF_UNITY,
+        &c3.wrapping_add(&Uint::ONE).bitand(&mask),
+    );
+
+    let mut tv1 = c6;
+    // tv2 = v^(2^c1 - 1)
+    let mut tv2 = F::ONE;
+    for _ in 0..c1 {
+        tv2 = tv2.square() * v;
+    }
+    let mut tv3 = tv2.square() * v;
+    let mut tv5 = pow_vartime(&(*u * tv3), &c3) * tv2;
+    tv2 = tv5 * v;
+    tv3 = tv5 * u;
+    let mut tv4 = tv3 * tv2;
+    tv5 = sqn(&tv4, c1 - 1);
+
+    // The RFC assumes `u != 0`, whereas `0 / v` is a square here.
+    let is_square = ct_eq(&tv5, &F::ONE) | ct_eq(u, &F::ZERO);
+    tv2 = tv3 * c7;
+    tv5 = tv4 * tv1;
+    tv3 = F::conditional_select(&tv2, &tv3, is_square);
+    tv4 = F::conditional_select(&tv5, &tv4, is_square);
+
+    for i in (2..=c1).rev() {
+        tv5 = sqn(&tv4, i - 2);
+        let e1 = ct_eq(&tv5, &F::ONE);
+        tv2 = tv3 * tv1;
+        tv1 = tv1.square();
+        tv5 = tv4 * tv1;
+        tv3 = F::conditional_select(&tv2, &tv3, e1);
+        tv4 = F::conditional_select(&tv5, &tv4, e1);
+    }
+
+    (is_square, tv3)
+}
+
+/// Euler's criterion: `x^((p - 1) / 2)`.
+fn euler<F: Field, const LIMBS: usize>(x: &F, modulus: &Uint<LIMBS>) -> F {
+    pow_vartime(x, &modulus.wrapping_shr_vartime(1))
+}
+
+/// Compute `x^exp`, in variable time with respect to `exp` only.
+fn pow_vartime<F: Field, const LIMBS: usize>(x: &F, exp: &Uint<LIMBS>) -> F {
+    let mut res = F::ONE;
+
+    for i in (0..exp.bits_vartime()).rev() {
+        res = res.square();
+
+        if exp.bit_vartime(i) {
+            res *= x;
+        }
+    }
+
+    res
+}
+
+/// Compute `x^(2^n)`.
+fn sqn<F: Field>(x: &F, n: u32) -> F {
+    let mut res = *x;
+
+    for _ in 0..n {
+        res = res.square();
+    }
+
+    res
+}
+
+/// Compare canonical encodings, since not every field element type keeps its
+/// internal representation normalized.
+fn ct_eq<F: PrimeField>(a: &F, b: &F) -> Choice {
+    a.to_repr().as_ref().ct_eq(b.to_repr().as_ref())
+}
@@ -0,0 +1,76 @@
+//! Benchmarks comparing the point arithmetic backends, using NIST P-256 defined
+//! with `define_curve!`.
+
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::Measurement, BenchmarkGroup,
+    BenchmarkId, Criterion,
+};
+use primeorder::{Double, Field, PrimeCurveParams, ProjectivePoint};
+
+macro_rules! define_p256 {
+    ($module:ident, $point_arithmetic:ty) => {
+        mod $module {
+            use primeorder::elliptic_curve::{bigint::U256, consts::U32};
+
+            primeorder::define_curve! {
+                /// NIST P-256 defined from its domain parameters.
+                pub struct P256;
+                uint: U256;
+                field_bytes_size: U32;
+                oid: "1.2.840.10045.3.1.7";
+                p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+                n: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+                a: "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc";
+                b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b";
+                gx: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296";
+                gy: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5";
+                point_arithmetic: $point_arithmetic;
+            }
+        }
+    };
+}
+
+define_p256!(
+    complete,
+    primeorder::point_arithmetic::EquationAIsMinusThree
+);
+define_p256!(
+    jacobian,
+    primeorder::point_arithmetic::EquationAIsMinusThreeJacobian
+);
+define_p256!(coz, primeorder::point_arithmetic::EquationAIsMinusThreeCoZ);
+
+fn bench_backend<C: PrimeCurveParams, M: Measurement>(
+    group: &mut BenchmarkGroup<'_, M>,
+    backend: &str,
+) {
+    // A full-width scalar
+    let k = C::Scalar::from(3).invert().unwrap();
+    let x = ProjectivePoint::<C>::GENERATOR * k;
+    let y = x.double() + x;
+    let y_affine = y.to_affine();
+
+    group.bench_function(BenchmarkId::new("add", backend), |b| {
+        b.iter(|| black_box(x) + black_box(y))
+    });
+    group.bench_function(BenchmarkId::new("add_mixed", backend), |b| {
+        b.iter(|| black_box(x) + black_box(y_affine))
+    });
+    group.bench_function(BenchmarkId::new("double", backend), |b| {
+        b.iter(|| black_box(x).double())
+    });
+    group.bench_function(BenchmarkId::new("point-scalar mul", backend), |b| {
+        b.iter(|| black_box(x) * black_box(k))
+    });
+}
+
+fn bench_point_arithmetic(c: &mut Criterion) {
+    let mut group = c.benchmark_group("point arithmetic");
+    bench_backend::<complete::P256, _>(&mut group, "complete");
+    bench_backend::<jacobian::P256, _>(&mut group, "jacobian");
+    bench_backend::<coz::P256, _>(&mut group, "co-Z");
+    group.finish();
+}
+
+criterion_group!(benches, bench_point_arithmetic);
+criterion_main!(benches);
@@ -21,6 +21,9 @@ use elliptic_curve::{
     Error, FieldBytes, FieldBytesEncoding, FieldBytesSize, PublicKey, Result, Scalar,
 };
 
+#[cfg(feature = "alloc")]
+use {alloc::vec::Vec, elliptic_curve::ops::BatchInvert};
+
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
 
@@ -68,6 +71,73 @@ where
         Choice::from(self.infinity)
     }
 
+    /// Computes `lhs[i] + rhs[i]` for every `i`, sharing a single field
+    /// inversion between all of the additions (Montgomery's trick).
+    ///
+    /// This is considerably cheaper than adding the points in projective
+    /// coordinates and normalizing the results when affine outputs are needed.
+    /// Doublings, additions of the identity and of a point and its negation
+    /// are all handled in constant time.
+    ///
+    /// # Panics
+    ///
+    /// If `lhs` and `rhs` have different lengths.
+    #[cfg(feature = "alloc")]
+    pub fn batch_add(lhs: &[Self], rhs: &[Self]) -> Vec<Self> {
+        assert_eq!(lhs.len(), rhs.len(), "length mismatch");
+
+        // Batch inversion fails for an empty batch
+        if lhs.is_empty() {
+            return Vec::new();
+        }
+
+        let mut nums = Vec::with_capacity(lhs.len());
+        let mut dens = Vec::with_capacity(lhs.len());
+
+        for (p, q) in lhs.iter().zip(rhs) {
+            let doubling = p.x.ct_eq(&q.x) & p.y.ct_eq(&q.y);
+
+            // Slope of the tangent `(3x² + a) / 2y` when doubling, and of the
+            // chord `(y2 - y1) / (x2 - x1)` otherwise
+            let xx = p.x.square();
+            let num = C::FieldElement::conditional_select(
+                &(q.y - p.y),
+                &(xx.double() + xx + C::EQUATION_A),
+                doubling,
+            );
+            let den = C::FieldElement::conditional_select(&(q.x - p.x), &p.y.double(), doubling);
+
+            // Even a single zero value will fail inversion for the entire
+            // batch. Such additions result in the identity or one of the
+            // inputs, which is selected below.
+            nums.push(num);
+            dens.push(C::FieldElement::conditional_select(
+                &den,
+                &C::FieldElement::ONE,
+                den.is_zero(),
+            ));
+        }
+
+        // This is safe to unwrap since we assured that all elements are non-zero
+        let invs = <C::FieldElement as BatchInvert<[_]>>::batch_invert(&dens).unwrap();
+
+        lhs.iter()
+            .zip(rhs)
+            .zip(nums.iter().zip(invs.iter()))
+            .map(|((p, q), (num, inv))| {
+                let lambda = *num * inv;
+                let x = lambda.square() - p.x - q.x;
+                let y = lambda * (p.x - x) - p.y;
+
+                let mut sum = Self::from_affine_coordinates(x, y);
+                sum.conditional_assign(&Self::IDENTITY, p.x.ct_eq(&q.x) & !p.y.ct_eq(&q.y));
+                sum.conditional_assign(q, p.is_identity());
+                sum.conditional_assign(p, q.is_identity());
+                sum
+            })
+            .collect()
+    }
+
     /// Conditionally negate [`AffinePoint`] for use with point compaction.
     fn to_compact(self) -> Self {
         let neg_self = -self;
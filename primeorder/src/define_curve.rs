@@ -0,0 +1,392 @@
+//! Declarative definition of short Weierstrass curves from their parameters.
+
+/// Define a prime order short Weierstrass curve from its hex-encoded domain
+/// parameters.
+///
+/// This is intended for bringing up curves which don't have a dedicated crate
+/// in this repository, without needing to synthesize field arithmetic with
+/// fiat-crypto first. Field and scalar arithmetic are implemented in terms of
+/// [`ConstMontyForm`] from `crypto-bigint`, with the Montgomery parameters and
+/// the [`PrimeField`] constants all computed at compile time from `p` and `n`.
+///
+/// Point arithmetic uses the complete formulas for a generic `a` coefficient,
+/// unless a [`point_arithmetic`] backend specialised for the curve's `a`
+/// coefficient is selected with the optional `point_arithmetic` parameter.
+/// Curves with an efficiently computable endomorphism can also pass the
+/// [`GlvParams`] constants in an optional `glv` block, in which case scalar
+/// multiplication uses [`GlvParams::lincomb_glv`]. The `glv` constants are
+/// hex-encoded like the other parameters.
+///
+/// The macro defines the following items in the module it is invoked in, so
+/// each curve should live in a module of its own:
+///
+/// - the curve type itself, with impls of [`Curve`], [`PrimeCurve`],
+///   [`PointCompression`], [`CurveArithmetic`], [`PrimeCurveArithmetic`] and
+///   [`PrimeCurveParams`], as well as `AssociatedOid` when the `pkcs8`
+///   feature of this crate is enabled and the optional `oid` is given
+/// - `FieldBytes`: serialized field elements and scalars
+/// - `FieldElement`: elements of the base field modulo `p`
+/// - `Scalar`: elements of the scalar field modulo `n`
+/// - `AffinePoint` and `ProjectivePoint`
+///
+/// All hex parameters are big endian and must be exactly as wide as `uint`.
+///
+/// # Example
+///
+/// ```
+/// use primeorder::elliptic_curve::{bigint::U256, consts::U32};
+///
+/// primeorder::define_curve! {
+///     /// NIST P-256.
+///     pub struct MyP256;
+///     uint: U256;
+///     field_bytes_size: U32;
+///     oid: "1.2.840.10045.3.1.7";
+///     p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+///     n: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+///     a: "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc";
+///     b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b";
+///     gx: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296";
+///     gy: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5";
+/// }
+///
+/// let k = Scalar::from_u64(42);
+/// let p = ProjectivePoint::GENERATOR * k;
+/// let q = ProjectivePoint::GENERATOR * Scalar::from_u64(6) * Scalar::from_u64(7);
+/// assert_eq!(p.to_affine(), q.to_affine());
+/// ```
+///
+/// [`ConstMontyForm`]: elliptic_curve::bigint::modular::ConstMontyForm
+/// [`PrimeField`]: elliptic_curve::ff::PrimeField
+/// [`Curve`]: elliptic_curve::Curve
+/// [`PrimeCurve`]: elliptic_curve::PrimeCurve
+/// [`PointCompression`]: elliptic_curve::point::PointCompression
+/// [`CurveArithmetic`]: elliptic_curve::CurveArithmetic
+/// [`PrimeCurveArithmetic`]: elliptic_curve::PrimeCurveArithmetic
+/// [`PrimeCurveParams`]: crate::PrimeCurveParams
+/// [`point_arithmetic`]: crate::point_arithmetic
+/// [`GlvParams`]: crate::GlvParams
+/// [`GlvParams::lincomb_glv`]: crate::GlvParams::lincomb_glv
+#[macro_export]
+macro_rules! define_curve {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $curve:ident;
+        uint: $uint:ty;
+        field_bytes_size: $bytes_size:ty;
+        $(oid: $oid:expr;)?
+        p: $p:expr;
+        n: $n:expr;
+        a: $a:expr;
+        b: $b:expr;
+        gx: $gx:expr;
+        gy: $gy:expr
+        $(; point_arithmetic: $arith:ty)?
+        $(; glv: {
+            beta: $beta:expr,
+            lambda: $lambda:expr,
+            minus_b1: $minus_b1:expr,
+            minus_b2: $minus_b2:expr,
+            g1: $g1:expr,
+            g2: $g2:expr $(,)?
+        })? $(;)?
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+        $vis struct $curve;
+
+        impl $crate::elliptic_curve::Curve for $curve {
+            type FieldBytesSize = $bytes_size;
+            type Uint = $uint;
+            const ORDER: $uint = <$uint>::from_be_hex($n);
+        }
+
+        impl $crate::elliptic_curve::PrimeCurve for $curve {}
+
+        impl $crate::elliptic_curve::point::PointCompression for $curve {
+            const COMPRESS_POINTS: bool = false;
+        }
+
+        impl $crate::elliptic_curve::FieldBytesEncoding<$curve> for $uint {}
+
+        $crate::__define_curve_oid!($curve $(, $oid)?);
+
+        /// Serialized field element or scalar.
+        $vis type FieldBytes = $crate::elliptic_curve::FieldBytes<$curve>;
+
+        /// Elliptic curve point in affine coordinates.
+        $vis type AffinePoint = $crate::AffinePoint<$curve>;
+
+        /// Elliptic curve point in projective coordinates.
+        $vis type ProjectivePoint = $crate::ProjectivePoint<$curve>;
+
+        $crate::__define_curve_field!(
+            $vis,
+            $curve,
+            FieldElement,
+            $uint,
+            $p,
+            "Element of the base field used for curve coordinates."
+        );
+
+        $crate::__define_curve_field!(
+            $vis,
+            $curve,
+            Scalar,
+            $uint,
+            $n,
+            "Element of the scalar field modulo the curve's order."
+        );
+
+        $crate::__define_curve_scalar!($curve, Scalar, $uint);
+
+        impl $crate::elliptic_curve::CurveArithmetic for $curve {
+            type AffinePoint = AffinePoint;
+            type ProjectivePoint = ProjectivePoint;
+            type Scalar = Scalar;
+        }
+
+        impl $crate::elliptic_curve::PrimeCurveArithmetic for $curve {
+            type CurveGroup = ProjectivePoint;
+        }
+
+        impl $crate::PrimeCurveParams for $curve {
+            type FieldElement = FieldElement;
+            type PointArithmetic = $crate::__define_curve_point_arithmetic!($($arith)?);
+
+            const EQUATION_A: FieldElement = FieldElement::from_hex($a);
+            const EQUATION_B: FieldElement = FieldElement::from_hex($b);
+            const GENERATOR: (FieldElement, FieldElement) =
+                (FieldElement::from_hex($gx), FieldElement::from_hex($gy));
+
+            $($crate::__define_curve_glv_lincomb!($beta);)?
+        }
+
+        $(
+            impl $crate::GlvParams for $curve {
+                const BETA: FieldElement = FieldElement::from_hex($beta);
+                const LAMBDA: Scalar = Scalar::from_hex($lambda);
+                const MINUS_B1: Scalar = Scalar::from_hex($minus_b1);
+                const MINUS_B2: Scalar = Scalar::from_hex($minus_b2);
+                const G1: $uint = <$uint>::from_be_hex($g1);
+                const G2: $uint = <$uint>::from_be_hex($g2);
+            }
+        )?
+    };
+}
+
+/// Override `PrimeCurveParams::lincomb` of a curve defined with
+/// [`define_curve!`] to use its `GlvParams`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_curve_glv_lincomb {
+    ($beta:expr) => {
+        fn lincomb(
+            xks: &[($crate::ProjectivePoint<Self>, Self::Scalar)],
+        ) -> $crate::ProjectivePoint<Self> {
+            <Self as $crate::GlvParams>::lincomb_glv(xks)
+        }
+    };
+}
+
+/// Implement `AssociatedOid` for a curve defined with [`define_curve!`].
+#[cfg(feature = "pkcs8")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_curve_oid {
+    ($curve:ident) => {};
+    ($curve:ident, $oid:expr) => {
+        impl $crate::elliptic_curve::pkcs8::AssociatedOid for $curve {
+            const OID: $crate::elliptic_curve::pkcs8::ObjectIdentifier =
+                $crate::elliptic_curve::pkcs8::ObjectIdentifier::new_unwrap($oid);
+        }
+    };
+}
+
+/// Implement `AssociatedOid` for a curve defined with [`define_curve!`].
+#[cfg(not(feature = "pkcs8"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_curve_oid {
+    ($curve:ident $(, $oid:expr)?) => {};
+}
+
+/// Select the point arithmetic backend of a curve defined with
+/// [`define_curve!`], defaulting to the generic formulas.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_curve_point_arithmetic {
+    () => {
+        $crate::point_arithmetic::EquationAIsGeneric
+    };
+    ($arith:ty) => {
+        $arith
+    };
+}
+
+/// Define a field element type for a curve defined with [`define_curve!`],
+/// backed by `ConstMontyForm`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_curve_field {
+    ($vis:vis, $curve:ident, $fe:ident, $uint:ty, $modulus:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone, Copy)]
+        $vis struct $fe($uint);
+
+        $crate::primefield::impl_monty_field_element!(
+            $curve,
+            $fe,
+            $crate::elliptic_curve::FieldBytes<$curve>,
+            $uint,
+            $modulus
+        );
+    };
+}
+
+/// Add the scalar-specific impls required by `CurveArithmetic` to a field
+/// element type defined with `__define_curve_field!`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_curve_scalar {
+    ($curve:ident, $scalar:ident, $uint:ty) => {
+        const _: () = {
+            use core::ops::{Shr, ShrAssign};
+            use $crate::elliptic_curve::{
+                bigint::NonZero,
+                ff::PrimeField,
+                ops::Reduce,
+                scalar::{FromUintUnchecked, IsHigh},
+                subtle::{Choice, ConstantTimeGreater},
+                Curve, Error, FieldBytes, FieldBytesEncoding, Result, ScalarPrimitive,
+            };
+
+            impl $scalar {
+                /// Right shifts the scalar.
+                ///
+                /// Note: not constant-time with respect to the `shift` parameter.
+                pub const fn shr_vartime(&self, shift: u32) -> Self {
+                    Self::from_uint_unchecked(self.to_canonical().wrapping_shr_vartime(shift))
+                }
+            }
+
+            impl AsRef<$scalar> for $scalar {
+                fn as_ref(&self) -> &$scalar {
+                    self
+                }
+            }
+
+            impl FromUintUnchecked for $scalar {
+                type Uint = $uint;
+
+                fn from_uint_unchecked(uint: Self::Uint) -> Self {
+                    Self::from_uint_unchecked(uint)
+                }
+            }
+
+            impl IsHigh for $scalar {
+                fn is_high(&self) -> Choice {
+                    const MODULUS_SHR1: $uint = <$curve as Curve>::ORDER.shr_vartime(1);
+                    self.to_canonical().ct_gt(&MODULUS_SHR1)
+                }
+            }
+
+            impl Shr<usize> for $scalar {
+                type Output = Self;
+
+                fn shr(self, rhs: usize) -> Self::Output {
+                    self.shr_vartime(rhs as u32)
+                }
+            }
+
+            impl Shr<usize> for &$scalar {
+                type Output = $scalar;
+
+                fn shr(self, rhs: usize) -> Self::Output {
+                    self.shr_vartime(rhs as u32)
+                }
+            }
+
+            impl ShrAssign<usize> for $scalar {
+                fn shr_assign(&mut self, rhs: usize) {
+                    *self = *self >> rhs;
+                }
+            }
+
+            impl Reduce<$uint> for $scalar {
+                type Bytes = FieldBytes<$curve>;
+
+                fn reduce(w: $uint) -> Self {
+                    // A single conditional subtraction is only enough when
+                    // `n > 2^(W - 1)`, so compute the full remainder instead.
+                    // This is variable-time only with respect to the public
+                    // modulus.
+                    let n = NonZero::new(<$curve as Curve>::ORDER).unwrap();
+                    Self::from_uint_unchecked(w.rem_vartime(&n))
+                }
+
+                #[inline]
+                fn reduce_bytes(bytes: &FieldBytes<$curve>) -> Self {
+                    let w = <$uint as FieldBytesEncoding<$curve>>::decode_field_bytes(bytes);
+                    Self::reduce(w)
+                }
+            }
+
+            impl From<ScalarPrimitive<$curve>> for $scalar {
+                fn from(w: ScalarPrimitive<$curve>) -> Self {
+                    $scalar::from(&w)
+                }
+            }
+
+            impl From<&ScalarPrimitive<$curve>> for $scalar {
+                fn from(w: &ScalarPrimitive<$curve>) -> $scalar {
+                    $scalar::from_uint_unchecked(*w.as_uint())
+                }
+            }
+
+            impl From<$scalar> for ScalarPrimitive<$curve> {
+                fn from(scalar: $scalar) -> ScalarPrimitive<$curve> {
+                    ScalarPrimitive::from(&scalar)
+                }
+            }
+
+            impl From<&$scalar> for ScalarPrimitive<$curve> {
+                fn from(scalar: &$scalar) -> ScalarPrimitive<$curve> {
+                    ScalarPrimitive::new(scalar.into()).unwrap()
+                }
+            }
+
+            impl From<$scalar> for FieldBytes<$curve> {
+                fn from(scalar: $scalar) -> Self {
+                    scalar.to_repr()
+                }
+            }
+
+            impl From<&$scalar> for FieldBytes<$curve> {
+                fn from(scalar: &$scalar) -> Self {
+                    scalar.to_repr()
+                }
+            }
+
+            impl From<$scalar> for $uint {
+                fn from(scalar: $scalar) -> $uint {
+                    <$uint>::from(&scalar)
+                }
+            }
+
+            impl From<&$scalar> for $uint {
+                fn from(scalar: &$scalar) -> $uint {
+                    scalar.to_canonical()
+                }
+            }
+
+            impl TryFrom<$uint> for $scalar {
+                type Error = Error;
+
+                fn try_from(w: $uint) -> Result<Self> {
+                    Option::from(Self::from_uint(w)).ok_or(Error)
+                }
+            }
+        };
+    };
+}
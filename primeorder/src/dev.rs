@@ -2,9 +2,73 @@
 
 // TODO(tarcieri): move all development-related macros into this module
 
+mod audit;
+
+pub use self::audit::{
+    AuditError, AuditReport, DomainParams, TwistSecurity, CM_DISCRIMINANT_BOUND,
+    EMBEDDING_DEGREE_BOUND, POLLARD_RHO_BOUND, TRIAL_DIVISION_BOUND,
+};
+
 /// Implement projective arithmetic tests.
+///
+/// The `diffie_hellman_x` arm implements tests for the curve's
+/// [`diffie_hellman_x`] re-export instead. It is separate so that callers can
+/// gate it on their own `ecdh` feature.
+///
+/// [`diffie_hellman_x`]: crate::ecdh::diffie_hellman_x
 #[macro_export]
 macro_rules! impl_projective_arithmetic_tests {
+    (
+        diffie_hellman_x: $diffie_hellman_x:path,
+        $affine:tt,
+        $projective:tt,
+        $scalar:ty,
+        $mul_vectors:expr
+    ) => {
+        #[test]
+        fn test_vector_diffie_hellman_x() {
+            use $crate::elliptic_curve::{
+                ecdh, group::GroupEncoding, sec1::ToEncodedPoint, NonZeroScalar,
+            };
+
+            fn non_zero<C: $crate::PrimeCurveParams>(
+                _: &$crate::ProjectivePoint<C>,
+                k: C::Scalar,
+            ) -> NonZeroScalar<C> {
+                NonZeroScalar::new(k).unwrap()
+            }
+
+            let generator = $affine::GENERATOR.to_encoded_point(true);
+
+            for (k, x, _) in $mul_vectors.iter().cloned() {
+                let k = <$scalar>::from_repr($crate::array::Array::clone_from_slice(&k)).unwrap();
+                let k = non_zero(&$projective::GENERATOR, k);
+
+                let shared = $diffie_hellman_x(k, generator.as_bytes()).unwrap();
+                assert_eq!(shared.raw_secret_bytes().as_slice(), &x[..]);
+
+                let shared = $diffie_hellman_x(k, generator.x().unwrap()).unwrap();
+                assert_eq!(shared.raw_secret_bytes().as_slice(), &x[..]);
+
+                let peer = ($projective::GENERATOR * <$scalar>::from(7u64) * *k).to_affine();
+                let expected = ecdh::diffie_hellman(k, peer);
+                let shared = $diffie_hellman_x(k, peer.to_encoded_point(true).as_bytes()).unwrap();
+                assert_eq!(shared.raw_secret_bytes(), expected.raw_secret_bytes());
+            }
+
+            // Only x-coordinates of points on the curve are accepted
+            let k = non_zero(&$projective::GENERATOR, <$scalar>::from(3u64));
+            let mut bytes = generator.as_bytes().to_vec();
+            bytes[1..].fill(0);
+
+            for i in 0..32 {
+                *bytes.last_mut().unwrap() = i;
+                let point = $affine::from_bytes(&$crate::array::Array::clone_from_slice(&bytes));
+                let on_curve = bool::from(point.is_some());
+                assert_eq!($diffie_hellman_x(k, &bytes).is_ok(), on_curve);
+            }
+        }
+    };
     (
         $affine: tt,
         $projective:tt,
@@ -150,5 +214,310 @@ macro_rules! impl_projective_arithmetic_tests {
                 assert_point_eq!(p, coords);
             }
         }
+
+        #[test]
+        fn test_vector_mul_by_generator() {
+            use $crate::elliptic_curve::ops::MulByGenerator;
+
+            for (k, x, y) in $mul_vectors.iter().cloned() {
+                let k = <$scalar>::from_repr($crate::array::Array::clone_from_slice(&k)).unwrap();
+                let p = $projective::mul_by_generator(&k);
+                assert_point_eq!(p, (x, y));
+            }
+        }
+
+        #[test]
+        fn test_vector_lincomb() {
+            use $crate::elliptic_curve::ops::LinearCombination;
+
+            let generator = $projective::GENERATOR;
+            let scalars = $mul_vectors
+                .iter()
+                .map(|(k, _, _)| {
+                    <$scalar>::from_repr($crate::array::Array::clone_from_slice(k)).unwrap()
+                })
+                .collect::<Vec<_>>();
+
+            for pair in scalars.windows(2) {
+                let (k, l) = (pair[0], pair[1]);
+                let x = generator.double();
+                let reference = generator * &k + x * &l;
+
+                assert_eq!($projective::lincomb(&[(generator, k), (x, l)]), reference);
+                assert_eq!(
+                    $projective::lincomb(&[(generator, k), (x, l)][..]),
+                    reference
+                );
+            }
+
+            let pairs = scalars
+                .iter()
+                .enumerate()
+                .map(|(i, k)| (generator * <$scalar>::from(i as u64 + 2), *k))
+                .collect::<Vec<_>>();
+            let reference = pairs.iter().map(|(x, k)| *x * k).sum::<$projective>();
+            assert_eq!($projective::lincomb(pairs.as_slice()), reference);
+        }
+
+        #[test]
+        fn test_vector_mul_vartime() {
+            let generator = $projective::GENERATOR;
+
+            for (k, x, y) in $mul_vectors.iter().cloned() {
+                let k = <$scalar>::from_repr($crate::array::Array::clone_from_slice(&k)).unwrap();
+                assert_point_eq!(generator.mul_vartime(&k), (x, y));
+                assert_point_eq!((-generator).mul_vartime(&-k), (x, y));
+            }
+        }
+
+        #[test]
+        fn test_vector_lincomb_vartime() {
+            let generator = $projective::GENERATOR;
+            let pairs = $mul_vectors
+                .iter()
+                .enumerate()
+                .map(|(i, (k, _, _))| {
+                    let k = <$scalar>::from_repr($crate::array::Array::clone_from_slice(k));
+                    (generator * <$scalar>::from(i as u64 + 2), k.unwrap())
+                })
+                .collect::<Vec<_>>();
+
+            for i in 0..=pairs.len() {
+                let reference = pairs[..i].iter().map(|(x, k)| *x * k).sum::<$projective>();
+                assert_eq!($projective::lincomb_vartime(&pairs[..i]), reference);
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn test_vector_batch_add() {
+            let generator = $projective::GENERATOR;
+            let points = (1..6u64)
+                .map(|i| (generator * <$scalar>::from(i)).to_affine())
+                .chain([$affine::IDENTITY, -$affine::GENERATOR])
+                .collect::<Vec<_>>();
+
+            // All pairs, including doublings and a point plus its negation
+            let (lhs, rhs): (Vec<_>, Vec<_>) = points
+                .iter()
+                .flat_map(|p| points.iter().map(move |q| (*p, *q)))
+                .unzip();
+
+            let sums = $affine::batch_add(&lhs, &rhs);
+            assert_eq!(sums.len(), lhs.len());
+
+            for ((p, q), sum) in lhs.iter().zip(&rhs).zip(&sums) {
+                assert_eq!(*sum, ($projective::from(*p) + q).to_affine());
+            }
+
+            assert!($affine::batch_add(&[], &[]).is_empty());
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn test_vector_msm() {
+            let generator = $projective::GENERATOR;
+            let (points, scalars): (Vec<_>, Vec<_>) = $mul_vectors
+                .iter()
+                .enumerate()
+                .map(|(i, (k, _, _))| {
+                    let k = <$scalar>::from_repr($crate::array::Array::clone_from_slice(k));
+                    (generator * <$scalar>::from(i as u64 + 2), k.unwrap())
+                })
+                .chain((0..40).map(|i| {
+                    let x = generator * <$scalar>::from(i as u64 + 3);
+                    (x, -<$scalar>::from(i as u64 * 0x1337 + 1))
+                }))
+                .unzip();
+
+            for i in [0, 1, 2, 5, points.len()] {
+                let reference = points[..i]
+                    .iter()
+                    .zip(&scalars[..i])
+                    .map(|(x, k)| *x * k)
+                    .sum::<$projective>();
+
+                assert_eq!($projective::msm(&points[..i], &scalars[..i]), reference);
+                assert_eq!(
+                    $projective::msm_vartime(&points[..i], &scalars[..i]),
+                    reference
+                );
+            }
+        }
+    };
+}
+
+/// Implement tests which audit a curve's domain parameters, comparing the
+/// resulting [`AuditReport`] against the expected one.
+#[macro_export]
+macro_rules! impl_domain_params_tests {
+    ($curve:ty, $expected_report:expr) => {
+        #[test]
+        fn domain_params() {
+            let report = $crate::dev::DomainParams::from_curve::<$curve>()
+                .audit()
+                .expect("invalid domain parameters");
+
+            assert_eq!(report, $expected_report);
+        }
+    };
+}
+
+/// Implement tests which check that [`VartimeVerifyingKey`] accepts and
+/// rejects the same signatures as [`ecdsa_core::VerifyingKey`].
+///
+/// With test vectors, the signatures from the vectors are checked. Without
+/// them, signatures are computed with [`ecdsa_core::SigningKey`], which
+/// requires the curve to implement `DigestPrimitive`.
+///
+/// [`VartimeVerifyingKey`]: crate::ecdsa::VartimeVerifyingKey
+#[macro_export]
+macro_rules! impl_vartime_verifying_key_tests {
+    ($curve:ty, $vectors:expr) => {
+        #[test]
+        fn vartime_verifying_key() {
+            use $crate::{
+                ecdsa::VartimeVerifyingKey,
+                ecdsa_core::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey},
+                elliptic_curve::{array::Array, sec1::EncodedPoint},
+            };
+
+            for vector in $vectors {
+                let q_encoded = EncodedPoint::<$curve>::from_affine_coordinates(
+                    Array::from_slice(vector.q_x),
+                    Array::from_slice(vector.q_y),
+                    false,
+                );
+                let verifying_key = VerifyingKey::<$curve>::from_encoded_point(&q_encoded).unwrap();
+                let vartime_key = VartimeVerifyingKey::from(verifying_key);
+
+                let signature = Signature::<$curve>::from_scalars(
+                    Array::try_from(vector.r).unwrap(),
+                    Array::try_from(vector.s).unwrap(),
+                )
+                .unwrap();
+                assert!(vartime_key.verify_prehash(vector.m, &signature).is_ok());
+
+                let mut buf = [0u8; 128];
+                let prehash = &mut buf[..vector.m.len()];
+                prehash.copy_from_slice(vector.m);
+                prehash[0] ^= 1;
+                assert!(verifying_key.verify_prehash(prehash, &signature).is_err());
+                assert!(vartime_key.verify_prehash(prehash, &signature).is_err());
+            }
+        }
+    };
+    ($curve:ty) => {
+        #[test]
+        fn vartime_verifying_key() {
+            use $crate::{
+                ecdsa::VartimeVerifyingKey,
+                ecdsa_core::{
+                    signature::hazmat::{PrehashSigner, PrehashVerifier},
+                    Signature, SigningKey,
+                },
+                elliptic_curve::{NonZeroScalar, Scalar},
+            };
+
+            for i in 1..=16u64 {
+                let d = Scalar::<$curve>::from(i.wrapping_mul(0x9e3779b97f4a7c15));
+                let signing_key = SigningKey::<$curve>::from(NonZeroScalar::new(d).unwrap());
+                let verifying_key = *signing_key.verifying_key();
+                let vartime_key = VartimeVerifyingKey::from(verifying_key);
+
+                let mut prehash = [i as u8; 64];
+                let signature: Signature<$curve> = signing_key.sign_prehash(&prehash).unwrap();
+                assert!(verifying_key.verify_prehash(&prehash, &signature).is_ok());
+                assert!(vartime_key.verify_prehash(&prehash, &signature).is_ok());
+
+                prehash[0] ^= 1;
+                assert!(verifying_key.verify_prehash(&prehash, &signature).is_err());
+                assert!(vartime_key.verify_prehash(&prehash, &signature).is_err());
+            }
+        }
+    };
+}
+
+/// Implement tests for a curve's hash-to-curve implementation using the
+/// simplified SWU map, i.e. [`impl_hash2curve!`].
+///
+/// [`impl_hash2curve!`]: crate::impl_hash2curve
+#[macro_export]
+macro_rules! impl_hash2curve_tests {
+    ($curve:ty, $affine:ty, $field_element:ty, $scalar:ty) => {
+        #[test]
+        fn params() {
+            use $crate::{elliptic_curve::Field, hash2curve::SswuParams, PrimeCurveParams};
+
+            // Criteria of RFC 9380 § H.2, for the curve the map is applied to
+            let (a, b) = match <$curve as SswuParams>::ISOGENY {
+                Some(iso) => (iso.a, iso.b),
+                None => (
+                    <$curve as PrimeCurveParams>::EQUATION_A,
+                    <$curve as PrimeCurveParams>::EQUATION_B,
+                ),
+            };
+            let z = <$curve as SswuParams>::Z;
+
+            assert!(bool::from(z.sqrt().is_none()));
+            assert_ne!(z, -<$field_element>::ONE);
+
+            let x = b * (z * a).invert().unwrap();
+            assert!(bool::from((x.square() * x + a * x + b).sqrt().is_some()));
+        }
+
+        #[test]
+        fn map_to_curve() {
+            use $crate::{
+                elliptic_curve::{
+                    hash2curve::MapToCurve,
+                    point::AffineCoordinates,
+                    sec1::{FromEncodedPoint, ToEncodedPoint},
+                },
+                hash2curve::SswuParams,
+            };
+
+            for u in (0..64u64)
+                .map(<$field_element>::from)
+                .chain([-<$field_element>::ONE])
+            {
+                let p = u.map_to_curve().to_affine();
+                assert!(!bool::from(p.is_identity()));
+
+                // The isogeny doesn't preserve the sign of `y`
+                if <$curve as SswuParams>::ISOGENY.is_none() {
+                    assert_eq!(p.y_is_odd().unwrap_u8(), u.is_odd().unwrap_u8());
+                }
+
+                let encoded = p.to_encoded_point(false);
+                assert_eq!(<$affine>::from_encoded_point(&encoded).unwrap(), p);
+            }
+        }
+
+        #[test]
+        fn from_okm() {
+            use $crate::elliptic_curve::{array::Array, hash2curve::FromOkm};
+
+            let mut okm = Array::<u8, <$field_element as FromOkm>::Length>::default();
+            let last = okm.len() - 1;
+            okm[0] = 1;
+            okm[last] = 3;
+
+            assert_eq!(
+                <$field_element>::from_okm(&okm),
+                <$field_element>::from(256u64).pow_vartime(&[last as u64])
+                    + <$field_element>::from(3u64)
+            );
+
+            let mut okm = Array::<u8, <$scalar as FromOkm>::Length>::default();
+            let last = okm.len() - 1;
+            okm[0] = 1;
+            okm[last] = 3;
+
+            assert_eq!(
+                <$scalar>::from_okm(&okm),
+                <$scalar>::from(256u64).pow_vartime(&[last as u64]) + <$scalar>::from(3u64)
+            );
+        }
     };
 }
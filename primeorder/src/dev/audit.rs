@@ -0,0 +1,436 @@
+//! Domain parameter auditing.
+//!
+//! Checks the hardcoded constants of a curve implementation using simple
+//! variable-time affine arithmetic which is independent of the curve's own
+//! field and point arithmetic, so a mistake in either is caught rather than
+//! reproduced.
+
+use crate::{
+    params::{is_probable_prime, is_within_hasse_bound},
+    Field, PrimeCurveParams, PrimeField,
+};
+use elliptic_curve::{
+    bigint::{
+        modular::{MontyForm, MontyParams},
+        Limb, NonZero, Odd, Word, U640,
+    },
+    FieldBytesEncoding,
+};
+
+/// Integer type used for all computations, wide enough for `4p` on P-521.
+type Wide = U640;
+
+/// Field element (or integer modulo `n`) in Montgomery form.
+type Fe = MontyForm<{ Wide::LIMBS }>;
+
+/// Affine point, or `None` for the point at infinity.
+type Point = Option<(Fe, Fe)>;
+
+/// Small prime factors of the twist order are removed by trial division up to
+/// this bound.
+pub const TRIAL_DIVISION_BOUND: Word = 1 << 16;
+
+/// Largest number of iterations of Pollard's rho used to split a composite
+/// factor of the twist order, which finds most factors of up to ~36 bits.
+pub const POLLARD_RHO_BOUND: u32 = 1 << 18;
+
+/// Number of iterations of Pollard's rho between GCD computations.
+const POLLARD_RHO_BATCH_SIZE: u32 = 128;
+
+/// Largest embedding degree which is searched for.
+pub const EMBEDDING_DEGREE_BOUND: u32 = 100;
+
+/// Largest absolute CM discriminant which is searched for.
+pub const CM_DISCRIMINANT_BOUND: Word = 1 << 16;
+
+/// Reasons for domain parameters to be rejected.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AuditError {
+    /// Field modulus `p` is not prime.
+    FieldModulusNotPrime,
+
+    /// `a`, `b` or a generator coordinate is not reduced modulo `p`.
+    FieldElementNotReduced,
+
+    /// Discriminant `4a³ + 27b²` is zero.
+    SingularCurve,
+
+    /// Generator doesn't satisfy the curve equation.
+    GeneratorNotOnCurve,
+
+    /// Group order `n` is not prime.
+    OrderNotPrime,
+
+    /// Group order `n` is outside of the Hasse interval `p + 1 ± 2√p`.
+    OrderOutsideHasseBound,
+
+    /// `[n]G` is not the identity.
+    GeneratorOrderMismatch,
+}
+
+/// Approximate security in bits of the quadratic twist against Pollard's rho,
+/// i.e. half the size of the largest prime factor of the twist order.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TwistSecurity {
+    /// The twist order was fully factored.
+    Exact(u32),
+
+    /// The twist order has a composite factor of `unfactored_bits` bits which
+    /// couldn't be split by trial division up to [`TRIAL_DIVISION_BOUND`] and
+    /// [`POLLARD_RHO_BOUND`] iterations of Pollard's rho.
+    ///
+    /// `bits` is a lower bound: every prime factor of the composite factor is
+    /// above [`TRIAL_DIVISION_BOUND`], which bounds how many of them there
+    /// are and hence how small the largest of them can be.
+    AtLeast {
+        /// Lower bound on the security in bits.
+        bits: u32,
+
+        /// Size in bits of the largest unfactored composite factor.
+        unfactored_bits: u32,
+    },
+}
+
+/// Security properties of domain parameters which passed the audit.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AuditReport {
+    /// Security of the quadratic twist against Pollard's rho.
+    pub twist_security: TwistSecurity,
+
+    /// Embedding degree `k`, i.e. the smallest `k` such that `n | pᵏ - 1`.
+    ///
+    /// `None` if it exceeds [`EMBEDDING_DEGREE_BOUND`], making the MOV/FR
+    /// reductions to a finite field discrete logarithm infeasible.
+    pub embedding_degree: Option<u32>,
+
+    /// Absolute value of the CM field discriminant, i.e. of the fundamental
+    /// discriminant of `t² - 4p` where `t = p + 1 - n` is the trace of
+    /// Frobenius.
+    ///
+    /// `None` if it exceeds [`CM_DISCRIMINANT_BOUND`].
+    pub cm_discriminant: Option<Word>,
+}
+
+/// Domain parameters of a prime order short Weierstrass curve
+/// `y² = x³ + ax + b` with generator `G = (gx, gy)` of order `n`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DomainParams {
+    p: Wide,
+    a: Wide,
+    b: Wide,
+    gx: Wide,
+    gy: Wide,
+    n: Wide,
+}
+
+impl DomainParams {
+    /// Create domain parameters from big endian integers.
+    ///
+    /// # Panics
+    ///
+    /// If any of the integers exceeds 640 bits.
+    pub fn new(p: &[u8], a: &[u8], b: &[u8], gx: &[u8], gy: &[u8], n: &[u8]) -> Self {
+        Self {
+            p: wide_from_be_slice(p),
+            a: wide_from_be_slice(a),
+            b: wide_from_be_slice(b),
+            gx: wide_from_be_slice(gx),
+            gy: wide_from_be_slice(gy),
+            n: wide_from_be_slice(n),
+        }
+    }
+
+    /// Extract the domain parameters hardcoded in a curve implementation.
+    pub fn from_curve<C: PrimeCurveParams>() -> Self {
+        // `p - 1` is the largest representable field element.
+        let p_minus_one = wide_from_be_slice(&(-C::FieldElement::ONE).to_repr());
+        let (gx, gy) = C::GENERATOR;
+
+        Self {
+            p: p_minus_one.wrapping_add(&Wide::ONE),
+            a: wide_from_be_slice(&C::EQUATION_A.to_repr()),
+            b: wide_from_be_slice(&C::EQUATION_B.to_repr()),
+            gx: wide_from_be_slice(&gx.to_repr()),
+            gy: wide_from_be_slice(&gy.to_repr()),
+            n: wide_from_be_slice(&C::ORDER.encode_field_bytes()),
+        }
+    }
+
+    /// Check the domain parameters, and report on the security properties of
+    /// the curve if they are valid.
+    pub fn audit(&self) -> Result<AuditReport, AuditError> {
+        let p = Option::from(Odd::new(self.p))
+            .filter(|p| self.p > Wide::from_u8(3) && is_probable_prime(p))
+            .ok_or(AuditError::FieldModulusNotPrime)?;
+
+        if [self.a, self.b, self.gx, self.gy]
+            .iter()
+            .any(|x| x >= &self.p)
+        {
+            return Err(AuditError::FieldElementNotReduced);
+        }
+
+        let field = MontyParams::new_vartime(p);
+        let fe = |x: &Wide| Fe::new(x, field);
+        let (a, b, gx, gy) = (fe(&self.a), fe(&self.b), fe(&self.gx), fe(&self.gy));
+        let small = |x: u8| fe(&Wide::from_u8(x));
+
+        if is_zero(&(small(4) * a.square() * a + small(27) * b.square())) {
+            return Err(AuditError::SingularCurve);
+        }
+
+        if gy.square() != gx.square() * gx + a * gx + b {
+            return Err(AuditError::GeneratorNotOnCurve);
+        }
+
+        let n = Option::from(Odd::new(self.n))
+            .filter(is_probable_prime)
+            .ok_or(AuditError::OrderNotPrime)?;
+
+        if !is_within_hasse_bound(&self.p, &self.n) {
+            return Err(AuditError::OrderOutsideHasseBound);
+        }
+
+        // Within the Hasse bound, t² ≤ 4p doesn't overflow.
+        let p_plus_one = self.p.wrapping_add(&Wide::ONE);
+        let trace = if p_plus_one >= self.n {
+            p_plus_one.wrapping_sub(&self.n)
+        } else {
+            self.n.wrapping_sub(&p_plus_one)
+        };
+        let four_p = self.p.wrapping_shl_vartime(2);
+        let trace_squared = trace.wrapping_mul(&trace);
+
+        if mul(Some((gx, gy)), &a, &self.n).is_some() {
+            return Err(AuditError::GeneratorOrderMismatch);
+        }
+
+        // The twist has order 2(p + 1) - n.
+        let twist_order = p_plus_one.wrapping_shl_vartime(1).wrapping_sub(&self.n);
+
+        Ok(AuditReport {
+            twist_security: twist_security(twist_order),
+            embedding_degree: embedding_degree(&self.p, n),
+            cm_discriminant: cm_discriminant(four_p.wrapping_sub(&trace_squared)),
+        })
+    }
+}
+
+/// Estimate the security of the twist from the prime factorization of its
+/// order `x`.
+fn twist_security(mut x: Wide) -> TwistSecurity {
+    let mut factors = Factors {
+        largest_prime_bits: 0,
+        unfactored_bits: 0,
+    };
+
+    for d in 2..TRIAL_DIVISION_BOUND {
+        let d = NonZero::new(Limb(d)).unwrap();
+
+        loop {
+            let (quotient, remainder) = x.div_rem_limb(d);
+            if remainder != Limb::ZERO {
+                break;
+            }
+            x = quotient;
+            factors.largest_prime_bits = Limb::BITS - d.get().0.leading_zeros();
+        }
+    }
+
+    if x != Wide::ONE {
+        factors.factor(x);
+    }
+
+    if factors.unfactored_bits == 0 {
+        return TwistSecurity::Exact(factors.largest_prime_bits / 2);
+    }
+
+    // The unfactored composite `c` has at most `m` prime factors, all above
+    // the trial division bound, so the largest of them is at least `c^(1/m)`.
+    let bound_bits = TRIAL_DIVISION_BOUND.trailing_zeros();
+    let max_factors = (factors.unfactored_bits - 1) / bound_bits;
+    let smallest_largest_prime_bits = (factors.unfactored_bits - 1) / max_factors + 1;
+
+    TwistSecurity::AtLeast {
+        bits: factors.largest_prime_bits.max(smallest_largest_prime_bits) / 2,
+        unfactored_bits: factors.unfactored_bits,
+    }
+}
+
+/// Sizes of the factors of the twist order which are found.
+struct Factors {
+    /// Size in bits of the largest prime factor.
+    largest_prime_bits: u32,
+
+    /// Size in bits of the largest composite factor which couldn't be split.
+    unfactored_bits: u32,
+}
+
+impl Factors {
+    /// Factor `x`, which must be odd and have no prime factors below
+    /// [`TRIAL_DIVISION_BOUND`].
+    fn factor(&mut self, x: Wide) {
+        let x = Odd::new(x).unwrap();
+        let bits = x.bits_vartime();
+
+        if is_probable_prime(&x) {
+            self.largest_prime_bits = self.largest_prime_bits.max(bits);
+        } else if let Some(d) = pollard_rho(&x) {
+            self.factor(d);
+            self.factor(x.wrapping_div_vartime(&NonZero::new(d).unwrap()));
+        } else {
+            self.unfactored_bits = self.unfactored_bits.max(bits);
+        }
+    }
+}
+
+/// Find a nontrivial factor of the odd composite `n` using Brent's variant of
+/// Pollard's rho, giving up after [`POLLARD_RHO_BOUND`] iterations.
+fn pollard_rho(n: &Odd<Wide>) -> Option<Wide> {
+    let params = MontyParams::new_vartime(*n);
+    let one = Fe::one(params);
+
+    // Differences are accumulated in Montgomery form, which is coprime to `n`
+    // so doesn't affect the GCD.
+    let gcd = |x: &Fe| n.as_ref().gcd(x.as_montgomery()).unwrap();
+
+    // Retry with a different polynomial `x² + c` if the cycle is found for all
+    // prime factors at once.
+    for c in 1..=4 {
+        let c = Fe::new(&Wide::from_u8(c), params);
+        let f = |x: &Fe| x.square() + c;
+
+        let mut y = Fe::new(&Wide::from_u8(2), params);
+        let mut iterations = 0;
+        let mut r = 1;
+
+        'cycle: while iterations < POLLARD_RHO_BOUND {
+            let x = y;
+            for _ in 0..r {
+                y = f(&y);
+            }
+
+            let mut k = 0;
+            while k < r {
+                let ys = y;
+                let batch = POLLARD_RHO_BATCH_SIZE.min(r - k);
+                let mut q = one;
+                for _ in 0..batch {
+                    y = f(&y);
+                    q *= x - y;
+                }
+
+                let d = gcd(&q);
+                if d == *n.as_ref() {
+                    // Find the factor one step at a time.
+                    let mut ys = ys;
+                    for _ in 0..batch {
+                        ys = f(&ys);
+                        let d = gcd(&(x - ys));
+                        if d != Wide::ONE && d != *n.as_ref() {
+                            return Some(d);
+                        }
+                    }
+                    break 'cycle;
+                } else if d != Wide::ONE {
+                    return Some(d);
+                }
+
+                k += batch;
+                iterations += batch;
+            }
+
+            r *= 2;
+        }
+    }
+
+    None
+}
+
+/// Find the smallest `k` up to [`EMBEDDING_DEGREE_BOUND`] with `pᵏ ≡ 1 mod n`.
+fn embedding_degree(p: &Wide, n: Odd<Wide>) -> Option<u32> {
+    let params = MontyParams::new_vartime(n);
+    let p = Fe::new(&p.rem_vartime(n.as_nz_ref()), params);
+    let one = Fe::one(params);
+    let mut p_k = p;
+
+    for k in 1..=EMBEDDING_DEGREE_BOUND {
+        if p_k == one {
+            return Some(k);
+        }
+        p_k *= p;
+    }
+
+    None
+}
+
+/// Find the fundamental discriminant of `-m`, if its absolute value is at most
+/// [`CM_DISCRIMINANT_BOUND`].
+fn cm_discriminant(m: Wide) -> Option<Word> {
+    // `m = d·f²` for the smallest `d` such that `m / d` is a perfect square.
+    let d = (1..=CM_DISCRIMINANT_BOUND).find(|&d| {
+        let (quotient, remainder) = m.div_rem_limb(NonZero::new(Limb(d)).unwrap());
+        remainder == Limb::ZERO && {
+            let root = quotient.sqrt_vartime();
+            root.wrapping_mul(&root) == quotient
+        }
+    })?;
+
+    // -d is a fundamental discriminant iff -d ≡ 1 (mod 4), otherwise -4d is.
+    let d = if d % 4 == 3 { d } else { 4 * d };
+    Some(d).filter(|&d| d <= CM_DISCRIMINANT_BOUND)
+}
+
+/// Compute `[k]P` using variable-time double-and-add.
+fn mul(point: Point, a: &Fe, k: &Wide) -> Point {
+    let mut acc = None;
+
+    for i in (0..k.bits_vartime()).rev() {
+        acc = add(acc, acc, a);
+        if k.bit_vartime(i) {
+            acc = add(acc, point, a);
+        }
+    }
+
+    acc
+}
+
+/// Add two points using the textbook affine formulas.
+fn add(lhs: Point, rhs: Point, a: &Fe) -> Point {
+    let ((x1, y1), (x2, y2)) = match (lhs, rhs) {
+        (None, _) => return rhs,
+        (_, None) => return lhs,
+        (Some(lhs), Some(rhs)) => (lhs, rhs),
+    };
+
+    let lambda = if x1 != x2 {
+        (y2 - y1) * invert(&(x2 - x1))
+    } else if y1 == y2 && !is_zero(&y1) {
+        let x1_squared = x1.square();
+        (x1_squared + x1_squared + x1_squared + a) * invert(&(y1 + y1))
+    } else {
+        return None;
+    };
+
+    let x3 = lambda.square() - x1 - x2;
+    let y3 = lambda * (x1 - x3) - y1;
+    Some((x3, y3))
+}
+
+/// Invert a nonzero field element using Fermat's little theorem.
+fn invert(x: &Fe) -> Fe {
+    let p_minus_two = x.params().modulus().wrapping_sub(&Wide::from_u8(2));
+    x.pow(&p_minus_two)
+}
+
+/// Returns whether `x` is zero.
+fn is_zero(x: &Fe) -> bool {
+    x.retrieve() == Wide::ZERO
+}
+
+/// Decode an integer from a big endian byte slice of at most 640 bits.
+fn wide_from_be_slice(bytes: &[u8]) -> Wide {
+    let mut padded = [0u8; Wide::BYTES];
+    padded[(Wide::BYTES - bytes.len())..].copy_from_slice(bytes);
+    Wide::from_be_slice(&padded)
+}
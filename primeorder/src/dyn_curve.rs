@@ -0,0 +1,547 @@
+//! Prime order short Weierstrass curves whose domain parameters are only known
+//! at runtime.
+//!
+//! Certificates and smart card keys occasionally carry explicit
+//! `SpecifiedECDomain` parameters ([RFC 3279] § 2.3.5) rather than the OID of a
+//! named curve. [`DynCurve`] supports ECDSA verification and ECDH for such
+//! curves, using the same complete addition formulas as [`ProjectivePoint`]
+//! over runtime-modulus Montgomery arithmetic from `crypto-bigint`.
+//!
+//! [RFC 3279]: https://datatracker.ietf.org/doc/html/rfc3279
+//! [`ProjectivePoint`]: crate::ProjectivePoint
+
+#[cfg(feature = "pkcs8")]
+use crate::specified_curve::SpecifiedCurve;
+use crate::{
+    params::{is_probable_prime, is_within_hasse_bound},
+    point_arithmetic::{add_generic_a, double_generic_a},
+};
+use elliptic_curve::{
+    bigint::{
+        modular::{MontyForm, MontyParams},
+        Limb, NonZero, Odd, Uint, Word,
+    },
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess},
+    Error, Result,
+};
+
+/// Minimum size in bits of the field modulus and the group order of a curve
+/// loaded with [`DynCurve::new`], i.e. the size of the smallest NIST curve.
+pub const MIN_BITS: u32 = 192;
+
+/// SEC1 tag of a compressed point with an even y-coordinate.
+const TAG_COMPRESSED_EVEN_Y: u8 = 0x02;
+
+/// SEC1 tag of a compressed point with an odd y-coordinate.
+const TAG_COMPRESSED_ODD_Y: u8 = 0x03;
+
+/// SEC1 tag of an uncompressed point.
+const TAG_UNCOMPRESSED: u8 = 0x04;
+
+/// Prime order short Weierstrass curve `y² = x³ + ax + b` over a prime field,
+/// with domain parameters supplied at runtime.
+///
+/// `LIMBS` is the number of limbs used to represent both field elements and
+/// scalars, which bounds the size of the curves which can be loaded, e.g.
+/// `U256::LIMBS` for curves of up to 256 bits.
+///
+/// All byte-oriented inputs and outputs are big endian. Points are SEC1
+/// encoded, with field elements padded to [`DynCurve::field_size`] bytes.
+#[derive(Clone, Debug)]
+pub struct DynCurve<const LIMBS: usize> {
+    /// Montgomery parameters for the base field.
+    field: MontyParams<LIMBS>,
+
+    /// Montgomery parameters for the scalar field.
+    scalars: MontyParams<LIMBS>,
+
+    /// Coefficient `a` in the curve equation.
+    a: MontyForm<LIMBS>,
+
+    /// Coefficient `b` in the curve equation.
+    b: MontyForm<LIMBS>,
+
+    /// Generator point.
+    generator: Point<LIMBS>,
+
+    /// Size of a serialized field element in bytes.
+    field_size: usize,
+}
+
+impl<const LIMBS: usize> DynCurve<LIMBS> {
+    /// Load a curve from its domain parameters.
+    ///
+    /// - `p`: the prime modulus of the base field
+    /// - `a`, `b`: the coefficients of the curve equation
+    /// - `generator`: the SEC1-encoded generator point
+    /// - `order`: the prime order of the generator, which must also be the
+    ///   order of the curve, i.e. the cofactor must be 1
+    ///
+    /// Returns an error if the parameters don't fit in `LIMBS` limbs, if `p`
+    /// or `order` is smaller than [`MIN_BITS`] bits or isn't prime, if the
+    /// curve is singular, if the generator isn't on the curve, if the
+    /// generator's order isn't `order`, or if `order` isn't within the Hasse
+    /// bound `|p + 1 - n| ≤ 2√p`. Since the order of the curve is also within
+    /// that bound and a multiple of the generator's order, the last check
+    /// ensures that the cofactor is 1.
+    ///
+    /// Primality is checked with a Baillie-PSW test, so the parameters may
+    /// come from untrusted sources, e.g. the explicit parameters of a key.
+    /// This costs a few dozen modular exponentiations, so callers which load
+    /// the same parameters repeatedly should keep the resulting curve.
+    pub fn new(p: &[u8], a: &[u8], b: &[u8], generator: &[u8], order: &[u8]) -> Result<Self> {
+        let p = uint_from_be_slice::<LIMBS>(p)?;
+        let order = uint_from_be_slice::<LIMBS>(order)?;
+
+        if p.bits_vartime() < MIN_BITS
+            || order.bits_vartime() < MIN_BITS
+            || !is_within_hasse_bound(&p, &order)
+        {
+            return Err(Error);
+        }
+
+        let p = Option::from(Odd::new(p))
+            .filter(is_probable_prime)
+            .ok_or(Error)?;
+        let order = Option::from(Odd::new(order))
+            .filter(is_probable_prime)
+            .ok_or(Error)?;
+
+        let field = MontyParams::new_vartime(p);
+        let scalars = MontyParams::new_vartime(order);
+        let field_size = p.bits_vartime().div_ceil(8) as usize;
+
+        let a = field_element_from_be_slice(a, field)?;
+        let b = field_element_from_be_slice(b, field)?;
+
+        // Discriminant: 4a³ + 27b² ≠ 0
+        let four = MontyForm::new(&Uint::from_u8(4), field);
+        let twenty_seven = MontyForm::new(&Uint::from_u8(27), field);
+        if is_zero(&(four * a.square() * a + twenty_seven * b.square())) {
+            return Err(Error);
+        }
+
+        let mut curve = Self {
+            field,
+            scalars,
+            a,
+            b,
+            generator: Point::identity(field),
+            field_size,
+        };
+
+        curve.generator = curve.decode_point(generator)?;
+
+        if !curve.is_identity(&curve.mul(&curve.generator, &order)) {
+            return Err(Error);
+        }
+
+        Ok(curve)
+    }
+
+    /// Load a curve from the explicitly specified domain parameters of a key,
+    /// see [`SpecifiedCurve`].
+    ///
+    /// Returns an error if the cofactor is present and isn't 1, or under the
+    /// same conditions as [`DynCurve::new`], which in particular checks that
+    /// `[n] G` is the identity and that the curve's cofactor is 1.
+    #[cfg(feature = "pkcs8")]
+    pub fn from_specified(params: &SpecifiedCurve<'_>) -> Result<Self> {
+        if params.cofactor.is_some_and(|h| h != [1]) {
+            return Err(Error);
+        }
+
+        Self::new(params.p, params.a, params.b, params.base, params.order)
+    }
+
+    /// Size of a serialized field element in bytes.
+    pub fn field_size(&self) -> usize {
+        self.field_size
+    }
+
+    /// Prime modulus of the base field.
+    pub fn modulus(&self) -> &Odd<Uint<LIMBS>> {
+        self.field.modulus()
+    }
+
+    /// Order of the curve's group (i.e. scalar modulus).
+    pub fn order(&self) -> &Odd<Uint<LIMBS>> {
+        self.scalars.modulus()
+    }
+
+    /// Compute the uncompressed SEC1 encoding of the public key `[d] G` for
+    /// the given secret scalar `d`, writing it to `out`, which must be
+    /// `1 + 2 * field_size()` bytes long.
+    ///
+    /// The scalar multiplication is constant time.
+    pub fn public_key(&self, secret_key: &[u8], out: &mut [u8]) -> Result<()> {
+        if out.len() != 1 + 2 * self.field_size {
+            return Err(Error);
+        }
+
+        let d = self.decode_secret_key(secret_key)?;
+        let (x, y) = self
+            .to_affine(&self.mul(&self.generator, &d))
+            .ok_or(Error)?;
+
+        let (tag, coordinates) = out.split_at_mut(1);
+        let (x_out, y_out) = coordinates.split_at_mut(self.field_size);
+        tag[0] = TAG_UNCOMPRESSED;
+        uint_to_be_slice(&x.retrieve(), x_out);
+        uint_to_be_slice(&y.retrieve(), y_out);
+        Ok(())
+    }
+
+    /// Compute an ECDH shared secret, i.e. the x-coordinate of `[d] Q` for the
+    /// secret scalar `d` and the SEC1-encoded public key `Q`, writing it to
+    /// `shared_secret`, which must be `field_size()` bytes long.
+    ///
+    /// The scalar multiplication is constant time.
+    pub fn diffie_hellman(
+        &self,
+        secret_key: &[u8],
+        public_key: &[u8],
+        shared_secret: &mut [u8],
+    ) -> Result<()> {
+        if shared_secret.len() != self.field_size {
+            return Err(Error);
+        }
+
+        let d = self.decode_secret_key(secret_key)?;
+        let q = self.decode_point(public_key)?;
+        let (x, _) = self.to_affine(&self.mul(&q, &d)).ok_or(Error)?;
+
+        uint_to_be_slice(&x.retrieve(), shared_secret);
+        Ok(())
+    }
+
+    /// Verify an ECDSA signature `(r, s)` over the given message digest,
+    /// using the SEC1-encoded public key.
+    ///
+    /// The digest is converted to an integer using the leftmost bits of the
+    /// digest as described in [SEC1] § 4.1.4, and must be at least half as
+    /// long as the curve's order.
+    ///
+    /// [SEC1]: https://www.secg.org/sec1-v2.pdf
+    pub fn verify_prehash(
+        &self,
+        public_key: &[u8],
+        prehash: &[u8],
+        r: &[u8],
+        s: &[u8],
+    ) -> Result<()> {
+        let order = *self.order().as_ref();
+        let q = self.decode_point(public_key)?;
+        let r = uint_from_be_slice::<LIMBS>(r)?;
+        let s = uint_from_be_slice::<LIMBS>(s)?;
+
+        for x in [&r, &s] {
+            if x == &Uint::ZERO || x >= &order {
+                return Err(Error);
+            }
+        }
+
+        let e = MontyForm::new(&self.bits2int(prehash)?, self.scalars);
+        let s = MontyForm::new(&s, self.scalars);
+        let s_inv = s.pow(&order.wrapping_sub(&Uint::from_u8(2)));
+
+        // Guards against composite orders, for which Fermat inversion fails.
+        if s * s_inv != MontyForm::one(self.scalars) {
+            return Err(Error);
+        }
+
+        let u1 = (e * s_inv).retrieve();
+        let u2 = (MontyForm::new(&r, self.scalars) * s_inv).retrieve();
+        let point = self.lincomb_vartime(&self.generator, &u1, &q, &u2);
+        let (x, _) = self.to_affine(&point).ok_or(Error)?;
+
+        let nz_order = Option::<NonZero<_>>::from(NonZero::new(order)).ok_or(Error)?;
+        if x.retrieve().rem_vartime(&nz_order) == r {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Convert a message digest to an integer modulo the curve's order.
+    fn bits2int(&self, prehash: &[u8]) -> Result<Uint<LIMBS>> {
+        let order = *self.order().as_ref();
+        let order_bits = order.bits_vartime() as usize;
+        let order_size = order_bits.div_ceil(8);
+
+        if prehash.len() < order_size / 2 {
+            return Err(Error);
+        }
+
+        let prehash = &prehash[..prehash.len().min(order_size)];
+        let mut e = uint_from_be_slice::<LIMBS>(prehash)?;
+
+        if prehash.len() * 8 > order_bits {
+            e = e.wrapping_shr_vartime((prehash.len() * 8 - order_bits) as u32);
+        }
+
+        // e < 2^order_bits < 2 * order
+        let (reduced, borrow) = e.sbb(&order, Limb::ZERO);
+        Ok(Uint::conditional_select(
+            &reduced,
+            &e,
+            Choice::from((borrow.0 >> (Limb::BITS - 1)) as u8),
+        ))
+    }
+
+    /// Decode a secret scalar, checking that it is in the range `[1, n)`.
+    fn decode_secret_key(&self, bytes: &[u8]) -> Result<Uint<LIMBS>> {
+        let d = uint_from_be_slice::<LIMBS>(bytes)?;
+        let is_valid = !d.ct_eq(&Uint::ZERO) & d.ct_lt(self.order().as_ref());
+
+        Option::from(elliptic_curve::subtle::CtOption::new(d, is_valid)).ok_or(Error)
+    }
+
+    /// Decode a SEC1-encoded point, checking that it is on the curve. The
+    /// identity is rejected.
+    fn decode_point(&self, bytes: &[u8]) -> Result<Point<LIMBS>> {
+        let (tag, coordinates) = bytes.split_first().ok_or(Error)?;
+
+        let (x, y) = match *tag {
+            TAG_UNCOMPRESSED if coordinates.len() == 2 * self.field_size => {
+                let (x, y) = coordinates.split_at(self.field_size);
+                let x = field_element_from_be_slice(x, self.field)?;
+                let y = field_element_from_be_slice(y, self.field)?;
+
+                if y.square() != self.rhs(&x) {
+                    return Err(Error);
+                }
+
+                (x, y)
+            }
+            TAG_COMPRESSED_EVEN_Y | TAG_COMPRESSED_ODD_Y
+                if coordinates.len() == self.field_size =>
+            {
+                let x = field_element_from_be_slice(coordinates, self.field)?;
+                let y = sqrt_vartime(&self.rhs(&x)).ok_or(Error)?;
+                let is_odd = y.retrieve().bit_vartime(0);
+
+                if is_odd == (*tag == TAG_COMPRESSED_ODD_Y) {
+                    (x, y)
+                } else {
+                    (x, -y)
+                }
+            }
+            _ => return Err(Error),
+        };
+
+        Ok(Point {
+            x,
+            y,
+            z: MontyForm::one(self.field),
+        })
+    }
+
+    /// Right hand side of the curve equation: `x³ + ax + b`.
+    fn rhs(&self, x: &MontyForm<LIMBS>) -> MontyForm<LIMBS> {
+        (x.square() + self.a) * x + self.b
+    }
+
+    /// Returns `3b`.
+    fn b3(&self) -> MontyForm<LIMBS> {
+        self.b + self.b + self.b
+    }
+
+    fn add(&self, lhs: &Point<LIMBS>, rhs: &Point<LIMBS>) -> Point<LIMBS> {
+        let (x, y, z) = add_generic_a(
+            self.a,
+            self.b3(),
+            (lhs.x, lhs.y, lhs.z),
+            (rhs.x, rhs.y, rhs.z),
+        );
+
+        Point { x, y, z }
+    }
+
+    fn double(&self, point: &Point<LIMBS>) -> Point<LIMBS> {
+        let (x, y, z) = double_generic_a(self.a, self.b3(), (point.x, point.y, point.z));
+        Point { x, y, z }
+    }
+
+    /// Constant-time scalar multiplication using a double-and-add-always
+    /// ladder over the bits of the order.
+    fn mul(&self, point: &Point<LIMBS>, k: &Uint<LIMBS>) -> Point<LIMBS> {
+        let mut acc = Point::identity(self.field);
+
+        for i in (0..self.order().bits_vartime()).rev() {
+            acc = self.double(&acc);
+            let sum = self.add(&acc, point);
+            acc.conditional_assign(&sum, k.bit(i).into());
+        }
+
+        acc
+    }
+
+    /// Variable-time computation of `[k1] p1 + [k2] p2` using Shamir's trick.
+    fn lincomb_vartime(
+        &self,
+        p1: &Point<LIMBS>,
+        k1: &Uint<LIMBS>,
+        p2: &Point<LIMBS>,
+        k2: &Uint<LIMBS>,
+    ) -> Point<LIMBS> {
+        let p12 = self.add(p1, p2);
+        let mut acc = Point::identity(self.field);
+
+        for i in (0..self.order().bits_vartime()).rev() {
+            acc = self.double(&acc);
+
+            match (k1.bit_vartime(i), k2.bit_vartime(i)) {
+                (true, true) => acc = self.add(&acc, &p12),
+                (true, false) => acc = self.add(&acc, p1),
+                (false, true) => acc = self.add(&acc, p2),
+                (false, false) => (),
+            }
+        }
+
+        acc
+    }
+
+    fn is_identity(&self, point: &Point<LIMBS>) -> bool {
+        is_zero(&point.z)
+    }
+
+    /// Returns the affine coordinates of `point`, or `None` if it is the
+    /// identity.
+    fn to_affine(&self, point: &Point<LIMBS>) -> Option<(MontyForm<LIMBS>, MontyForm<LIMBS>)> {
+        if self.is_identity(point) {
+            return None;
+        }
+
+        let p_minus_2 = self.modulus().wrapping_sub(&Uint::from_u8(2));
+        let z_inv = point.z.pow(&p_minus_2);
+        Some((point.x * z_inv, point.y * z_inv))
+    }
+}
+
+/// Point in homogeneous projective coordinates `(X : Y : Z)`.
+#[derive(Clone, Copy, Debug)]
+struct Point<const LIMBS: usize> {
+    x: MontyForm<LIMBS>,
+    y: MontyForm<LIMBS>,
+    z: MontyForm<LIMBS>,
+}
+
+impl<const LIMBS: usize> Point<LIMBS> {
+    /// The identity `(0 : 1 : 0)`.
+    fn identity(field: MontyParams<LIMBS>) -> Self {
+        Self {
+            x: MontyForm::zero(field),
+            y: MontyForm::one(field),
+            z: MontyForm::zero(field),
+        }
+    }
+
+    fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        self.x.conditional_assign(&other.x, choice);
+        self.y.conditional_assign(&other.y, choice);
+        self.z.conditional_assign(&other.z, choice);
+    }
+}
+
+/// Returns whether `x` is zero.
+fn is_zero<const LIMBS: usize>(x: &MontyForm<LIMBS>) -> bool {
+    x.as_montgomery().ct_eq(&Uint::ZERO).into()
+}
+
+/// Variable-time square root using the Tonelli-Shanks algorithm.
+///
+/// Only used for decompressing public points.
+fn sqrt_vartime<const LIMBS: usize>(x: &MontyForm<LIMBS>) -> Option<MontyForm<LIMBS>> {
+    let params = *x.params();
+    let one = MontyForm::one(params);
+    let p_minus_1 = params.modulus().wrapping_sub(&Uint::ONE);
+    let s = p_minus_1.trailing_zeros_vartime();
+    let q = p_minus_1.wrapping_shr_vartime(s);
+
+    if is_zero(x) {
+        return Some(*x);
+    }
+
+    // Find a quadratic non-residue z
+    let legendre_exp = p_minus_1.wrapping_shr_vartime(1);
+    let mut z = one + one;
+    while z.pow(&legendre_exp) == one {
+        z += one;
+    }
+
+    let mut m = s;
+    let mut c = z.pow(&q);
+    let mut t = x.pow(&q);
+    let mut r = x.pow(&q.wrapping_add(&Uint::ONE).wrapping_shr_vartime(1));
+
+    while t != one {
+        let mut i = 0;
+        let mut t2i = t;
+
+        while t2i != one {
+            t2i = t2i.square();
+            i += 1;
+
+            if i == m {
+                return None;
+            }
+        }
+
+        let mut b = c;
+        for _ in 0..(m - i - 1) {
+            b = b.square();
+        }
+
+        m = i;
+        c = b.square();
+        t *= c;
+        r *= b;
+    }
+
+    Some(r)
+}
+
+/// Decode a field element from a big endian byte slice, checking that it is
+/// fully reduced.
+fn field_element_from_be_slice<const LIMBS: usize>(
+    bytes: &[u8],
+    field: MontyParams<LIMBS>,
+) -> Result<MontyForm<LIMBS>> {
+    let x = uint_from_be_slice::<LIMBS>(bytes)?;
+
+    if &x < field.modulus().as_ref() {
+        Ok(MontyForm::new(&x, field))
+    } else {
+        Err(Error)
+    }
+}
+
+/// Decode an integer from a big endian byte slice of any length, ignoring
+/// leading zeros.
+fn uint_from_be_slice<const LIMBS: usize>(bytes: &[u8]) -> Result<Uint<LIMBS>> {
+    let leading_zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+    let bytes = &bytes[leading_zeros..];
+
+    if bytes.len() > Uint::<LIMBS>::BYTES {
+        return Err(Error);
+    }
+
+    let mut words = [Word::default(); LIMBS];
+    for (i, byte) in bytes.iter().rev().enumerate() {
+        words[i / Limb::BYTES] |= Word::from(*byte) << (8 * (i % Limb::BYTES));
+    }
+
+    Ok(Uint::from_words(words))
+}
+
+/// Encode the `out.len()` least significant bytes of an integer as big endian.
+fn uint_to_be_slice<const LIMBS: usize>(x: &Uint<LIMBS>, out: &mut [u8]) {
+    let words = x.as_words();
+
+    for (i, byte) in out.iter_mut().rev().enumerate() {
+        *byte = words
+            .get(i / Limb::BYTES)
+            .map_or(0, |word| (word >> (8 * (i % Limb::BYTES))) as u8);
+    }
+}
@@ -0,0 +1,401 @@
+//! Elliptic Curve Diffie-Hellman (ECDH).
+//!
+//! [`diffie_hellman`] and [`EphemeralSecret`] are equivalent to their
+//! counterparts in [`elliptic_curve::ecdh`], but return an error rather than a
+//! shared secret of all zeroes if the result of the scalar multiplication is
+//! the identity. For a nonzero scalar and a valid public key this only
+//! happens if the multiplication failed the consistency check of the
+//! `fault-countermeasures` feature, which replaces a corrupted point with the
+//! identity.
+//!
+//! [`diffie_hellman_x`] uses an x-coordinate-only Montgomery ladder instead.
+//! The shared secret of an ECDH key exchange is the x-coordinate of `[k] P`,
+//! which can be computed from the x-coordinate of `P` alone, without ever
+//! recovering or computing a y-coordinate.
+//!
+//! The ladder uses the formulas from [Brier-Joye 2002] for projective
+//! `(X : Z)` coordinates, which work for any short Weierstrass curve.
+//!
+//! The curve crates in this repository which are built on `primeorder`
+//! re-export these from their `ecdh` modules, with two
+//! exceptions: `p192` deliberately provides no ECDH support, since it is only
+//! intended for verifying legacy signatures, and the Brainpool crates don't
+//! provide ECDH while their arithmetic is still experimental.
+//!
+//! [Brier-Joye 2002]: https://link.springer.com/chapter/10.1007/3-540-45664-3_24
+
+use crate::{mul::window, PrimeCurveParams, PrimeField};
+use core::borrow::Borrow;
+use elliptic_curve::{
+    array::typenum::Unsigned,
+    bigint::{Bounded, Limb},
+    ecdh::SharedSecret,
+    ff::Field,
+    group::{Curve, Group},
+    point::AffineCoordinates,
+    rand_core::CryptoRngCore,
+    sec1::Tag,
+    subtle::{Choice, ConditionallySelectable},
+    zeroize::{Zeroize, ZeroizeOnDrop},
+    CurveArithmetic, Error, FieldBytes, FieldBytesSize, NonZeroScalar, PublicKey, Result,
+};
+
+#[cfg(feature = "side-channel-hardening")]
+use {
+    crate::{mul, AffinePoint, ProjectivePoint},
+    elliptic_curve::bigint::BitOps,
+};
+
+/// Compute a Diffie-Hellman shared secret from a secret scalar and the peer's
+/// public key.
+///
+/// This is equivalent to [`elliptic_curve::ecdh::diffie_hellman`], but returns
+/// an error if the result of the scalar multiplication is the identity, e.g.
+/// because it was corrupted by an injected fault, rather than a shared secret
+/// of all zeroes.
+pub fn diffie_hellman<C>(
+    secret_key: impl Borrow<NonZeroScalar<C>>,
+    public_key: impl Borrow<C::AffinePoint>,
+) -> Result<SharedSecret<C>>
+where
+    C: CurveArithmetic,
+{
+    let public_point = C::ProjectivePoint::from(*public_key.borrow());
+    checked_shared_secret::<C>(public_point * secret_key.borrow().as_ref())
+}
+
+/// Ephemeral Diffie-Hellman secret.
+///
+/// This is equivalent to [`elliptic_curve::ecdh::EphemeralSecret`], but
+/// [`EphemeralSecret::diffie_hellman`] returns an error if the result of the
+/// scalar multiplication is the identity, see [`diffie_hellman`].
+///
+/// # ⚠️ SECURITY WARNING ⚠️
+///
+/// Ephemeral Diffie-Hellman exchanges are unauthenticated and without a
+/// further authentication step are trivially vulnerable to man-in-the-middle
+/// attacks!
+pub struct EphemeralSecret<C: CurveArithmetic> {
+    scalar: NonZeroScalar<C>,
+}
+
+impl<C> EphemeralSecret<C>
+where
+    C: CurveArithmetic,
+{
+    /// Generate a cryptographically random [`EphemeralSecret`].
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        Self {
+            scalar: NonZeroScalar::random(rng),
+        }
+    }
+
+    /// Get the public key associated with this ephemeral secret.
+    pub fn public_key(&self) -> PublicKey<C> {
+        PublicKey::from_secret_scalar(&self.scalar)
+    }
+
+    /// Compute a Diffie-Hellman shared secret from this ephemeral secret and
+    /// the public key of the other participant in the exchange.
+    pub fn diffie_hellman(&self, public_key: &PublicKey<C>) -> Result<SharedSecret<C>> {
+        diffie_hellman(self.scalar, public_key.as_affine())
+    }
+}
+
+impl<C> From<&EphemeralSecret<C>> for PublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(ephemeral_secret: &EphemeralSecret<C>) -> Self {
+        ephemeral_secret.public_key()
+    }
+}
+
+impl<C> Zeroize for EphemeralSecret<C>
+where
+    C: CurveArithmetic,
+{
+    fn zeroize(&mut self) {
+        self.scalar.zeroize()
+    }
+}
+
+impl<C> ZeroizeOnDrop for EphemeralSecret<C> where C: CurveArithmetic {}
+
+impl<C> Drop for EphemeralSecret<C>
+where
+    C: CurveArithmetic,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// Compute a Diffie-Hellman shared secret from a secret scalar and the
+/// x-coordinate of the peer's public key, using an x-coordinate-only
+/// Montgomery ladder.
+///
+/// `public_key` is either a SEC1 compressed point, or a bare big-endian
+/// x-coordinate. The y-coordinate (and thus the SEC1 tag) is irrelevant, since
+/// `P` and `-P` result in the same shared secret.
+///
+/// The scalar multiplication is constant time. The public key is checked to
+/// be the x-coordinate of a point on the curve, i.e. not a point on its
+/// quadratic twist; an error is returned otherwise.
+///
+/// With the `fault-countermeasures` feature, an error is also returned if the
+/// result of the ladder fails its consistency check, e.g. due to an injected
+/// fault.
+pub fn diffie_hellman_x<C>(
+    secret_key: impl Borrow<NonZeroScalar<C>>,
+    public_key: &[u8],
+) -> Result<SharedSecret<C>>
+where
+    C: PrimeCurveParams,
+{
+    let x = decode_x::<C>(public_key)?;
+    let k = Into::<C::Uint>::into(*secret_key.borrow().as_ref());
+
+    shared_secret::<C>(ladder::<C>(
+        &x,
+        C::FieldElement::ONE,
+        k.as_ref(),
+        C::Uint::BITS as usize,
+    ))
+}
+
+/// Compute a Diffie-Hellman shared secret like [`diffie_hellman_x`], but
+/// randomising the computation on every call using `rng` to protect the secret
+/// scalar against differential power analysis and template attacks.
+///
+/// The scalar is replaced by `k + r * n`, where `n` is the group order and `r`
+/// is a random 64-bit integer, and the projective coordinates of the peer's
+/// public key are multiplied by a random nonzero field element.
+#[cfg(feature = "side-channel-hardening")]
+pub fn diffie_hellman_x_blinded<C>(
+    secret_key: impl Borrow<NonZeroScalar<C>>,
+    public_key: &[u8],
+    rng: &mut impl CryptoRngCore,
+) -> Result<SharedSecret<C>>
+where
+    C: PrimeCurveParams,
+{
+    let x = decode_x::<C>(public_key)?;
+    let (k, len) = mul::blind_scalar::<C>(secret_key.borrow().as_ref(), rng);
+    let bits = C::ORDER.bits() as usize + mul::BLINDING_BITS;
+
+    shared_secret::<C>(ladder::<C>(
+        &x,
+        mul::random_nonzero::<C>(rng),
+        &k[..len],
+        bits,
+    ))
+}
+
+/// Compute a Diffie-Hellman shared secret from a secret scalar and the peer's
+/// public key, randomising the computation on every call using `rng`.
+///
+/// This is equivalent to [`diffie_hellman`], using
+/// [`ProjectivePoint::mul_blinded`] for the scalar multiplication.
+#[cfg(feature = "side-channel-hardening")]
+pub fn diffie_hellman_blinded<C>(
+    secret_key: impl Borrow<NonZeroScalar<C>>,
+    public_key: impl Borrow<AffinePoint<C>>,
+    rng: &mut impl CryptoRngCore,
+) -> Result<SharedSecret<C>>
+where
+    C: PrimeCurveParams,
+{
+    let public_key = ProjectivePoint::from(*public_key.borrow());
+    checked_shared_secret::<C>(public_key.mul_blinded(secret_key.borrow().as_ref(), rng))
+}
+
+/// Convert the result of a scalar multiplication into a shared secret,
+/// rejecting the identity.
+fn checked_shared_secret<C>(point: C::ProjectivePoint) -> Result<SharedSecret<C>>
+where
+    C: CurveArithmetic,
+{
+    if point.is_identity().into() {
+        return Err(Error);
+    }
+
+    Ok(SharedSecret::from(point.to_affine().x()))
+}
+
+/// Convert the `(X : Z)` result of the ladder into a shared secret.
+fn shared_secret<C>((x, z): (C::FieldElement, C::FieldElement)) -> Result<SharedSecret<C>>
+where
+    C: PrimeCurveParams,
+{
+    let z_inv = Option::<C::FieldElement>::from(z.invert()).ok_or(Error)?;
+    Ok(SharedSecret::from((x * z_inv).to_repr()))
+}
+
+/// Decode the x-coordinate of a public key, checking that it is on the curve.
+fn decode_x<C>(bytes: &[u8]) -> Result<C::FieldElement>
+where
+    C: PrimeCurveParams,
+{
+    let len = FieldBytesSize::<C>::USIZE;
+
+    let x = match bytes.split_first() {
+        _ if bytes.len() == len => bytes,
+        Some((&tag, x)) if x.len() == len && Tag::from_u8(tag)?.is_compressed() => x,
+        _ => return Err(Error),
+    };
+
+    let x = Option::<C::FieldElement>::from(C::FieldElement::from_repr(
+        FieldBytes::<C>::clone_from_slice(x),
+    ))
+    .ok_or(Error)?;
+
+    // y² = x³ + ax + b must have a solution
+    let alpha = (x.square() + C::EQUATION_A) * x + C::EQUATION_B;
+
+    if alpha.sqrt().is_some().into() {
+        Ok(x)
+    } else {
+        Err(Error)
+    }
+}
+
+/// Calculates the x-coordinate of `[k] P` as `(X : Z)`, given the affine
+/// x-coordinate of `P`, the `Z` coordinate to represent `P` with, and the
+/// `bits` least significant bits of `k` as a little-endian integer. This
+/// operation is constant time.
+///
+/// With the `fault-countermeasures` feature, `Z` is zero if the invariant of
+/// the ladder doesn't hold for the result, see [`is_ladder_step`].
+fn ladder<C>(
+    x: &C::FieldElement,
+    z: C::FieldElement,
+    k: &[Limb],
+    bits: usize,
+) -> (C::FieldElement, C::FieldElement)
+where
+    C: PrimeCurveParams,
+{
+    // Invariant: r1 - r0 = ±P
+    let mut r0 = (C::FieldElement::ONE, C::FieldElement::ZERO);
+    let mut r1 = (*x * z, z);
+    let mut swap = Choice::from(0);
+
+    for i in (0..bits).rev() {
+        let bit = Choice::from(window(k, i, 1) as u8);
+        conditional_swap::<C>(&mut r0, &mut r1, swap ^ bit);
+        swap = bit;
+
+        r1 = add::<C>(&r0, &r1, x);
+        r0 = double::<C>(&r0);
+    }
+
+    conditional_swap::<C>(&mut r0, &mut r1, swap);
+
+    #[cfg(feature = "fault-countermeasures")]
+    {
+        let faulty = !is_ladder_step::<C>(&r0, &r1, x);
+        r0.1.conditional_assign(&C::FieldElement::ZERO, faulty);
+    }
+
+    r0
+}
+
+/// Returns whether the affine x-coordinate `xd` of `P` is the x-coordinate of
+/// `P1 - P2` or `P1 + P2`, i.e. whether the invariant of the ladder holds.
+///
+/// Given `(X1 : Z1)` and `(X2 : Z2)`, those are the roots of:
+///
+/// ```text
+/// (X1Z2 - X2Z1)²xd² - 2((X1Z2 + X2Z1)(X1X2 + aZ1Z2) + 2b(Z1Z2)²)xd
+///     + (X1X2 - aZ1Z2)² - 4bZ1Z2(X1Z2 + X2Z1) = 0
+/// ```
+///
+/// With the `fault-countermeasures` feature, the ladder checks this for its
+/// result, so that a point corrupted by an injected fault is never released.
+#[cfg(feature = "fault-countermeasures")]
+fn is_ladder_step<C>(
+    p1: &(C::FieldElement, C::FieldElement),
+    p2: &(C::FieldElement, C::FieldElement),
+    xd: &C::FieldElement,
+) -> Choice
+where
+    C: PrimeCurveParams,
+{
+    let (x1, z1) = p1;
+    let (x2, z2) = p2;
+
+    let x1z2 = *x1 * z2;
+    let x2z1 = *x2 * z1;
+    let z1z2 = *z1 * z2;
+    let azz = C::EQUATION_A * z1z2;
+
+    let u = (x1z2 - x2z1).square();
+    let v = (x1z2 + x2z1) * (*x1 * x2 + azz) + (C::EQUATION_B * z1z2.square()).double();
+    let w = (*x1 * x2 - azz).square() - (C::EQUATION_B * z1z2 * (x1z2 + x2z1)).double().double();
+
+    (u * xd.square() - v.double() * xd + w).is_zero()
+}
+
+fn conditional_swap<C>(
+    a: &mut (C::FieldElement, C::FieldElement),
+    b: &mut (C::FieldElement, C::FieldElement),
+    choice: Choice,
+) where
+    C: PrimeCurveParams,
+{
+    C::FieldElement::conditional_swap(&mut a.0, &mut b.0, choice);
+    C::FieldElement::conditional_swap(&mut a.1, &mut b.1, choice);
+}
+
+/// Differential addition: calculates `(X : Z)` of `P1 + P2`, given the affine
+/// x-coordinate `xd` of `P2 - P1`.
+///
+/// ```text
+/// X3 = 2(X1Z2 + X2Z1)(X1X2 + aZ1Z2) + 4b(Z1Z2)² - xd(X1Z2 - X2Z1)²
+/// Z3 = (X1Z2 - X2Z1)²
+/// ```
+fn add<C>(
+    p1: &(C::FieldElement, C::FieldElement),
+    p2: &(C::FieldElement, C::FieldElement),
+    xd: &C::FieldElement,
+) -> (C::FieldElement, C::FieldElement)
+where
+    C: PrimeCurveParams,
+{
+    let (x1, z1) = p1;
+    let (x2, z2) = p2;
+
+    let x1z2 = *x1 * z2;
+    let x2z1 = *x2 * z1;
+    let z1z2 = *z1 * z2;
+
+    let s = (x1z2 + x2z1) * (*x1 * x2 + C::EQUATION_A * z1z2);
+    let t = (x1z2 - x2z1).square();
+    let bzz = C::EQUATION_B * z1z2.square();
+
+    (s.double() + bzz.double().double() - *xd * t, t)
+}
+
+/// Doubling: calculates `(X : Z)` of `2P`.
+///
+/// ```text
+/// X3 = (X² - aZ²)² - 8bXZ³
+/// Z3 = 4Z(X³ + aXZ² + bZ³)
+/// ```
+fn double<C>(p: &(C::FieldElement, C::FieldElement)) -> (C::FieldElement, C::FieldElement)
+where
+    C: PrimeCurveParams,
+{
+    let (x, z) = p;
+
+    let xx = x.square();
+    let zz = z.square();
+    let azz = C::EQUATION_A * zz;
+    let bzzz = C::EQUATION_B * zz * z;
+
+    let x3 = (xx - azz).square() - (*x * bzzz).double().double().double();
+    let z3 = (*z * ((xx + azz) * x + bzzz)).double().double();
+
+    (x3, z3)
+}
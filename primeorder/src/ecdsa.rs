@@ -0,0 +1,299 @@
+//! Hardened Elliptic Curve Digital Signature Algorithm (ECDSA) signing and
+//! variable-time verification.
+//!
+//! [`HardenedSigningKey`] wraps an [`ecdsa_core::SigningKey`] and computes the
+//! same signatures, with the following countermeasures:
+//!
+//! - With the `side-channel-hardening` feature, the randomized signing traits
+//!   ([`RandomizedSigner`] and [`RandomizedPrehashSigner`]) additionally use
+//!   the provided random number generator to randomise the computation of
+//!   `R = [k] G`, see [`ProjectivePoint::mul_blinded`]. The deterministic
+//!   signing traits ([`Signer`] and [`PrehashSigner`]) have no source of
+//!   randomness and compute `R` like [`ecdsa_core::SigningKey`] does.
+//! - With the `fault-countermeasures` feature, every signature is verified
+//!   before it is returned, so that a signature corrupted by an injected
+//!   fault, which could leak the secret key, is never released.
+//!
+//! [`VartimeVerifyingKey`] wraps an [`ecdsa_core::VerifyingKey`] and verifies
+//! signatures with [`ProjectivePoint::lincomb_vartime`]. Verification only
+//! involves public values, so it doesn't need to run in constant time.
+
+use crate::{PrimeCurveParams, ProjectivePoint};
+use ecdsa_core::{
+    hazmat::{bits2field, DigestPrimitive},
+    signature::{
+        digest::Digest,
+        hazmat::{PrehashSigner, PrehashVerifier, RandomizedPrehashSigner},
+        rand_core::CryptoRngCore,
+        Error, Keypair, RandomizedSigner, Signer, Verifier,
+    },
+    EcdsaCurve, Result, Signature, SignatureSize, SigningKey, VerifyingKey,
+};
+use elliptic_curve::{
+    array::ArraySize,
+    ops::{Invert, MulByGenerator, Reduce},
+    point::AffineCoordinates,
+    scalar::IsHigh,
+    subtle::CtOption,
+    CurveArithmetic, FieldBytes, FieldBytesEncoding, NonZeroScalar, PrimeField, PublicKey, Scalar,
+};
+
+/// Curves which signatures can be computed for with [`HardenedSigningKey`].
+///
+/// This is implemented for all curves implementing [`PrimeCurveParams`], and
+/// can be implemented by curves which provide their own arithmetic.
+pub trait HardenedCurve: EcdsaCurve + CurveArithmetic + DigestPrimitive {
+    /// Calculates `[k] G`, where `G` is the generator.
+    ///
+    /// With the `side-channel-hardening` feature, the computation must be
+    /// randomised using `rng`.
+    fn mul_by_generator_with_rng(
+        k: &Scalar<Self>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Self::ProjectivePoint;
+}
+
+impl<C> HardenedCurve for C
+where
+    C: PrimeCurveParams + EcdsaCurve + DigestPrimitive,
+{
+    fn mul_by_generator_with_rng(
+        k: &Scalar<Self>,
+        rng: &mut impl CryptoRngCore,
+    ) -> ProjectivePoint<C> {
+        #[cfg(feature = "side-channel-hardening")]
+        return ProjectivePoint::GENERATOR.mul_blinded(k, rng);
+
+        #[cfg(not(feature = "side-channel-hardening"))]
+        {
+            let _ = rng;
+            ProjectivePoint::mul_by_generator(k)
+        }
+    }
+}
+
+/// ECDSA signing key which hardens the computation of signatures, see the
+/// [module documentation](self).
+#[derive(Clone, Debug)]
+pub struct HardenedSigningKey<C>
+where
+    C: HardenedCurve,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>>,
+    SignatureSize<C>: ArraySize,
+{
+    signing_key: SigningKey<C>,
+}
+
+impl<C> HardenedSigningKey<C>
+where
+    C: HardenedCurve,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>>,
+    SignatureSize<C>: ArraySize,
+{
+    /// Generate a cryptographically random [`HardenedSigningKey`].
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        SigningKey::random(rng).into()
+    }
+
+    /// Borrow the wrapped [`SigningKey`].
+    pub fn signing_key(&self) -> &SigningKey<C> {
+        &self.signing_key
+    }
+
+    /// Get the [`VerifyingKey`] which corresponds to this signing key.
+    pub fn verifying_key(&self) -> &VerifyingKey<C> {
+        self.signing_key.verifying_key()
+    }
+
+    /// Sign the prehashed message `z` with the ephemeral scalar computed as
+    /// described in [RFC6979] with the additional data `ad`, computing
+    /// `R = [k] G` with `mul_by_generator`.
+    ///
+    /// With the `fault-countermeasures` feature, the signature is verified
+    /// before it is returned.
+    ///
+    /// This mirrors [`ecdsa_core::hazmat::sign_prehashed_rfc6979`].
+    ///
+    /// [RFC6979]: https://datatracker.ietf.org/doc/html/rfc6979
+    fn sign_prehashed(
+        &self,
+        z_bytes: &FieldBytes<C>,
+        ad: &[u8],
+        mul_by_generator: impl FnOnce(&Scalar<C>) -> C::ProjectivePoint,
+    ) -> Result<Signature<C>> {
+        let d = self.signing_key.as_nonzero_scalar();
+        let z = <Scalar<C> as Reduce<C::Uint>>::reduce_bytes(z_bytes);
+
+        let k = NonZeroScalar::<C>::from_repr(rfc6979::generate_k::<C::Digest, _>(
+            &d.to_repr(),
+            &C::ORDER.encode_field_bytes(),
+            &z.to_repr(),
+            ad,
+        ))
+        .unwrap();
+
+        let k_inv = k.invert();
+        let big_r = mul_by_generator(k.as_ref()).into();
+        let r = <Scalar<C> as Reduce<C::Uint>>::reduce_bytes(&big_r.x());
+        let s = *k_inv * (z + r * d.as_ref());
+
+        // NOTE: `Signature::from_scalars` checks that both `r` and `s` are non-zero.
+        let mut signature = Signature::from_scalars(r, s)?;
+
+        if C::NORMALIZE_S {
+            signature = signature.normalize_s();
+        }
+
+        #[cfg(feature = "fault-countermeasures")]
+        self.verifying_key().verify_prehash(z_bytes, &signature)?;
+
+        Ok(signature)
+    }
+}
+
+impl<C> From<SigningKey<C>> for HardenedSigningKey<C>
+where
+    C: HardenedCurve,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>>,
+    SignatureSize<C>: ArraySize,
+{
+    fn from(signing_key: SigningKey<C>) -> Self {
+        Self { signing_key }
+    }
+}
+
+impl<C> Keypair for HardenedSigningKey<C>
+where
+    C: HardenedCurve,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>>,
+    SignatureSize<C>: ArraySize,
+{
+    type VerifyingKey = VerifyingKey<C>;
+
+    fn verifying_key(&self) -> VerifyingKey<C> {
+        *self.signing_key.verifying_key()
+    }
+}
+
+impl<C> PrehashSigner<Signature<C>> for HardenedSigningKey<C>
+where
+    C: HardenedCurve,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>>,
+    SignatureSize<C>: ArraySize,
+{
+    fn sign_prehash(&self, prehash: &[u8]) -> Result<Signature<C>> {
+        let z = bits2field::<C>(prehash)?;
+        self.sign_prehashed(&z, &[], C::ProjectivePoint::mul_by_generator)
+    }
+}
+
+impl<C> Signer<Signature<C>> for HardenedSigningKey<C>
+where
+    C: HardenedCurve,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>>,
+    SignatureSize<C>: ArraySize,
+{
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature<C>> {
+        self.sign_prehash(&C::Digest::digest(msg))
+    }
+}
+
+impl<C> RandomizedPrehashSigner<Signature<C>> for HardenedSigningKey<C>
+where
+    C: HardenedCurve,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>>,
+    SignatureSize<C>: ArraySize,
+{
+    fn sign_prehash_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        prehash: &[u8],
+    ) -> Result<Signature<C>> {
+        let z = bits2field::<C>(prehash)?;
+        let mut ad = FieldBytes::<C>::default();
+        rng.fill_bytes(&mut ad);
+        self.sign_prehashed(&z, &ad, |k| C::mul_by_generator_with_rng(k, rng))
+    }
+}
+
+impl<C> RandomizedSigner<Signature<C>> for HardenedSigningKey<C>
+where
+    C: HardenedCurve,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>>,
+    SignatureSize<C>: ArraySize,
+{
+    fn try_sign_with_rng(&self, rng: &mut impl CryptoRngCore, msg: &[u8]) -> Result<Signature<C>> {
+        self.sign_prehash_with_rng(rng, &C::Digest::digest(msg))
+    }
+}
+
+/// ECDSA verifying key which verifies signatures in variable time, see the
+/// [module documentation](self).
+#[derive(Clone, Copy, Debug)]
+pub struct VartimeVerifyingKey<C>
+where
+    C: PrimeCurveParams + EcdsaCurve,
+{
+    verifying_key: VerifyingKey<C>,
+}
+
+impl<C> VartimeVerifyingKey<C>
+where
+    C: PrimeCurveParams + EcdsaCurve,
+{
+    /// Borrow the wrapped [`VerifyingKey`].
+    pub fn verifying_key(&self) -> &VerifyingKey<C> {
+        &self.verifying_key
+    }
+}
+
+impl<C> From<VerifyingKey<C>> for VartimeVerifyingKey<C>
+where
+    C: PrimeCurveParams + EcdsaCurve,
+{
+    fn from(verifying_key: VerifyingKey<C>) -> Self {
+        Self { verifying_key }
+    }
+}
+
+impl<C> PrehashVerifier<Signature<C>> for VartimeVerifyingKey<C>
+where
+    C: PrimeCurveParams + EcdsaCurve,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>>,
+    SignatureSize<C>: ArraySize,
+{
+    /// This mirrors [`ecdsa_core::hazmat::verify_prehashed`], computing
+    /// `[u1] G + [u2] Q` with [`ProjectivePoint::lincomb_vartime`].
+    fn verify_prehash(&self, prehash: &[u8], signature: &Signature<C>) -> Result<()> {
+        if C::NORMALIZE_S && signature.s().is_high().into() {
+            return Err(Error::new());
+        }
+
+        let z = <Scalar<C> as Reduce<C::Uint>>::reduce_bytes(&bits2field::<C>(prehash)?);
+        let (r, s) = signature.split_scalars();
+        let s_inv = *s.invert_vartime();
+        let u1 = z * s_inv;
+        let u2 = *r * s_inv;
+        let q = PublicKey::from(&self.verifying_key).to_projective();
+        let x = ProjectivePoint::lincomb_vartime(&[(ProjectivePoint::GENERATOR, u1), (q, u2)])
+            .to_affine()
+            .x();
+
+        if *r == <Scalar<C> as Reduce<C::Uint>>::reduce_bytes(&x) {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl<C> Verifier<Signature<C>> for VartimeVerifyingKey<C>
+where
+    C: PrimeCurveParams + EcdsaCurve + DigestPrimitive,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>>,
+    SignatureSize<C>: ArraySize,
+{
+    fn verify(&self, msg: &[u8], signature: &Signature<C>) -> Result<()> {
+        self.verify_prehash(&C::Digest::digest(msg), signature)
+    }
+}
@@ -16,6 +16,12 @@
 /// - `pub fn is_odd`
 /// - `pub fn is_zero`
 /// - `pub fn double`
+/// - `pub fn invert_vartime`
+/// - `pub fn legendre`
+/// - `pub fn is_square`
+/// - `pub fn pow_uint`
+/// - `pub fn batch_invert_array`
+/// - `pub fn batch_invert` (requires `alloc`)
 ///
 /// NOTE: field implementations must provide their own inherent impls of
 /// the following methods in order for the code generated by this macro to
@@ -259,11 +265,29 @@ macro_rules! impl_mont_field_element {
 
                 res
             }
+
+            /// Compute the multiplicative inverse `1 / self` in variable time.
+            ///
+            /// ⚠️ WARNING!
+            ///
+            /// This method should not be used with secret values, as its
+            /// variable-time operation can potentially leak secrets through
+            /// sidechannels.
+            pub fn invert_vartime(&self) -> $crate::elliptic_curve::subtle::CtOption<Self> {
+                let inverse = $crate::primefield::invert_vartime(&self.to_canonical(), &$modulus);
+
+                $crate::elliptic_curve::subtle::CtOption::new(
+                    Self::from_uint_unchecked(inverse.unwrap_or(<$uint>::ZERO)),
+                    $crate::elliptic_curve::subtle::Choice::from(u8::from(inverse.is_some())),
+                )
+            }
         }
 
         $crate::impl_mont_field_element_arithmetic!(
-            $fe, $bytes, $uint, $arr, $add, $sub, $mul, $neg
+            $fe, $bytes, $uint, $modulus, $arr, $add, $sub, $mul, $neg
         );
+
+        $crate::primefield::impl_batch_invert!($fe);
     };
 }
 
@@ -274,12 +298,40 @@ macro_rules! impl_mont_field_element_arithmetic {
         $fe:tt,
         $bytes:ty,
         $uint:ty,
+        $modulus:expr,
         $arr:ty,
         $add:ident,
         $sub:ident,
         $mul:ident,
         $neg:ident
     ) => {
+        impl $fe {
+            /// Compute the Legendre symbol of `self`.
+            ///
+            /// Returns `1` if `self` is a nonzero square, `-1` if it's a
+            /// non-square, and `0` if it's zero.
+            pub fn legendre(&self) -> i8 {
+                $crate::primefield::legendre(self, &$modulus)
+            }
+
+            /// Is `self` a square (including zero)?
+            pub fn is_square(&self) -> $crate::elliptic_curve::subtle::Choice {
+                $crate::primefield::is_square(self, &$modulus)
+            }
+
+            /// Raise `self` to the power `exp` in constant time, using a fixed
+            /// window.
+            ///
+            /// Unlike `pow_vartime`, this is constant-time with respect to the
+            /// exponent, so it can be used with secret exponents.
+            pub fn pow_uint<const LIMBS: usize>(
+                &self,
+                exp: &$crate::elliptic_curve::bigint::Uint<LIMBS>,
+            ) -> Self {
+                $crate::primefield::pow(self, exp)
+            }
+        }
+
         impl AsRef<$arr> for $fe {
             fn as_ref(&self) -> &$arr {
                 self.0.as_ref()
@@ -382,7 +434,7 @@ macro_rules! impl_mont_field_element_arithmetic {
             }
 
             fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
-                $crate::elliptic_curve::ff::helpers::sqrt_ratio_generic(num, div)
+                $crate::primefield::sqrt_ratio(num, div, &$modulus)
             }
         }
 
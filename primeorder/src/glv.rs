@@ -0,0 +1,168 @@
+//! Scalar multiplication accelerated by an efficiently computable
+//! endomorphism (Gallant-Lambert-Vanstone).
+//!
+//! Curves with `a = 0` over a prime field `p ≡ 1 (mod 3)` have an endomorphism
+//! `φ(x, y) = (βx, y)`, where `β` is a cube root of unity in the base field,
+//! which acts as multiplication by a cube root of unity `λ` in the scalar
+//! field. Splitting a scalar `k` into `k1 + k2 * λ` with `k1` and `k2` roughly
+//! half the size of `k` halves the length of the doubling chain.
+//!
+//! See "Guide to Elliptic Curve Cryptography" (Hankerson, Menezes, Vanstone),
+//! section 3.5.
+
+use crate::{
+    mul::{lincomb_tables, LookupTable, LINCOMB_CHUNK_SIZE},
+    PrimeCurveParams, ProjectivePoint,
+};
+use elliptic_curve::{
+    bigint::{Bounded, FixedInteger, Zero},
+    ops::Reduce,
+    scalar::IsHigh,
+    subtle::ConditionallySelectable,
+    Scalar,
+};
+
+/// Parameters of the GLV endomorphism of a curve.
+///
+/// The lattice basis `(a1, b1), (a2, b2)` of the decomposition is the one
+/// described in "Guide to Elliptic Curve Cryptography" (algorithm 3.74). `k1`
+/// is recovered as `k - k2 * λ`, so only `b1` and `b2` are needed.
+///
+/// Curves implementing this trait can override [`PrimeCurveParams::lincomb`]
+/// with [`GlvParams::lincomb_glv`] to use the endomorphism for scalar
+/// multiplication and linear combinations. [`define_curve!`] does both when
+/// given a `glv` block.
+///
+/// [`define_curve!`]: crate::define_curve
+pub trait GlvParams: PrimeCurveParams {
+    /// Cube root of unity `β` in the base field.
+    const BETA: Self::FieldElement;
+
+    /// Cube root of unity `λ` in the scalar field, such that
+    /// `[λ] (x, y) = (βx, y)`.
+    const LAMBDA: Self::Scalar;
+
+    /// `-b1` reduced modulo the curve order.
+    const MINUS_B1: Self::Scalar;
+
+    /// `-b2` reduced modulo the curve order.
+    const MINUS_B2: Self::Scalar;
+
+    /// `round(2^(3w/2) * b2 / n)`, where `w = Uint::BITS` and `n` is the curve
+    /// order.
+    const G1: Self::Uint;
+
+    /// `round(2^(3w/2) * (-b1) / n)`, where `w = Uint::BITS` and `n` is the
+    /// curve order.
+    const G2: Self::Uint;
+
+    /// Find `r1` and `r2` given `k`, such that `r1 + r2 * λ == k mod n`.
+    ///
+    /// `r1` and `r2` are less than `2^(w/2)` in absolute value, i.e. either they
+    /// or their negations fit into half of a `Uint`.
+    fn decompose_scalar(k: &Self::Scalar) -> (Self::Scalar, Self::Scalar) {
+        let k = Into::<Self::Uint>::into(*k);
+
+        // Here we compute `c1 = round(b2 * k / n)` and `c2 = round((-b1) * k / n)`
+        let c1 = Self::Scalar::reduce(mul_shift(&k, &Self::G1));
+        let c2 = Self::Scalar::reduce(mul_shift(&k, &Self::G2));
+
+        let r2 = c1 * Self::MINUS_B1 + c2 * Self::MINUS_B2;
+        let r1 = Self::Scalar::reduce(k) - r2 * Self::LAMBDA;
+
+        (r1, r2)
+    }
+
+    /// Calculates `x1 * k1 + ... + xn * kn` in constant time using the
+    /// endomorphism.
+    ///
+    /// # Panics
+    ///
+    /// If [`GlvParams::decompose_scalar`] returns a value which is not less
+    /// than `2^(w/2)` in absolute value, which means the parameters are wrong.
+    fn lincomb_glv(xks: &[(ProjectivePoint<Self>, Self::Scalar)]) -> ProjectivePoint<Self> {
+        let half = Self::Uint::BITS as usize / 2;
+
+        let mut tables = [LookupTable::default(); 2 * LINCOMB_CHUNK_SIZE];
+        let mut ks = [Self::Uint::default(); 2 * LINCOMB_CHUNK_SIZE];
+        let mut acc = ProjectivePoint::IDENTITY;
+
+        for chunk in xks.chunks(LINCOMB_CHUNK_SIZE) {
+            for (i, (x, k)) in chunk.iter().enumerate() {
+                let (r1, r2) = Self::decompose_scalar(k);
+
+                for (j, (x, r)) in [(*x, r1), (x.endomorphism(), r2)].iter().enumerate() {
+                    let neg = r.is_high();
+                    let x = ProjectivePoint::conditional_select(x, &-*x, neg);
+                    let r = Scalar::<Self>::conditional_select(r, &-*r, neg);
+
+                    tables[2 * i + j] = LookupTable::from(&x);
+                    ks[2 * i + j] = r.into();
+
+                    // Only fails if the lattice basis is not reduced, so this
+                    // doesn't depend on `k` for valid parameters.
+                    assert!(
+                        bool::from((ks[2 * i + j] >> half as u32).is_zero()),
+                        "GLV decomposition out of range"
+                    );
+                }
+            }
+
+            let n = 2 * chunk.len();
+            acc = acc.add(&lincomb_tables(&tables[..n], &ks[..n], half));
+        }
+
+        acc
+    }
+}
+
+impl<C> ProjectivePoint<C>
+where
+    C: GlvParams,
+{
+    /// Calculates the curve endomorphism: `self * λ`.
+    pub fn endomorphism(&self) -> Self {
+        Self {
+            x: self.x * C::BETA,
+            y: self.y,
+            z: self.z,
+        }
+    }
+}
+
+/// Calculates `round(a * b / 2^(3w/2))`, where `w` is the width of `U`.
+///
+/// The result must be less than `2^w`. This operation is constant time.
+fn mul_shift<U: FixedInteger>(a: &U, b: &U) -> U {
+    // Split both operands into halves of `h` bits, so each partial product
+    // fits into `U`: `a * b = p3 * 2^(2h) + (p1 + p2) * 2^h + p0`.
+    let h = U::BITS / 2;
+    let mask = U::MAX >> h;
+
+    let (al, ah) = (*a & mask, *a >> h);
+    let (bl, bh) = (*b & mask, *b >> h);
+
+    let p0 = al.wrapping_mul(&bl);
+    let p1 = ah.wrapping_mul(&bl);
+    let p2 = al.wrapping_mul(&bh);
+    let p3 = ah.wrapping_mul(&bh);
+
+    // The result is `(p3 * 2^h + p1 + p2 + (p0 >> h) + 2^(2h - 1)) >> 2h`,
+    // where the last term rounds to the nearest integer. Carries out of `U`
+    // are counted separately.
+    let mut carry = 0u8;
+
+    let mid = p1.wrapping_add(&p2);
+    carry += mid.ct_lt(&p1).unwrap_u8();
+
+    let t = mid.wrapping_add(&(p0 >> h));
+    carry += t.ct_lt(&mid).unwrap_u8();
+
+    let u = t.wrapping_add(&(U::ONE << (U::BITS - 1)));
+    carry += u.ct_lt(&t).unwrap_u8();
+
+    let v = u.wrapping_add(&(p3 << h));
+    carry += v.ct_lt(&u).unwrap_u8();
+
+    (p3 >> h).wrapping_add(&U::from(carry))
+}
@@ -0,0 +1,219 @@
+//! Hashing to curves using the simplified Shallue-van de Woestijne-Ulas
+//! (SSWU) method described in [RFC 9380].
+//!
+//! Curves opt in by implementing [`SswuParams`], and using
+//! [`impl_hash2curve!`](crate::impl_hash2curve) to implement the
+//! [`GroupDigest`] traits for their field elements and scalars.
+//!
+//! The mapping uses the straight-line procedure of [RFC 9380 § 6.6.2], which
+//! works for any prime field, at the cost of computing two square roots.
+//! Curves for which `a * b = 0` are supported by mapping to an isogenous curve
+//! first, as described in [RFC 9380 § 6.6.3].
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html
+//! [RFC 9380 § 6.6.2]: https://www.rfc-editor.org/rfc/rfc9380.html#section-6.6.2
+//! [RFC 9380 § 6.6.3]: https://www.rfc-editor.org/rfc/rfc9380.html#section-6.6.3
+//! [`GroupDigest`]: elliptic_curve::hash2curve::GroupDigest
+
+use crate::{AffinePoint, PrimeCurveParams, ProjectivePoint};
+use elliptic_curve::{
+    ff::{Field, PrimeField},
+    subtle::{ConditionallySelectable, ConstantTimeEq},
+};
+
+/// Parameters for hashing to a curve with the simplified SWU method.
+pub trait SswuParams: PrimeCurveParams {
+    /// The `Z` constant of the mapping, chosen as described in
+    /// [RFC 9380 § H.2](https://www.rfc-editor.org/rfc/rfc9380.html#appendix-H.2).
+    ///
+    /// When [`SswuParams::ISOGENY`] is set, this is the constant for the
+    /// isogenous curve.
+    const Z: Self::FieldElement;
+
+    /// Isogeny to the curve, for curves where `a * b = 0`.
+    const ISOGENY: Option<SswuIsogeny<Self::FieldElement>> = None;
+
+    /// Suite identifier for the random oracle encoding (`hash_to_curve`),
+    /// following the naming conventions of
+    /// [RFC 9380 § 8.10](https://www.rfc-editor.org/rfc/rfc9380.html#section-8.10),
+    /// e.g. `P256_XMD:SHA-256_SSWU_RO_`.
+    const SUITE_ID_RO: &'static str;
+
+    /// Suite identifier for the nonuniform encoding (`encode_to_curve`),
+    /// e.g. `P256_XMD:SHA-256_SSWU_NU_`.
+    const SUITE_ID_NU: &'static str;
+}
+
+/// Isogenous curve `y² = x³ + a'x + b'` with `a' * b' != 0`, and the rational
+/// map from it to the curve being hashed to.
+///
+/// The map is `(x, y) -> (x_num(x) / x_den(x), y * y_num(x) / y_den(x))`, where
+/// the polynomials are given by their coefficients in ascending order of
+/// degree.
+#[derive(Clone, Copy, Debug)]
+pub struct SswuIsogeny<F: 'static> {
+    /// Coefficient `a'` of the isogenous curve.
+    pub a: F,
+
+    /// Coefficient `b'` of the isogenous curve.
+    pub b: F,
+
+    /// Coefficients of `x_num`.
+    pub x_num: &'static [F],
+
+    /// Coefficients of `x_den`.
+    pub x_den: &'static [F],
+
+    /// Coefficients of `y_num`.
+    pub y_num: &'static [F],
+
+    /// Coefficients of `y_den`.
+    pub y_den: &'static [F],
+}
+
+impl<F> SswuIsogeny<F>
+where
+    F: Field,
+{
+    /// Evaluate the map at `(x, y)`, returning `(X, Y, Z)` such that the image
+    /// is `(X / Z, Y / Z)`. `Z` is zero for the exceptional points which map to
+    /// the identity.
+    fn map(&self, x: F, y: F) -> (F, F, F) {
+        let x_num = eval(self.x_num, x);
+        let x_den = eval(self.x_den, x);
+        let y_num = eval(self.y_num, x);
+        let y_den = eval(self.y_den, x);
+
+        (x_num * y_den, y * y_num * x_den, x_den * y_den)
+    }
+}
+
+/// Evaluate the polynomial with the given coefficients at `x`.
+fn eval<F: Field>(coefficients: &[F], x: F) -> F {
+    coefficients
+        .iter()
+        .rev()
+        .fold(F::ZERO, |acc, k| acc * x + k)
+}
+
+/// Map a field element to a point on the curve. This operation is constant
+/// time.
+///
+/// This is the `map_to_curve` function of [RFC 9380 § 6.6.2], followed by the
+/// isogeny map for curves where `a * b = 0`.
+///
+/// [RFC 9380 § 6.6.2]: https://www.rfc-editor.org/rfc/rfc9380.html#section-6.6.2
+pub fn map_to_curve<C>(u: &C::FieldElement) -> ProjectivePoint<C>
+where
+    C: SswuParams,
+{
+    let (a, b) = match C::ISOGENY {
+        Some(isogeny) => (isogeny.a, isogeny.b),
+        None => (C::EQUATION_A, C::EQUATION_B),
+    };
+
+    debug_assert!(!bool::from(a.is_zero() | b.is_zero()));
+
+    // x1 = -b / a * (1 + 1 / (Z²u⁴ + Zu²)), or b / (Za) if the denominator is
+    // zero. Written as a single fraction so only one inversion is needed.
+    let zu2 = C::Z * u.square();
+    let tv = zu2.square() + zu2;
+    let exceptional = tv.is_zero();
+
+    let num =
+        C::FieldElement::conditional_select(&-(b * (tv + C::FieldElement::ONE)), &b, exceptional);
+    let den = C::FieldElement::conditional_select(&(a * tv), &(C::Z * a), exceptional);
+
+    // `den` is never zero, since neither `a` nor `Z` is
+    let x1 = num * den.invert().unwrap();
+    let x2 = zu2 * x1;
+
+    // Exactly one of `g(x1)` and `g(x2) = Z³u⁶ g(x1)` is square
+    let gx1 = (x1.square() + a) * x1 + b;
+    let gx2 = (x2.square() + a) * x2 + b;
+    let y1 = gx1.sqrt();
+    let y2 = gx2.sqrt().unwrap_or(C::FieldElement::ZERO);
+
+    let is_square = y1.is_some();
+    let x = C::FieldElement::conditional_select(&x2, &x1, is_square);
+    let y =
+        C::FieldElement::conditional_select(&y2, &y1.unwrap_or(C::FieldElement::ZERO), is_square);
+
+    // Choose the sign of `y` to match `u`
+    let y = C::FieldElement::conditional_select(&-y, &y, u.is_odd().ct_eq(&y.is_odd()));
+
+    match C::ISOGENY {
+        Some(isogeny) => {
+            let (x, y, z) = isogeny.map(x, y);
+            z.invert()
+                .map(|zinv| AffinePoint::from_affine_coordinates(x * zinv, y * zinv))
+                .unwrap_or(AffinePoint::IDENTITY)
+                .into()
+        }
+        None => AffinePoint::from_affine_coordinates(x, y).into(),
+    }
+}
+
+/// Reduce the output of `expand_message` into a field element, interpreting it
+/// as a big-endian integer.
+///
+/// This is the final step of `hash_to_field` as described in
+/// [RFC 9380 § 5.2](https://www.rfc-editor.org/rfc/rfc9380.html#section-5.2),
+/// and works for both base field elements and scalars. This operation is
+/// constant time.
+pub fn from_okm<F>(data: &[u8]) -> F
+where
+    F: PrimeField,
+{
+    // Horner's method in radix 2^56, so every chunk fits into a `u64`
+    const CHUNK_SIZE: usize = 7;
+    let shift = F::from(1 << (8 * CHUNK_SIZE));
+
+    data.rchunks(CHUNK_SIZE).rev().fold(F::ZERO, |acc, chunk| {
+        let mut bytes = [0u8; 8];
+        bytes[8 - chunk.len()..].copy_from_slice(chunk);
+        acc * shift + F::from(u64::from_be_bytes(bytes))
+    })
+}
+
+/// Implement the [`GroupDigest`] traits for a curve implementing
+/// [`SswuParams`], its base field element and scalar types.
+///
+/// `$len` is the number of bytes `L` of `expand_message` output used for each
+/// field element, as defined in
+/// [RFC 9380 § 5](https://www.rfc-editor.org/rfc/rfc9380.html#section-5), e.g.
+/// `U48` for 256-bit curves with 128-bit security.
+///
+/// [`GroupDigest`]: elliptic_curve::hash2curve::GroupDigest
+#[macro_export]
+macro_rules! impl_hash2curve {
+    ($curve:ty, $field_element:ty, $scalar:ty, $len:ty) => {
+        impl $crate::elliptic_curve::hash2curve::GroupDigest for $curve {
+            type FieldElement = $field_element;
+        }
+
+        impl $crate::elliptic_curve::hash2curve::FromOkm for $field_element {
+            type Length = $len;
+
+            fn from_okm(data: &$crate::array::Array<u8, Self::Length>) -> Self {
+                $crate::hash2curve::from_okm(data)
+            }
+        }
+
+        impl $crate::elliptic_curve::hash2curve::MapToCurve for $field_element {
+            type Output = $crate::ProjectivePoint<$curve>;
+
+            fn map_to_curve(&self) -> Self::Output {
+                $crate::hash2curve::map_to_curve::<$curve>(self)
+            }
+        }
+
+        impl $crate::elliptic_curve::hash2curve::FromOkm for $scalar {
+            type Length = $len;
+
+            fn from_okm(data: &$crate::array::Array<u8, Self::Length>) -> Self {
+                $crate::hash2curve::from_okm(data)
+            }
+        }
+    };
+}
@@ -14,15 +14,49 @@ extern crate alloc;
 
 pub mod point_arithmetic;
 
-mod affine;
 #[cfg(feature = "dev")]
-mod dev;
+pub mod dev;
+#[cfg(feature = "dyn-curve")]
+pub mod dyn_curve;
+#[cfg(feature = "ecdh")]
+pub mod ecdh;
+#[cfg(feature = "ecdsa")]
+pub mod ecdsa;
+#[cfg(feature = "hash2curve")]
+pub mod hash2curve;
+#[cfg(feature = "alloc")]
+pub mod pippenger;
+#[cfg(feature = "pkcs8")]
+pub mod specified_curve;
+
+mod affine;
+mod define_curve;
 mod field;
+mod glv;
+mod mul;
+#[cfg(any(feature = "dev", feature = "dyn-curve"))]
+mod params;
 mod projective;
 
-pub use crate::{affine::AffinePoint, projective::ProjectivePoint};
+pub use crate::{
+    affine::AffinePoint,
+    glv::GlvParams,
+    mul::{BasepointTable, FixedBaseTable},
+    projective::ProjectivePoint,
+};
 pub use elliptic_curve::{self, array, point::Double, Field, FieldBytes, PrimeCurve, PrimeField};
 
+pub use primefield::{
+    impl_field_invert_vartime_tests, impl_field_pow_tests, impl_field_sqrt_ratio_tests,
+};
+
+#[doc(hidden)]
+pub use primefield;
+
+#[cfg(feature = "ecdsa")]
+#[doc(hidden)]
+pub use ecdsa_core;
+
 use elliptic_curve::ops::Invert;
 use elliptic_curve::subtle::CtOption;
 use elliptic_curve::CurveArithmetic;
@@ -50,4 +84,25 @@ pub trait PrimeCurveParams:
 
     /// Generator point's affine coordinates: (x, y).
     const GENERATOR: (Self::FieldElement, Self::FieldElement);
+
+    /// Calculates `[k] G`, where `G` is the generator.
+    ///
+    /// Used to implement [`MulByGenerator`] for [`ProjectivePoint`]. Curves
+    /// which keep a precomputed [`BasepointTable`] can override this to use it.
+    ///
+    /// [`MulByGenerator`]: elliptic_curve::ops::MulByGenerator
+    fn mul_by_generator(k: &Self::Scalar) -> ProjectivePoint<Self> {
+        ProjectivePoint::GENERATOR * k
+    }
+
+    /// Calculates `x1 * k1 + ... + xn * kn` in constant time.
+    ///
+    /// Used to implement scalar multiplication and [`LinearCombination`] for
+    /// [`ProjectivePoint`]. Curves which implement [`GlvParams`] can override
+    /// this with [`GlvParams::lincomb_glv`] to halve the number of doublings.
+    ///
+    /// [`LinearCombination`]: elliptic_curve::ops::LinearCombination
+    fn lincomb(xks: &[(ProjectivePoint<Self>, Self::Scalar)]) -> ProjectivePoint<Self> {
+        mul::lincomb(xks)
+    }
 }
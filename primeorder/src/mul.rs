@@ -0,0 +1,683 @@
+//! Scalar multiplication and linear combinations.
+//!
+//! Scalars are processed in windows from the most significant end, with a
+//! single chain of doublings shared between all of the terms of a linear
+//! combination (a.k.a. Straus-Shamir interleaving).
+//!
+//! The default implementations are constant time and use the regular signed
+//! odd-digit recoding of Joye and Tunstall with 4-bit windows: every digit is
+//! odd and in `[-15, 15]`, so only the odd multiples `[p, 3p, ..., 15p]` are
+//! precomputed and negative digits are handled by conditionally negating the
+//! selected point. Compared to signed 5-bit windows this halves the size of the
+//! table scanned by every lookup, at the cost of one addition per four bits
+//! rather than per five. The `*_vartime` variants use width-5 NAF and may only
+//! be used with public inputs.
+//!
+//! With the `side-channel-hardening` feature, multiplication by secret scalars
+//! can additionally be randomised on every call using a caller-provided random
+//! number generator, see [`ProjectivePoint::mul_blinded`] and
+//! [`FixedBaseTable::mul_blinded`].
+//!
+//! Large multi-scalar multiplications are computed with the bucket method
+//! (a.k.a. Pippenger's algorithm) instead, see [`pippenger`].
+//!
+//! [`pippenger`]: crate::pippenger
+
+#![allow(clippy::needless_range_loop)]
+
+use crate::{PrimeCurveParams, ProjectivePoint};
+use elliptic_curve::{
+    bigint::{BitOps, Bounded, Integer, Limb, WrappingAdd, WrappingSub, Zero},
+    point::Double,
+    scalar::IsHigh,
+    subtle::{Choice, ConditionallySelectable},
+    Scalar,
+};
+
+#[cfg(feature = "side-channel-hardening")]
+use {
+    crate::point_arithmetic::PointArithmetic,
+    elliptic_curve::{bigint::Word, rand_core::CryptoRngCore, Field},
+};
+
+/// Width of the signed odd digits used for constant-time multiplication.
+const WINDOW_WIDTH: usize = 4;
+
+/// Number of odd multiples needed for [`WINDOW_WIDTH`].
+const TABLE_SIZE: usize = 1 << (WINDOW_WIDTH - 1);
+
+/// Lookup table containing precomputed odd multiples `[p, 3p, 5p, ..., 15p]`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LookupTable<C: PrimeCurveParams>([ProjectivePoint<C>; TABLE_SIZE]);
+
+impl<C> From<&ProjectivePoint<C>> for LookupTable<C>
+where
+    C: PrimeCurveParams,
+{
+    fn from(p: &ProjectivePoint<C>) -> Self {
+        let p2 = Double::double(p);
+        let mut points = [*p; TABLE_SIZE];
+
+        for i in 1..TABLE_SIZE {
+            points[i] = points[i - 1].add(&p2);
+        }
+
+        Self(points)
+    }
+}
+
+impl<C> LookupTable<C>
+where
+    C: PrimeCurveParams,
+{
+    /// Given a digit encoded as `0 <= c < 16`, see [`odd_digit`], returns
+    /// `(2c - 15) * p` in constant time.
+    pub(crate) fn select(&self, c: u8) -> ProjectivePoint<C> {
+        debug_assert!(c < 16);
+
+        // Digits `c < 8` are negative, and complementing their low three bits
+        // yields the index of their absolute value.
+        let neg = (c >> 3) ^ 1;
+        let x = (c ^ (0u8.wrapping_sub(neg) & 7)) & 7;
+
+        let mut t = ProjectivePoint::IDENTITY;
+
+        for i in 0..TABLE_SIZE {
+            t.conditional_assign(
+                &self.0[i],
+                Choice::from(((x as usize ^ i).wrapping_sub(1) >> 8) as u8 & 1),
+            );
+        }
+
+        ProjectivePoint::conditional_select(&t, &t.neg(), Choice::from(neg))
+    }
+
+    /// Returns `-p` if `k` is even and the identity otherwise, in constant
+    /// time.
+    ///
+    /// Adding this to the result of the recoding of `k` corrects for even
+    /// scalars, see [`odd_digit`].
+    pub(crate) fn parity_correction(&self, k: &[Limb]) -> ProjectivePoint<C> {
+        let even = Choice::from((k[0].0 & 1) as u8 ^ 1);
+        ProjectivePoint::conditional_select(&ProjectivePoint::IDENTITY, &self.0[0].neg(), even)
+    }
+}
+
+impl<C> Default for LookupTable<C>
+where
+    C: PrimeCurveParams,
+{
+    fn default() -> Self {
+        Self([ProjectivePoint::IDENTITY; TABLE_SIZE])
+    }
+}
+
+/// Returns the `i`-th of the `len` digits of the regular signed odd-digit
+/// recoding of `k`, encoded as `0 <= c < 16` for the digit `2c - 15`.
+///
+/// For odd `k < 16^len` we have `k = sum((2c_i - 15) * 16^i)`, where the `c_i`
+/// are the nibbles of `(k + 16^len - 1) / 2 = floor(k / 2) + 2^(4 * len - 1)`,
+/// i.e. of `k` shifted right by one bit with the top bit of the last nibble
+/// set. An even `k` is recoded as `k + 1`, which has the same digits, and the
+/// result corrected with [`LookupTable::parity_correction`]. This operation
+/// is constant time.
+fn odd_digit(k: &[Limb], i: usize, len: usize) -> u8 {
+    let c = window(k, i * WINDOW_WIDTH + 1, WINDOW_WIDTH) as u8;
+
+    if i + 1 == len {
+        c | 8
+    } else {
+        c
+    }
+}
+
+/// Number of terms of a constant-time linear combination which are processed
+/// with a shared chain of doublings.
+pub(crate) const LINCOMB_CHUNK_SIZE: usize = 4;
+
+/// Computes `x1 * k1 + ... + xn * kn` in constant time.
+///
+/// Terms are processed in chunks of [`LINCOMB_CHUNK_SIZE`], each of which
+/// shares a single doubling chain.
+pub(crate) fn lincomb<C>(xks: &[(ProjectivePoint<C>, Scalar<C>)]) -> ProjectivePoint<C>
+where
+    C: PrimeCurveParams,
+{
+    let mut tables = [LookupTable::default(); LINCOMB_CHUNK_SIZE];
+    let mut ks = [C::Uint::default(); LINCOMB_CHUNK_SIZE];
+    let mut acc = ProjectivePoint::IDENTITY;
+
+    for chunk in xks.chunks(LINCOMB_CHUNK_SIZE) {
+        for (i, (x, k)) in chunk.iter().enumerate() {
+            tables[i] = LookupTable::from(x);
+            ks[i] = Into::<C::Uint>::into(*k);
+        }
+
+        let n = chunk.len();
+        acc = acc.add(&lincomb_tables(
+            &tables[..n],
+            &ks[..n],
+            C::ORDER.bits() as usize,
+        ));
+    }
+
+    acc
+}
+
+/// Computes `x1 * k1 + ... + xn * kn` in constant time, sharing a single
+/// doubling chain between all of the terms.
+///
+/// `tables[i]` must contain the odd multiples of `xi`, and every `ki` must be
+/// less than `2^bits`.
+pub(crate) fn lincomb_tables<C, K>(
+    tables: &[LookupTable<C>],
+    ks: &[K],
+    bits: usize,
+) -> ProjectivePoint<C>
+where
+    C: PrimeCurveParams,
+    K: AsRef<[Limb]>,
+{
+    debug_assert_eq!(tables.len(), ks.len());
+
+    let len = bits.div_ceil(WINDOW_WIDTH);
+    let mut q = ProjectivePoint::IDENTITY;
+
+    for pos in (0..len).rev() {
+        if pos + 1 < len {
+            for _ in 0..WINDOW_WIDTH {
+                q = Double::double(&q);
+            }
+        }
+
+        for i in 0..tables.len() {
+            q = q.add(&tables[i].select(odd_digit(ks[i].as_ref(), pos, len)));
+        }
+    }
+
+    for i in 0..tables.len() {
+        q = q.add(&tables[i].parity_correction(ks[i].as_ref()));
+    }
+
+    q
+}
+
+/// Number of random bits in the multiple of the group order added to secret
+/// scalars.
+#[cfg(feature = "side-channel-hardening")]
+pub(crate) const BLINDING_BITS: usize = 64;
+
+/// Number of limbs needed to hold [`BLINDING_BITS`].
+#[cfg(feature = "side-channel-hardening")]
+const BLINDING_LIMBS: usize = BLINDING_BITS / Limb::BITS as usize;
+
+/// Maximum number of limbs of a blinded scalar, which is enough for curve
+/// orders of up to 576 bits (i.e. P-521).
+#[cfg(feature = "side-channel-hardening")]
+pub(crate) const MAX_BLINDED_LIMBS: usize = 576 / Limb::BITS as usize + BLINDING_LIMBS;
+
+/// Returns `k + r * n` as a little-endian integer, along with its number of
+/// limbs, where `n` is the group order and `r` is a random
+/// [`BLINDING_BITS`]-bit integer.
+#[cfg(feature = "side-channel-hardening")]
+#[allow(clippy::unnecessary_cast)] // `Word` is `u64` on 64-bit targets
+pub(crate) fn blind_scalar<C>(
+    k: &Scalar<C>,
+    rng: &mut impl CryptoRngCore,
+) -> ([Limb; MAX_BLINDED_LIMBS], usize)
+where
+    C: PrimeCurveParams,
+{
+    let n = C::ORDER;
+    let n = n.as_ref();
+    let len = n.len() + BLINDING_LIMBS;
+    assert!(len <= MAX_BLINDED_LIMBS, "curve order is too large");
+
+    let r = rng.next_u64();
+    let mut blinded = [Limb::ZERO; MAX_BLINDED_LIMBS];
+    blinded[..n.len()].copy_from_slice(Into::<C::Uint>::into(*k).as_ref());
+
+    // blinded += r * n, one limb of `r` at a time
+    for j in 0..BLINDING_LIMBS {
+        let rj = Limb((r >> (j * Limb::BITS as usize)) as Word);
+        let mut carry = Limb::ZERO;
+
+        for i in 0..n.len() {
+            (blinded[i + j], carry) = blinded[i + j].mac(n[i], rj, carry);
+        }
+
+        for i in n.len() + j..len {
+            (blinded[i], carry) = blinded[i].adc(Limb::ZERO, carry);
+        }
+    }
+
+    (blinded, len)
+}
+
+/// Returns a random nonzero field element, used to randomise projective
+/// coordinates.
+#[cfg(feature = "side-channel-hardening")]
+pub(crate) fn random_nonzero<C>(rng: &mut impl CryptoRngCore) -> C::FieldElement
+where
+    C: PrimeCurveParams,
+{
+    let lambda = C::FieldElement::random(rng);
+    C::FieldElement::conditional_select(&lambda, &C::FieldElement::ONE, lambda.is_zero())
+}
+
+/// Computes `[k] p` in constant time, randomising the computation on every
+/// call to protect secret scalars against differential power analysis and
+/// template attacks.
+///
+/// - The scalar is replaced by `k + r * n`, see [`blind_scalar`], so the
+///   digits processed differ between calls.
+/// - The projective coordinates of `p` are multiplied by a random nonzero
+///   field element, so all of the intermediate points differ between calls.
+///
+/// Since the blinded scalar is not reduced, [`PrimeCurveParams::lincomb`] and
+/// thus any endomorphism is not used.
+#[cfg(feature = "side-channel-hardening")]
+pub(crate) fn mul_blinded<C>(
+    p: &ProjectivePoint<C>,
+    k: &Scalar<C>,
+    rng: &mut impl CryptoRngCore,
+) -> ProjectivePoint<C>
+where
+    C: PrimeCurveParams,
+{
+    let (blinded, len) = blind_scalar::<C>(k, rng);
+    let p = C::PointArithmetic::rescale(p, &random_nonzero::<C>(rng));
+
+    lincomb_tables(
+        &[LookupTable::from(&p)],
+        &[&blinded[..len]],
+        C::ORDER.bits() as usize + BLINDING_BITS,
+    )
+}
+
+/// Precomputed table of multiples of a fixed point, used to accelerate
+/// repeated scalar multiplication of that point.
+///
+/// Building the table is considerably more expensive than a single scalar
+/// multiplication, but pays off when the same point is multiplied many times,
+/// e.g. when verifying many signatures under the same public key, or when
+/// committing with fixed Pedersen generators.
+///
+/// Contains the odd multiples `[P, 3P, ..., 15P] * 256^i` for `0 <= i < N`,
+/// where `N` is the number of bytes needed to encode a scalar. This allows
+/// computing `[k] P` with `2 * N` constant-time table lookups and additions,
+/// one more addition to correct for even scalars, and only four doublings.
+#[derive(Clone, Debug)]
+pub struct FixedBaseTable<C: PrimeCurveParams, const N: usize>([LookupTable<C>; N]);
+
+impl<C, const N: usize> FixedBaseTable<C, N>
+where
+    C: PrimeCurveParams,
+{
+    /// Compute the table for the given point.
+    ///
+    /// # Panics
+    ///
+    /// If `N` does not match the number of bytes needed to encode a scalar.
+    pub fn new(point: &ProjectivePoint<C>) -> Self {
+        assert!(
+            N * 8 >= C::ORDER.bits() as usize && N * 8 <= C::Uint::BITS as usize,
+            "table size does not match curve order"
+        );
+
+        let mut p = *point;
+        let mut tables = [LookupTable::default(); N];
+
+        for i in 0..N {
+            tables[i] = LookupTable::from(&p);
+
+            for _ in 0..8 {
+                p = Double::double(&p);
+            }
+        }
+
+        Self(tables)
+    }
+
+    /// Calculates `[k] P` in constant time, where `P` is the point the table
+    /// was computed for.
+    pub fn mul(&self, k: &Scalar<C>) -> ProjectivePoint<C> {
+        Self::lincomb(&[(self, *k)])
+    }
+
+    /// Calculates `[k] P` in constant time, where `P` is the point the table
+    /// was computed for, randomising the computation on every call.
+    ///
+    /// The entries of the table are fixed, so unlike
+    /// [`ProjectivePoint::mul_blinded`] neither the scalar nor the coordinates
+    /// can be blinded. Instead, `k` is split into `(k - m) + m` for a random
+    /// scalar `m`, so the table entries which are selected differ between
+    /// calls. This costs twice as many table lookups and additions as
+    /// [`FixedBaseTable::mul`].
+    #[cfg(feature = "side-channel-hardening")]
+    pub fn mul_blinded(&self, k: &Scalar<C>, rng: &mut impl CryptoRngCore) -> ProjectivePoint<C> {
+        let m = Scalar::<C>::random(rng);
+        Self::lincomb(&[(self, *k - m), (self, m)])
+    }
+
+    /// Calculates `[k1] P1 + ... + [kn] Pn` in constant time, where `Pi` is
+    /// the point the `i`-th table was computed for.
+    ///
+    /// All of the terms share the same four doublings.
+    pub fn lincomb(terms: &[(&Self, Scalar<C>)]) -> ProjectivePoint<C> {
+        // Even and odd digits are accumulated separately, so only every other
+        // radix-16 step needs its own table.
+        let mut lo = ProjectivePoint::IDENTITY;
+        let mut hi = ProjectivePoint::IDENTITY;
+
+        for (table, k) in terms {
+            let k = Into::<C::Uint>::into(*k);
+            let k = k.as_ref();
+
+            for i in 0..N {
+                lo = lo.add(&table.0[i].select(odd_digit(k, 2 * i, 2 * N)));
+                hi = hi.add(&table.0[i].select(odd_digit(k, 2 * i + 1, 2 * N)));
+            }
+
+            lo = lo.add(&table.0[0].parity_correction(k));
+        }
+
+        for _ in 0..4 {
+            hi = Double::double(&hi);
+        }
+
+        lo.add(&hi)
+    }
+}
+
+impl<C, const N: usize> From<&ProjectivePoint<C>> for FixedBaseTable<C, N>
+where
+    C: PrimeCurveParams,
+{
+    fn from(point: &ProjectivePoint<C>) -> Self {
+        Self::new(point)
+    }
+}
+
+/// Precomputed table of multiples of the generator, used to accelerate
+/// fixed-base scalar multiplication.
+///
+/// This is a [`FixedBaseTable`] for the generator of the curve. Since the
+/// table is fairly large, curve crates typically keep a single, lazily
+/// computed instance in a `static` and use it to override
+/// [`PrimeCurveParams::mul_by_generator`].
+#[derive(Clone, Debug)]
+pub struct BasepointTable<C: PrimeCurveParams, const N: usize>(FixedBaseTable<C, N>);
+
+impl<C, const N: usize> BasepointTable<C, N>
+where
+    C: PrimeCurveParams,
+{
+    /// Compute the table for the generator of the curve.
+    ///
+    /// # Panics
+    ///
+    /// If `N` does not match the number of bytes needed to encode a scalar.
+    pub fn new() -> Self {
+        Self(FixedBaseTable::new(&ProjectivePoint::GENERATOR))
+    }
+
+    /// Calculates `[k] G` in constant time, where `G` is the generator.
+    pub fn mul(&self, k: &Scalar<C>) -> ProjectivePoint<C> {
+        self.0.mul(k)
+    }
+
+    /// Calculates `[k] G` in constant time, where `G` is the generator,
+    /// randomising the computation on every call.
+    ///
+    /// See [`FixedBaseTable::mul_blinded`].
+    #[cfg(feature = "side-channel-hardening")]
+    pub fn mul_blinded(&self, k: &Scalar<C>, rng: &mut impl CryptoRngCore) -> ProjectivePoint<C> {
+        self.0.mul_blinded(k, rng)
+    }
+}
+
+impl<C, const N: usize> AsRef<FixedBaseTable<C, N>> for BasepointTable<C, N>
+where
+    C: PrimeCurveParams,
+{
+    fn as_ref(&self) -> &FixedBaseTable<C, N> {
+        &self.0
+    }
+}
+
+impl<C, const N: usize> Default for BasepointTable<C, N>
+where
+    C: PrimeCurveParams,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Window width used for variable-time wNAF multiplication.
+const WNAF_WIDTH: usize = 5;
+
+/// Number of odd multiples needed for [`WNAF_WIDTH`].
+const WNAF_TABLE_SIZE: usize = 1 << (WNAF_WIDTH - 2);
+
+/// Number of terms of a variable-time linear combination which are processed
+/// with a shared chain of doublings.
+const VARTIME_CHUNK_SIZE: usize = 8;
+
+/// Lookup table containing precomputed odd multiples `[p, 3p, 5p, ..., 15p]`.
+#[derive(Clone, Copy, Debug)]
+struct OddMultiples<C: PrimeCurveParams>([ProjectivePoint<C>; WNAF_TABLE_SIZE]);
+
+impl<C> From<&ProjectivePoint<C>> for OddMultiples<C>
+where
+    C: PrimeCurveParams,
+{
+    fn from(p: &ProjectivePoint<C>) -> Self {
+        let p2 = Double::double(p);
+        let mut points = [*p; WNAF_TABLE_SIZE];
+
+        for i in 1..WNAF_TABLE_SIZE {
+            points[i] = points[i - 1].add(&p2);
+        }
+
+        Self(points)
+    }
+}
+
+impl<C> OddMultiples<C>
+where
+    C: PrimeCurveParams,
+{
+    /// Given an odd `x` with `|x| < 2 * WNAF_TABLE_SIZE`, returns `x * p`.
+    ///
+    /// This operation is variable time.
+    fn get_vartime(&self, x: i8) -> ProjectivePoint<C> {
+        debug_assert!(x & 1 == 1);
+
+        if x > 0 {
+            self.0[(x as usize) / 2]
+        } else {
+            self.0[(-x as usize) / 2].neg()
+        }
+    }
+}
+
+impl<C> Default for OddMultiples<C>
+where
+    C: PrimeCurveParams,
+{
+    fn default() -> Self {
+        Self([ProjectivePoint::IDENTITY; WNAF_TABLE_SIZE])
+    }
+}
+
+/// Width-`w` non-adjacent form of a scalar.
+///
+/// Rather than storing one signed digit per bit position, which would need a
+/// buffer sized by the curve, the digits are kept in three integers:
+///
+/// - `starts` has a bit set at the position of every nonzero digit,
+/// - `magnitudes` holds the absolute value of each digit, placed at its position,
+/// - `negative` has a bit set at the position of every negative digit.
+///
+/// Nonzero digits are odd, less than `2^(w-1)` in absolute value and followed
+/// by at least `w - 1` zero digits, so their magnitudes never overlap.
+#[derive(Clone, Copy, Debug)]
+struct Wnaf<C: PrimeCurveParams> {
+    starts: C::Uint,
+    magnitudes: C::Uint,
+    negative: C::Uint,
+}
+
+impl<C> Wnaf<C>
+where
+    C: PrimeCurveParams,
+{
+    /// Compute the width-[`WNAF_WIDTH`] NAF of `k`.
+    ///
+    /// `k` must be less than `n / 2`, which ensures all of the digits fit in
+    /// a `C::Uint`. This operation is variable time.
+    fn new(k: &Scalar<C>) -> Self {
+        debug_assert!(!bool::from(k.is_high()));
+
+        let mut k = Into::<C::Uint>::into(*k);
+        let mut wnaf = Self::default();
+        let mut pos = 0;
+
+        while !bool::from(k.is_zero()) {
+            if bool::from(k.is_odd()) {
+                let window = k.as_ref()[0].0 as usize & ((1 << WNAF_WIDTH) - 1);
+
+                if window < 1 << (WNAF_WIDTH - 1) {
+                    k = k.wrapping_sub(&C::Uint::from(window as u64));
+                    wnaf.push(pos, window, false);
+                } else {
+                    let digit = (1 << WNAF_WIDTH) - window;
+                    k = k.wrapping_add(&C::Uint::from(digit as u64));
+                    wnaf.push(pos, digit, true);
+                }
+            }
+
+            k >>= 1u32;
+            pos += 1;
+        }
+
+        wnaf
+    }
+
+    /// Record a nonzero digit of the given magnitude at bit position `pos`.
+    fn push(&mut self, pos: u32, magnitude: usize, negative: bool) {
+        self.starts.set_bit(pos, Choice::from(1));
+        self.magnitudes |= C::Uint::from(magnitude as u64) << pos;
+        self.negative.set_bit(pos, Choice::from(negative as u8));
+    }
+
+    /// Number of digits, i.e. one more than the position of the highest
+    /// nonzero digit.
+    fn len(&self) -> u32 {
+        self.starts.bits_vartime()
+    }
+
+    /// Get the digit at bit position `pos`, or `None` if it is zero.
+    fn digit(&self, pos: u32) -> Option<i8> {
+        if !self.starts.bit_vartime(pos) {
+            return None;
+        }
+
+        let limbs = self.magnitudes.as_ref();
+        let bits = Limb::BITS;
+        let (i, shift) = ((pos / bits) as usize, pos % bits);
+
+        let mut window = limbs[i].0 >> shift;
+        if shift + WNAF_WIDTH as u32 - 1 > bits && i + 1 < limbs.len() {
+            window |= limbs[i + 1].0 << (bits - shift);
+        }
+
+        let magnitude = (window as usize & ((1 << (WNAF_WIDTH - 1)) - 1)) as i8;
+
+        if self.negative.bit_vartime(pos) {
+            Some(-magnitude)
+        } else {
+            Some(magnitude)
+        }
+    }
+}
+
+impl<C> Default for Wnaf<C>
+where
+    C: PrimeCurveParams,
+{
+    fn default() -> Self {
+        Self {
+            starts: C::Uint::default(),
+            magnitudes: C::Uint::default(),
+            negative: C::Uint::default(),
+        }
+    }
+}
+
+/// Computes `x1 * k1 + ... + xn * kn` using interleaved width-5 NAF.
+///
+/// This operation is variable time with respect to both the points and the
+/// scalars, and should only be used with public inputs.
+pub(crate) fn lincomb_vartime<C>(xks: &[(ProjectivePoint<C>, Scalar<C>)]) -> ProjectivePoint<C>
+where
+    C: PrimeCurveParams,
+{
+    let mut acc = ProjectivePoint::IDENTITY;
+
+    for chunk in xks.chunks(VARTIME_CHUNK_SIZE) {
+        let mut tables = [OddMultiples::default(); VARTIME_CHUNK_SIZE];
+        let mut wnafs = [Wnaf::<C>::default(); VARTIME_CHUNK_SIZE];
+        let mut len = 0;
+
+        for (i, (x, k)) in chunk.iter().enumerate() {
+            // Ensure the scalar is less than `n / 2` by negating both it and
+            // the point if necessary.
+            let (x, k) = if bool::from(k.is_high()) {
+                (x.neg(), -*k)
+            } else {
+                (*x, *k)
+            };
+
+            tables[i] = OddMultiples::from(&x);
+            wnafs[i] = Wnaf::new(&k);
+            len = len.max(wnafs[i].len());
+        }
+
+        let mut q = ProjectivePoint::IDENTITY;
+
+        for pos in (0..len).rev() {
+            q = Double::double(&q);
+
+            for i in 0..chunk.len() {
+                if let Some(digit) = wnafs[i].digit(pos) {
+                    q = q.add(&tables[i].get_vartime(digit));
+                }
+            }
+        }
+
+        acc = acc.add(&q);
+    }
+
+    acc
+}
+
+/// Returns the `len`-bit window of `k` starting at bit `pos`, where `len` is
+/// at most `Limb::BITS`. Bits past the end of `k` are zero.
+#[allow(clippy::unnecessary_cast)] // `Word` is `u32` on 32-bit targets
+pub(crate) fn window(k: &[Limb], pos: usize, len: usize) -> u64 {
+    let bits = Limb::BITS as usize;
+    let (i, shift) = (pos / bits, pos % bits);
+
+    let mut w = k.get(i).map(|limb| limb.0 as u64 >> shift).unwrap_or(0);
+    if shift + len > bits {
+        w |= k
+            .get(i + 1)
+            .map(|limb| (limb.0 as u64) << (bits - shift))
+            .unwrap_or(0);
+    }
+
+    w & ((1 << len) - 1)
+}
@@ -0,0 +1,182 @@
+//! Checks of domain parameters which are only known at runtime, shared by
+//! `DynCurve` and the domain parameter audit.
+
+use elliptic_curve::bigint::{
+    modular::{MontyForm, MontyParams},
+    Limb, NonZero, Odd, Uint, Word,
+};
+
+/// Bases used for the Miller-Rabin rounds of [`is_probable_prime`].
+const MILLER_RABIN_BASES: [Word; 16] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+
+/// Returns whether `n` is within the Hasse bound of a curve over the field
+/// modulo `p`, i.e. whether `(p + 1 - n)² ≤ 4p`.
+pub(crate) fn is_within_hasse_bound<const LIMBS: usize>(p: &Uint<LIMBS>, n: &Uint<LIMBS>) -> bool {
+    let t = if n <= p {
+        p.wrapping_sub(n).wrapping_add(&Uint::ONE)
+    } else {
+        n.wrapping_sub(p).wrapping_sub(&Uint::ONE)
+    };
+
+    let (t2_lo, t2_hi) = t.square_wide();
+    let p4_lo = p.wrapping_shl_vartime(2);
+    let p4_hi = p.wrapping_shr_vartime(Uint::<LIMBS>::BITS - 2);
+    (t2_hi, t2_lo) <= (p4_hi, p4_lo)
+}
+
+/// Baillie-PSW probable prime test.
+///
+/// Runs Miller-Rabin with the first 16 prime bases, followed by a strong Lucas
+/// test with Selfridge's parameters. Composites can be constructed which pass
+/// Miller-Rabin for any fixed set of bases, but no composite is known to pass
+/// both tests, so this is suitable for parameters from untrusted sources.
+///
+/// This operation is variable time.
+pub(crate) fn is_probable_prime<const LIMBS: usize>(n: &Odd<Uint<LIMBS>>) -> bool {
+    if n.as_ref() <= &Uint::from_u8(3) {
+        return n.as_ref() == &Uint::from_u8(3);
+    }
+
+    is_strong_probable_prime(n) && is_strong_lucas_probable_prime(n)
+}
+
+/// Miller-Rabin test with [`MILLER_RABIN_BASES`], for `n > 3`.
+fn is_strong_probable_prime<const LIMBS: usize>(n: &Odd<Uint<LIMBS>>) -> bool {
+    let params = MontyParams::new_vartime(*n);
+    let one = MontyForm::one(params);
+    let minus_one = -one;
+
+    let n_minus_one = n.wrapping_sub(&Uint::ONE);
+    let s = n_minus_one.trailing_zeros_vartime();
+    let d = n_minus_one.wrapping_shr_vartime(s);
+
+    MILLER_RABIN_BASES.iter().all(|&base| {
+        let base = Uint::from_word(base);
+        if &base >= n.as_ref() {
+            return true;
+        }
+
+        let mut x = MontyForm::new(&base, params).pow(&d);
+        if x == one || x == minus_one {
+            return true;
+        }
+
+        (1..s).any(|_| {
+            x = x.square();
+            x == minus_one
+        })
+    })
+}
+
+/// Strong Lucas test with `P = 1` and `Q = (1 - D) / 4`, where `D` is the first
+/// of `5, -7, 9, -11, ...` with Jacobi symbol `(D/n) = -1`, for `n > 3`.
+fn is_strong_lucas_probable_prime<const LIMBS: usize>(n: &Odd<Uint<LIMBS>>) -> bool {
+    // No suitable `D` exists for perfect squares.
+    let root = n.sqrt_vartime();
+    if &root.wrapping_mul(&root) == n.as_ref() {
+        return false;
+    }
+
+    let mut d: i64 = 5;
+    loop {
+        match jacobi(d, n) {
+            -1 => break,
+            0 => return n.as_ref() == &Uint::from_u64(d.unsigned_abs()),
+            _ => d = if d > 0 { -(d + 2) } else { 2 - d },
+        }
+    }
+
+    let params = MontyParams::new_vartime(*n);
+    let small = |x: i64| {
+        let y = MontyForm::new(&Uint::from_u64(x.unsigned_abs()), params);
+        if x < 0 {
+            -y
+        } else {
+            y
+        }
+    };
+    let is_zero = |x: &MontyForm<LIMBS>| x.as_montgomery() == &Uint::ZERO;
+    let (d, q) = (small(d), small((1 - d) / 4));
+
+    // n + 1 = k * 2^s, with k odd
+    let n_plus_one = n.wrapping_add(&Uint::ONE);
+    let (k, s) = if n_plus_one == Uint::ZERO {
+        (Uint::ONE, Uint::<LIMBS>::BITS)
+    } else {
+        let s = n_plus_one.trailing_zeros_vartime();
+        (n_plus_one.wrapping_shr_vartime(s), s)
+    };
+
+    // Compute U_k, V_k and Q^k from the most significant bit of `k`
+    let mut u = MontyForm::one(params);
+    let mut v = u;
+    let mut qk = q;
+
+    for i in (0..k.bits_vartime() - 1).rev() {
+        u *= v;
+        v = v.square() - qk - qk;
+        qk = qk.square();
+
+        if k.bit_vartime(i) {
+            (u, v) = ((u + v).div_by_2(), (d * u + v).div_by_2());
+            qk *= q;
+        }
+    }
+
+    if is_zero(&u) || is_zero(&v) {
+        return true;
+    }
+
+    (1..s).any(|_| {
+        v = v.square() - qk - qk;
+        qk = qk.square();
+        is_zero(&v)
+    })
+}
+
+/// Jacobi symbol `(d/n)` for odd `d` and `n`.
+fn jacobi<const LIMBS: usize>(d: i64, n: &Odd<Uint<LIMBS>>) -> i8 {
+    let a = d.unsigned_abs() as Word;
+    let n_mod_4 = n.as_ref().as_words()[0] & 3;
+    let mut sign = 1;
+
+    // (-1/n) = -1 iff n = 3 (mod 4)
+    if d < 0 && n_mod_4 == 3 {
+        sign = -sign;
+    }
+
+    // Quadratic reciprocity: (a/n) = (n/a), unless a = n = 3 (mod 4)
+    if a & 3 == 3 && n_mod_4 == 3 {
+        sign = -sign;
+    }
+
+    let r = n.rem_limb(NonZero::new(Limb(a)).expect("d is odd")).0;
+    sign * jacobi_word(r, a)
+}
+
+/// Jacobi symbol `(a/n)` for odd `n`.
+fn jacobi_word(mut a: Word, mut n: Word) -> i8 {
+    let mut sign = 1;
+    a %= n;
+
+    while a != 0 {
+        while a % 2 == 0 {
+            a /= 2;
+            if n % 8 == 3 || n % 8 == 5 {
+                sign = -sign;
+            }
+        }
+
+        core::mem::swap(&mut a, &mut n);
+        if a % 4 == 3 && n % 4 == 3 {
+            sign = -sign;
+        }
+        a %= n;
+    }
+
+    if n == 1 {
+        sign
+    } else {
+        0
+    }
+}
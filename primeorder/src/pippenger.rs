@@ -0,0 +1,163 @@
+//! Multi-scalar multiplication using the bucket method (a.k.a. Pippenger's
+//! algorithm).
+//!
+//! Each scalar is recoded into signed `c`-bit digits. For every window, the
+//! points are added to one of `2^(c-1)` buckets selected by their digit, and
+//! the buckets are then summed weighted by their index. This needs roughly
+//! `n + 2^c` additions per window rather than one per window and point, so it
+//! is considerably faster than interleaved multiplication for large numbers
+//! of terms.
+//!
+//! The functions in this module are generic over [`Group`], so they can be
+//! used by curve implementations which don't use [`ProjectivePoint`].
+//!
+//! [`ProjectivePoint`]: crate::ProjectivePoint
+
+use crate::mul::window;
+use alloc::vec::Vec;
+use elliptic_curve::{
+    bigint::Limb,
+    group::Group,
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
+};
+
+/// Number of terms below which the bucket method is slower than interleaved
+/// multiplication with a shared chain of doublings.
+pub const MIN_TERMS: usize = 32;
+
+/// Maximum window size of the constant-time bucket method.
+///
+/// Every bucket is scanned twice per point and window, so unlike the
+/// variable-time method the cost of each point grows with the number of
+/// buckets. Beyond 5 bits the scans outweigh the additions saved by using
+/// fewer windows.
+const MAX_CT_WINDOW: usize = 5;
+
+/// Computes `x1 * k1 + ... + xn * kn` in constant time.
+///
+/// Scalars are given as little-endian integers, each of which must be less
+/// than `2^bits`. Every bucket is read and written using a linear scan, so
+/// memory access patterns do not depend on the scalars.
+///
+/// # Panics
+///
+/// If `points` and `scalars` have different lengths.
+pub fn msm<G, K>(points: &[G], scalars: &[K], bits: usize) -> G
+where
+    G: Group + ConditionallySelectable,
+    K: AsRef<[Limb]>,
+{
+    let c = window_size(points.len()).min(MAX_CT_WINDOW);
+
+    msm_generic(points, scalars, bits, c, |buckets, x, digit| {
+        let neg = Choice::from((digit < 0) as u8);
+        let index = digit.unsigned_abs();
+
+        let mut t = G::identity();
+        for (j, bucket) in buckets.iter().enumerate() {
+            t.conditional_assign(bucket, (j as u32).ct_eq(&index));
+        }
+
+        t += G::conditional_select(x, &-*x, neg);
+
+        for (j, bucket) in buckets.iter_mut().enumerate() {
+            bucket.conditional_assign(&t, (j as u32).ct_eq(&index));
+        }
+    })
+}
+
+/// Computes `x1 * k1 + ... + xn * kn`.
+///
+/// Scalars are given as little-endian integers, each of which must be less
+/// than `2^bits`.
+///
+/// <div class="warning">
+/// This operation is variable time with respect to both the points and the
+/// scalars. It must only be used with public inputs.
+/// </div>
+///
+/// # Panics
+///
+/// If `points` and `scalars` have different lengths.
+pub fn msm_vartime<G, K>(points: &[G], scalars: &[K], bits: usize) -> G
+where
+    G: Group,
+    K: AsRef<[Limb]>,
+{
+    let c = window_size(points.len());
+
+    msm_generic(points, scalars, bits, c, |buckets, x, digit| {
+        let index = digit.unsigned_abs() as usize;
+
+        if digit > 0 {
+            buckets[index] += x;
+        } else if digit < 0 {
+            buckets[index] -= x;
+        }
+    })
+}
+
+/// Window size which minimises the number of additions for `n` terms.
+fn window_size(n: usize) -> usize {
+    if n < 32 {
+        3
+    } else {
+        // Roughly ln(n) + 2
+        ((usize::BITS - n.leading_zeros()) as usize * 69 / 100 + 2).min(16)
+    }
+}
+
+/// Recode `k` into signed digits `d_i` with `-2^(c-1) < d_i <= 2^(c-1)` such
+/// that `k = sum(d_i * 2^(c*i))`. This operation is constant time.
+fn signed_digits(k: &[Limb], c: usize, digits: &mut [i32]) {
+    let mut carry = 0;
+
+    for (i, digit) in digits.iter_mut().enumerate() {
+        let w = window(k, i * c, c) + carry;
+        carry = (w + (1 << (c - 1)) - 1) >> c;
+        *digit = w as i32 - (carry << c) as i32;
+    }
+}
+
+/// Computes `x1 * k1 + ... + xn * kn` with `c`-bit windows, using
+/// `add_to_bucket` to add each point to the bucket selected by its digit.
+fn msm_generic<G, K, F>(xs: &[G], ks: &[K], bits: usize, c: usize, add_to_bucket: F) -> G
+where
+    G: Group,
+    K: AsRef<[Limb]>,
+    F: Fn(&mut [G], &G, i32),
+{
+    assert_eq!(xs.len(), ks.len(), "length mismatch");
+
+    // One extra window absorbs the carry out of the topmost digit.
+    let windows = (bits + c) / c;
+
+    let mut digits = vec![0i32; windows * ks.len()];
+    for (k, digits) in ks.iter().zip(digits.chunks_mut(windows)) {
+        signed_digits(k.as_ref(), c, digits);
+    }
+
+    // Bucket 0 is never read and absorbs zero digits.
+    let mut buckets: Vec<G> = vec![G::identity(); (1 << (c - 1)) + 1];
+    let mut acc = G::identity();
+
+    for w in (0..windows).rev() {
+        for _ in 0..c {
+            acc = acc.double();
+        }
+
+        buckets.fill(G::identity());
+        for (i, x) in xs.iter().enumerate() {
+            add_to_bucket(&mut buckets, x, digits[i * windows + w]);
+        }
+
+        // Sum the buckets, weighting each by its index.
+        let mut running = G::identity();
+        for bucket in buckets.iter().skip(1).rev() {
+            running += bucket;
+            acc += &running;
+        }
+    }
+
+    acc
+}
@@ -3,12 +3,24 @@
 //! Support for formulas specialized to the short Weierstrass equation's
 //! 𝒂-coefficient.
 
-use elliptic_curve::{subtle::ConditionallySelectable, Field};
+use core::ops::{Add, Mul, Sub};
+use elliptic_curve::{
+    bigint::{BitOps, Limb},
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
+    Field, Scalar,
+};
 
-use crate::{AffinePoint, PrimeCurveParams, ProjectivePoint};
+use crate::{mul::window, AffinePoint, PrimeCurveParams, ProjectivePoint};
 
 mod sealed {
     use crate::{AffinePoint, PrimeCurveParams, ProjectivePoint};
+    use elliptic_curve::Scalar;
+
+    #[cfg(feature = "fault-countermeasures")]
+    use elliptic_curve::{
+        subtle::{Choice, ConstantTimeEq},
+        Field,
+    };
 
     /// Elliptic point arithmetic implementation
     ///
@@ -23,6 +35,54 @@ mod sealed {
 
         /// Returns `point + point`
         fn double(point: &ProjectivePoint<C>) -> ProjectivePoint<C>;
+
+        /// Returns `[k] point`, in constant time.
+        ///
+        /// The default implementation is [`PrimeCurveParams::lincomb`].
+        fn mul(point: &ProjectivePoint<C>, k: &Scalar<C>) -> ProjectivePoint<C> {
+            C::lincomb(&[(*point, *k)])
+        }
+
+        /// Returns the affine representation of `point`, given the inverse of
+        /// its `z` coordinate.
+        ///
+        /// The default implementation is for homogeneous projective coordinates
+        /// `(X : Y : Z)`, which represent the affine point `(X/Z, Y/Z)`.
+        fn to_affine(point: &ProjectivePoint<C>, zinv: &C::FieldElement) -> AffinePoint<C> {
+            AffinePoint {
+                x: point.x * zinv,
+                y: point.y * zinv,
+                infinity: 0,
+            }
+        }
+
+        /// Returns a different representation of the same point, with its
+        /// `z` coordinate multiplied by `lambda`, which must be nonzero.
+        ///
+        /// The default implementation is for homogeneous projective coordinates.
+        #[cfg(feature = "side-channel-hardening")]
+        fn rescale(point: &ProjectivePoint<C>, lambda: &C::FieldElement) -> ProjectivePoint<C> {
+            ProjectivePoint {
+                x: point.x * lambda,
+                y: point.y * lambda,
+                z: point.z * lambda,
+            }
+        }
+
+        /// Returns whether `point` is the identity or satisfies the curve
+        /// equation.
+        ///
+        /// The default implementation is for homogeneous projective coordinates:
+        /// `Y²Z = X³ + aXZ² + bZ³`.
+        #[cfg(feature = "fault-countermeasures")]
+        fn is_on_curve(point: &ProjectivePoint<C>) -> Choice {
+            let zz = point.z.square();
+            let lhs = point.y.square() * point.z;
+            let rhs =
+                (point.x.square() + C::EQUATION_A * zz) * point.x + C::EQUATION_B * zz * point.z;
+
+            point.z.is_zero() | lhs.ct_eq(&rhs)
+        }
     }
 }
 
@@ -37,110 +97,33 @@ impl<C: PrimeCurveParams> PointArithmetic<C> for EquationAIsGeneric {
     /// Implements complete addition for any curve
     ///
     /// Implements the complete addition formula from [Renes-Costello-Batina 2015]
-    /// (Algorithm 1). The comments after each line indicate which algorithm steps
-    /// are being performed.
+    /// (Algorithm 1).
     ///
     /// [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
     fn add(lhs: &ProjectivePoint<C>, rhs: &ProjectivePoint<C>) -> ProjectivePoint<C> {
         let b3 = C::FieldElement::from(3) * C::EQUATION_B;
+        let (x, y, z) = add_generic_a(
+            C::EQUATION_A,
+            b3,
+            (lhs.x, lhs.y, lhs.z),
+            (rhs.x, rhs.y, rhs.z),
+        );
 
-        let t0 = lhs.x * rhs.x; // 1
-        let t1 = lhs.y * rhs.y; // 2
-        let t2 = lhs.z * rhs.z; // 3
-        let t3 = lhs.x + lhs.y; // 4
-        let t4 = rhs.x + rhs.y; // 5
-        let t3 = t3 * t4; // 6
-        let t4 = t0 + t1; // 7
-        let t3 = t3 - t4; // 8
-        let t4 = lhs.x + lhs.z; // 9
-        let t5 = rhs.x + rhs.z; // 10
-        let t4 = t4 * t5; // 11
-        let t5 = t0 + t2; // 12
-        let t4 = t4 - t5; // 13
-        let t5 = lhs.y + lhs.z; // 14
-        let x3 = rhs.y + rhs.z; // 15
-        let t5 = t5 * x3; // 16
-        let x3 = t1 + t2; // 17
-        let t5 = t5 - x3; // 18
-        let z3 = C::EQUATION_A * t4; // 19
-        let x3 = b3 * t2; // 20
-        let z3 = x3 + z3; // 21
-        let x3 = t1 - z3; // 22
-        let z3 = t1 + z3; // 23
-        let y3 = x3 * z3; // 24
-        let t1 = t0 + t0; // 25
-        let t1 = t1 + t0; // 26
-        let t2 = C::EQUATION_A * t2; // 27
-        let t4 = b3 * t4; // 28
-        let t1 = t1 + t2; // 29
-        let t2 = t0 - t2; // 30
-        let t2 = C::EQUATION_A * t2; // 31
-        let t4 = t4 + t2; // 32
-        let t0 = t1 * t4; // 33
-        let y3 = y3 + t0; // 34
-        let t0 = t5 * t4; // 35
-        let x3 = t3 * x3; // 36
-        let x3 = x3 - t0; // 37
-        let t0 = t3 * t1; // 38
-        let z3 = t5 * z3; // 39
-        let z3 = z3 + t0; // 40
-
-        ProjectivePoint {
-            x: x3,
-            y: y3,
-            z: z3,
-        }
+        ProjectivePoint { x, y, z }
     }
 
     /// Implements complete mixed addition for curves with any `a`
     ///
     /// Implements the complete mixed addition formula from [Renes-Costello-Batina 2015]
-    /// (Algorithm 2). The comments after each line indicate which algorithm
-    /// steps are being performed.
+    /// (Algorithm 2).
     ///
     /// [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
     fn add_mixed(lhs: &ProjectivePoint<C>, rhs: &AffinePoint<C>) -> ProjectivePoint<C> {
         let b3 = C::EQUATION_B * C::FieldElement::from(3);
+        let (x, y, z) =
+            add_mixed_generic_a(C::EQUATION_A, b3, (lhs.x, lhs.y, lhs.z), (rhs.x, rhs.y));
 
-        let t0 = lhs.x * rhs.x; // 1
-        let t1 = lhs.y * rhs.y; // 2
-        let t3 = rhs.x + rhs.y; // 3
-        let t4 = lhs.x + lhs.y; // 4
-        let t3 = t3 * t4; // 5
-        let t4 = t0 + t1; // 6
-        let t3 = t3 - t4; // 7
-        let t4 = rhs.x * lhs.z; // 8
-        let t4 = t4 + lhs.x; // 9
-        let t5 = rhs.y * lhs.z; // 10
-        let t5 = t5 + lhs.y; // 11
-        let z3 = C::EQUATION_A * t4; // 12
-        let x3 = b3 * lhs.z; // 13
-        let z3 = x3 + z3; // 14
-        let x3 = t1 - z3; // 15
-        let z3 = t1 + z3; // 16
-        let y3 = x3 * z3; // 17
-        let t1 = t0 + t0; // 18
-        let t1 = t1 + t0; // 19
-        let t2 = C::EQUATION_A * lhs.z; // 20
-        let t4 = b3 * t4; // 21
-        let t1 = t1 + t2; // 22
-        let t2 = t0 - t2; // 23
-        let t2 = C::EQUATION_A * t2; // 24
-        let t4 = t4 + t2; // 25
-        let t0 = t1 * t4; // 26
-        let y3 = y3 + t0; // 27
-        let t0 = t5 * t4; // 28
-        let x3 = t3 * x3; // 29
-        let x3 = x3 - t0; // 30
-        let t0 = t3 * t1; // 31
-        let z3 = t5 * z3; // 32
-        let z3 = z3 + t0; // 33
-
-        let mut ret = ProjectivePoint {
-            x: x3,
-            y: y3,
-            z: z3,
-        };
+        let mut ret = ProjectivePoint { x, y, z };
         ret.conditional_assign(lhs, rhs.is_identity());
         ret
     }
@@ -148,53 +131,182 @@ impl<C: PrimeCurveParams> PointArithmetic<C> for EquationAIsGeneric {
     /// Implements point doubling for curves with any `a`
     ///
     /// Implements the exception-free point doubling formula from [Renes-Costello-Batina 2015]
-    /// (Algorithm 3). The comments after each line indicate which algorithm
-    /// steps are being performed.
+    /// (Algorithm 3).
     ///
     /// [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
     fn double(point: &ProjectivePoint<C>) -> ProjectivePoint<C> {
         let b3 = C::EQUATION_B * C::FieldElement::from(3);
+        let (x, y, z) = double_generic_a(C::EQUATION_A, b3, (point.x, point.y, point.z));
 
-        let t0 = point.x * point.x; // 1
-        let t1 = point.y * point.y; // 2
-        let t2 = point.z * point.z; // 3
-        let t3 = point.x * point.y; // 4
-        let t3 = t3 + t3; // 5
-        let z3 = point.x * point.z; // 6
-        let z3 = z3 + z3; // 7
-        let x3 = C::EQUATION_A * z3; // 8
-        let y3 = b3 * t2; // 9
-        let y3 = x3 + y3; // 10
-        let x3 = t1 - y3; // 11
-        let y3 = t1 + y3; // 12
-        let y3 = x3 * y3; // 13
-        let x3 = t3 * x3; // 14
-        let z3 = b3 * z3; // 15
-        let t2 = C::EQUATION_A * t2; // 16
-        let t3 = t0 - t2; // 17
-        let t3 = C::EQUATION_A * t3; // 18
-        let t3 = t3 + z3; // 19
-        let z3 = t0 + t0; // 20
-        let t0 = z3 + t0; // 21
-        let t0 = t0 + t2; // 22
-        let t0 = t0 * t3; // 23
-        let y3 = y3 + t0; // 24
-        let t2 = point.y * point.z; // 25
-        let t2 = t2 + t2; // 26
-        let t0 = t2 * t3; // 27
-        let x3 = x3 - t0; // 28
-        let z3 = t2 * t1; // 29
-        let z3 = z3 + z3; // 30
-        let z3 = z3 + z3; // 31
-
-        ProjectivePoint {
-            x: x3,
-            y: y3,
-            z: z3,
-        }
+        ProjectivePoint { x, y, z }
     }
 }
 
+/// Implements complete addition for any curve, in homogeneous projective
+/// coordinates over any field type `F`, given `a` and `b3 = 3b`.
+///
+/// Implements the complete addition formula from [Renes-Costello-Batina 2015]
+/// (Algorithm 1). The comments after each line indicate which algorithm steps
+/// are being performed.
+///
+/// [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
+pub(crate) fn add_generic_a<F: FormulaField>(
+    a: F,
+    b3: F,
+    (x1, y1, z1): (F, F, F),
+    (x2, y2, z2): (F, F, F),
+) -> (F, F, F) {
+    let t0 = x1 * x2; // 1
+    let t1 = y1 * y2; // 2
+    let t2 = z1 * z2; // 3
+    let t3 = x1 + y1; // 4
+    let t4 = x2 + y2; // 5
+    let t3 = t3 * t4; // 6
+    let t4 = t0 + t1; // 7
+    let t3 = t3 - t4; // 8
+    let t4 = x1 + z1; // 9
+    let t5 = x2 + z2; // 10
+    let t4 = t4 * t5; // 11
+    let t5 = t0 + t2; // 12
+    let t4 = t4 - t5; // 13
+    let t5 = y1 + z1; // 14
+    let x3 = y2 + z2; // 15
+    let t5 = t5 * x3; // 16
+    let x3 = t1 + t2; // 17
+    let t5 = t5 - x3; // 18
+    let z3 = a * t4; // 19
+    let x3 = b3 * t2; // 20
+    let z3 = x3 + z3; // 21
+    let x3 = t1 - z3; // 22
+    let z3 = t1 + z3; // 23
+    let y3 = x3 * z3; // 24
+    let t1 = t0 + t0; // 25
+    let t1 = t1 + t0; // 26
+    let t2 = a * t2; // 27
+    let t4 = b3 * t4; // 28
+    let t1 = t1 + t2; // 29
+    let t2 = t0 - t2; // 30
+    let t2 = a * t2; // 31
+    let t4 = t4 + t2; // 32
+    let t0 = t1 * t4; // 33
+    let y3 = y3 + t0; // 34
+    let t0 = t5 * t4; // 35
+    let x3 = t3 * x3; // 36
+    let x3 = x3 - t0; // 37
+    let t0 = t3 * t1; // 38
+    let z3 = t5 * z3; // 39
+    let z3 = z3 + t0; // 40
+
+    (x3, y3, z3)
+}
+
+/// Implements complete mixed addition for any curve, in homogeneous projective
+/// coordinates over any field type `F`, given `a` and `b3 = 3b`.
+///
+/// The affine point `(x2, y2)` must not be the identity.
+///
+/// Implements the complete mixed addition formula from [Renes-Costello-Batina 2015]
+/// (Algorithm 2). The comments after each line indicate which algorithm
+/// steps are being performed.
+///
+/// [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
+pub(crate) fn add_mixed_generic_a<F: FormulaField>(
+    a: F,
+    b3: F,
+    (x1, y1, z1): (F, F, F),
+    (x2, y2): (F, F),
+) -> (F, F, F) {
+    let t0 = x1 * x2; // 1
+    let t1 = y1 * y2; // 2
+    let t3 = x2 + y2; // 3
+    let t4 = x1 + y1; // 4
+    let t3 = t3 * t4; // 5
+    let t4 = t0 + t1; // 6
+    let t3 = t3 - t4; // 7
+    let t4 = x2 * z1; // 8
+    let t4 = t4 + x1; // 9
+    let t5 = y2 * z1; // 10
+    let t5 = t5 + y1; // 11
+    let z3 = a * t4; // 12
+    let x3 = b3 * z1; // 13
+    let z3 = x3 + z3; // 14
+    let x3 = t1 - z3; // 15
+    let z3 = t1 + z3; // 16
+    let y3 = x3 * z3; // 17
+    let t1 = t0 + t0; // 18
+    let t1 = t1 + t0; // 19
+    let t2 = a * z1; // 20
+    let t4 = b3 * t4; // 21
+    let t1 = t1 + t2; // 22
+    let t2 = t0 - t2; // 23
+    let t2 = a * t2; // 24
+    let t4 = t4 + t2; // 25
+    let t0 = t1 * t4; // 26
+    let y3 = y3 + t0; // 27
+    let t0 = t5 * t4; // 28
+    let x3 = t3 * x3; // 29
+    let x3 = x3 - t0; // 30
+    let t0 = t3 * t1; // 31
+    let z3 = t5 * z3; // 32
+    let z3 = z3 + t0; // 33
+
+    (x3, y3, z3)
+}
+
+/// Implements point doubling for any curve, in homogeneous projective
+/// coordinates over any field type `F`, given `a` and `b3 = 3b`.
+///
+/// Implements the exception-free point doubling formula from [Renes-Costello-Batina 2015]
+/// (Algorithm 3). The comments after each line indicate which algorithm
+/// steps are being performed.
+///
+/// [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
+pub(crate) fn double_generic_a<F: FormulaField>(a: F, b3: F, (x, y, z): (F, F, F)) -> (F, F, F) {
+    let t0 = x * x; // 1
+    let t1 = y * y; // 2
+    let t2 = z * z; // 3
+    let t3 = x * y; // 4
+    let t3 = t3 + t3; // 5
+    let z3 = x * z; // 6
+    let z3 = z3 + z3; // 7
+    let x3 = a * z3; // 8
+    let y3 = b3 * t2; // 9
+    let y3 = x3 + y3; // 10
+    let x3 = t1 - y3; // 11
+    let y3 = t1 + y3; // 12
+    let y3 = x3 * y3; // 13
+    let x3 = t3 * x3; // 14
+    let z3 = b3 * z3; // 15
+    let t2 = a * t2; // 16
+    let t3 = t0 - t2; // 17
+    let t3 = a * t3; // 18
+    let t3 = t3 + z3; // 19
+    let z3 = t0 + t0; // 20
+    let t0 = z3 + t0; // 21
+    let t0 = t0 + t2; // 22
+    let t0 = t0 * t3; // 23
+    let y3 = y3 + t0; // 24
+    let t2 = y * z; // 25
+    let t2 = t2 + t2; // 26
+    let t0 = t2 * t3; // 27
+    let x3 = x3 - t0; // 28
+    let z3 = t2 * t1; // 29
+    let z3 = z3 + z3; // 30
+    let z3 = z3 + z3; // 31
+
+    (x3, y3, z3)
+}
+
+/// Field arithmetic required by the generic formulas, which is implemented
+/// both by [`PrimeCurveParams::FieldElement`] and by runtime-modulus field
+/// elements.
+pub(crate) trait FormulaField:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+}
+
+impl<F> FormulaField for F where F: Copy + Add<Output = F> + Sub<Output = F> + Mul<Output = F> {}
+
 /// The 𝒂-coefficient of the short Weierstrass equation is -3.
 pub struct EquationAIsMinusThree {}
 
@@ -316,3 +428,578 @@ impl<C: PrimeCurveParams> PointArithmetic<C> for EquationAIsMinusThree {
         ProjectivePoint { x, y, z }
     }
 }
+
+/// The 𝒂-coefficient of the short Weierstrass equation is 0.
+pub struct EquationAIsZero {}
+
+impl<C: PrimeCurveParams> PointArithmetic<C> for EquationAIsZero {
+    /// Implements complete addition for curves with `a = 0`
+    ///
+    /// Implements the complete addition formula from [Renes-Costello-Batina 2015]
+    /// (Algorithm 7). The comments after each line indicate which algorithm steps
+    /// are being performed.
+    ///
+    /// [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
+    fn add(lhs: &ProjectivePoint<C>, rhs: &ProjectivePoint<C>) -> ProjectivePoint<C> {
+        debug_assert_eq!(
+            C::EQUATION_A,
+            C::FieldElement::ZERO,
+            "this implementation is only valid for C::EQUATION_A = 0"
+        );
+
+        let b3 = C::EQUATION_B.double() + C::EQUATION_B;
+
+        let xx = lhs.x * rhs.x; // 1
+        let yy = lhs.y * rhs.y; // 2
+        let zz = lhs.z * rhs.z; // 3
+        let xy_pairs = ((lhs.x + lhs.y) * (rhs.x + rhs.y)) - (xx + yy); // 4, 5, 6, 7, 8
+        let yz_pairs = ((lhs.y + lhs.z) * (rhs.y + rhs.z)) - (yy + zz); // 9, 10, 11, 12, 13
+        let xz_pairs = ((lhs.x + lhs.z) * (rhs.x + rhs.z)) - (xx + zz); // 14, 15, 16, 17, 18
+
+        let xx3 = xx.double() + xx; // 19, 20
+        let bzz3 = b3 * zz; // 21
+        let yy_p_bzz3 = yy + bzz3; // 22
+        let yy_m_bzz3 = yy - bzz3; // 23
+        let bxz3 = b3 * xz_pairs; // 24
+
+        ProjectivePoint {
+            x: (xy_pairs * yy_m_bzz3) - (yz_pairs * bxz3), // 25, 26, 27
+            y: (yy_m_bzz3 * yy_p_bzz3) + (bxz3 * xx3),     // 28, 29, 30
+            z: (yy_p_bzz3 * yz_pairs) + (xx3 * xy_pairs),  // 31, 32, 33
+        }
+    }
+
+    /// Implements complete mixed addition for curves with `a = 0`
+    ///
+    /// Implements the complete mixed addition formula from [Renes-Costello-Batina 2015]
+    /// (Algorithm 8). The comments after each line indicate which algorithm
+    /// steps are being performed.
+    ///
+    /// [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
+    fn add_mixed(lhs: &ProjectivePoint<C>, rhs: &AffinePoint<C>) -> ProjectivePoint<C> {
+        debug_assert_eq!(
+            C::EQUATION_A,
+            C::FieldElement::ZERO,
+            "this implementation is only valid for C::EQUATION_A = 0"
+        );
+
+        let b3 = C::EQUATION_B.double() + C::EQUATION_B;
+
+        let xx = lhs.x * rhs.x; // 1
+        let yy = lhs.y * rhs.y; // 2
+        let xy_pairs = ((lhs.x + lhs.y) * (rhs.x + rhs.y)) - (xx + yy); // 3, 4, 5, 6, 7
+        let yz_pairs = (rhs.y * lhs.z) + lhs.y; // 8, 9
+        let xz_pairs = (rhs.x * lhs.z) + lhs.x; // 10, 11
+
+        let xx3 = xx.double() + xx; // 12, 13
+        let bz3 = b3 * lhs.z; // 14
+        let yy_p_bz3 = yy + bz3; // 15
+        let yy_m_bz3 = yy - bz3; // 16
+        let bxz3 = b3 * xz_pairs; // 17
+
+        let mut ret = ProjectivePoint {
+            x: (xy_pairs * yy_m_bz3) - (yz_pairs * bxz3), // 18, 19, 20
+            y: (yy_m_bz3 * yy_p_bz3) + (bxz3 * xx3),      // 21, 22, 23
+            z: (yy_p_bz3 * yz_pairs) + (xx3 * xy_pairs),  // 24, 25, 26
+        };
+        ret.conditional_assign(lhs, rhs.is_identity());
+        ret
+    }
+
+    /// Implements point doubling for curves with `a = 0`
+    ///
+    /// Implements the exception-free point doubling formula from [Renes-Costello-Batina 2015]
+    /// (Algorithm 9). The comments after each line indicate which algorithm
+    /// steps are being performed.
+    ///
+    /// [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
+    fn double(point: &ProjectivePoint<C>) -> ProjectivePoint<C> {
+        debug_assert_eq!(
+            C::EQUATION_A,
+            C::FieldElement::ZERO,
+            "this implementation is only valid for C::EQUATION_A = 0"
+        );
+
+        let b3 = C::EQUATION_B.double() + C::EQUATION_B;
+
+        let yy = point.y.square(); // 1
+        let yy8 = yy.double().double().double(); // 2, 3, 4
+        let yz = point.y * point.z; // 5
+        let bzz3 = b3 * point.z.square(); // 6, 7
+        let x_frag = bzz3 * yy8; // 8
+        let yy_p_bzz3 = yy + bzz3; // 9
+        let z = yz * yy8; // 10
+        let bzz9 = bzz3.double() + bzz3; // 11, 12
+        let yy_m_bzz9 = yy - bzz9; // 13
+        let y = x_frag + (yy_m_bzz9 * yy_p_bzz3); // 14, 15
+        let x = (yy_m_bzz9 * (point.x * point.y)).double(); // 16, 17, 18
+
+        ProjectivePoint { x, y, z }
+    }
+}
+
+/// Jacobian coordinates for curves with any 𝒂-coefficient.
+///
+/// Points `(X : Y : Z)` represent the affine point `(X/Z², Y/Z³)`. Doubling is
+/// considerably cheaper than with the complete formulas. Addition uses the
+/// unified formula from [Brier-Joye 2002], which also handles doubling, and
+/// the remaining exceptional cases (either input is the identity, or the
+/// formula is undefined for the inputs) are handled explicitly in constant
+/// time.
+///
+/// [Brier-Joye 2002]: https://link.springer.com/chapter/10.1007/3-540-45664-3_24
+pub struct EquationAIsGenericJacobian {}
+
+impl<C: PrimeCurveParams> PointArithmetic<C> for EquationAIsGenericJacobian {
+    /// Implements unified Jacobian addition.
+    fn add(lhs: &ProjectivePoint<C>, rhs: &ProjectivePoint<C>) -> ProjectivePoint<C> {
+        jacobian_add(lhs, rhs)
+    }
+
+    /// Implements unified Jacobian mixed addition.
+    fn add_mixed(lhs: &ProjectivePoint<C>, rhs: &AffinePoint<C>) -> ProjectivePoint<C> {
+        jacobian_add_mixed(lhs, rhs)
+    }
+
+    /// Implements Jacobian doubling for any `a` ("dbl-2007-bl" from the [EFD]).
+    ///
+    /// The identity is mapped to a point with `Z = 0`, i.e. the identity.
+    ///
+    /// [EFD]: https://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html
+    fn double(point: &ProjectivePoint<C>) -> ProjectivePoint<C> {
+        let xx = point.x.square();
+        let yy = point.y.square();
+        let yyyy = yy.square();
+        let zz = point.z.square();
+        let s = ((point.x + yy).square() - xx - yyyy).double();
+        let m = xx.double() + xx + C::EQUATION_A * zz.square();
+
+        let x = m.square() - s.double();
+        let y = m * (s - x) - yyyy.double().double().double();
+        let z = (point.y + point.z).square() - yy - zz;
+
+        ProjectivePoint { x, y, z }
+    }
+
+    fn to_affine(point: &ProjectivePoint<C>, zinv: &C::FieldElement) -> AffinePoint<C> {
+        jacobian_to_affine(point, zinv)
+    }
+
+    #[cfg(feature = "side-channel-hardening")]
+    fn rescale(point: &ProjectivePoint<C>, lambda: &C::FieldElement) -> ProjectivePoint<C> {
+        jacobian_rescale(point, lambda)
+    }
+
+    #[cfg(feature = "fault-countermeasures")]
+    fn is_on_curve(point: &ProjectivePoint<C>) -> Choice {
+        jacobian_is_on_curve(point)
+    }
+}
+
+/// Jacobian coordinates for curves with `a = -3`.
+///
+/// See [`EquationAIsGenericJacobian`] for the caveats of these formulas.
+pub struct EquationAIsMinusThreeJacobian {}
+
+impl<C: PrimeCurveParams> PointArithmetic<C> for EquationAIsMinusThreeJacobian {
+    /// Implements unified Jacobian addition.
+    fn add(lhs: &ProjectivePoint<C>, rhs: &ProjectivePoint<C>) -> ProjectivePoint<C> {
+        jacobian_add(lhs, rhs)
+    }
+
+    /// Implements unified Jacobian mixed addition.
+    fn add_mixed(lhs: &ProjectivePoint<C>, rhs: &AffinePoint<C>) -> ProjectivePoint<C> {
+        jacobian_add_mixed(lhs, rhs)
+    }
+
+    /// Implements Jacobian doubling for `a = -3` ("dbl-2001-b" from the [EFD]).
+    ///
+    /// The identity is mapped to a point with `Z = 0`, i.e. the identity.
+    ///
+    /// [EFD]: https://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-3.html
+    fn double(point: &ProjectivePoint<C>) -> ProjectivePoint<C> {
+        debug_assert_eq!(
+            C::EQUATION_A,
+            -C::FieldElement::from(3),
+            "this implementation is only valid for C::EQUATION_A = -3"
+        );
+
+        let delta = point.z.square();
+        let gamma = point.y.square();
+        let beta = point.x * gamma;
+        let alpha = (point.x - delta) * (point.x + delta);
+        let alpha = alpha.double() + alpha;
+        let beta4 = beta.double().double();
+
+        let x = alpha.square() - beta4.double();
+        let z = (point.y + point.z).square() - gamma - delta;
+        let y = alpha * (beta4 - x) - gamma.square().double().double().double();
+
+        ProjectivePoint { x, y, z }
+    }
+
+    fn to_affine(point: &ProjectivePoint<C>, zinv: &C::FieldElement) -> AffinePoint<C> {
+        jacobian_to_affine(point, zinv)
+    }
+
+    #[cfg(feature = "side-channel-hardening")]
+    fn rescale(point: &ProjectivePoint<C>, lambda: &C::FieldElement) -> ProjectivePoint<C> {
+        jacobian_rescale(point, lambda)
+    }
+
+    #[cfg(feature = "fault-countermeasures")]
+    fn is_on_curve(point: &ProjectivePoint<C>) -> Choice {
+        jacobian_is_on_curve(point)
+    }
+}
+
+/// Co-Z Jacobian coordinates for curves with any 𝒂-coefficient.
+///
+/// Points are represented and added as with [`EquationAIsGenericJacobian`],
+/// but multiplication by a scalar uses a Montgomery ladder with the co-Z
+/// formulas from [Goundar-Joye-Miyaji 2010]: both ladder points share their
+/// `Z` coordinate, so each step needs only an addition and a conjugate
+/// addition, rather than a doubling and an addition. The ladder processes one
+/// bit at a time without a table of precomputed multiples, and the sequence of
+/// operations does not depend on the scalar. It is faster than the complete
+/// formulas, but not than the windowed method of the Jacobian backends, which
+/// needs fewer additions.
+///
+/// The scalars for which the ladder is undefined are handled explicitly in
+/// constant time. With the `side-channel-hardening` feature, multiplication
+/// uses the randomised windowed method of the other backends instead.
+///
+/// [Goundar-Joye-Miyaji 2010]: https://eprint.iacr.org/2010/309
+pub struct EquationAIsGenericCoZ {}
+
+impl<C: PrimeCurveParams> PointArithmetic<C> for EquationAIsGenericCoZ {
+    fn add(lhs: &ProjectivePoint<C>, rhs: &ProjectivePoint<C>) -> ProjectivePoint<C> {
+        jacobian_add(lhs, rhs)
+    }
+
+    fn add_mixed(lhs: &ProjectivePoint<C>, rhs: &AffinePoint<C>) -> ProjectivePoint<C> {
+        jacobian_add_mixed(lhs, rhs)
+    }
+
+    fn double(point: &ProjectivePoint<C>) -> ProjectivePoint<C> {
+        EquationAIsGenericJacobian::double(point)
+    }
+
+    /// Implements the co-Z Montgomery ladder.
+    fn mul(point: &ProjectivePoint<C>, k: &Scalar<C>) -> ProjectivePoint<C> {
+        coz_mul::<C, Self>(point, k)
+    }
+
+    fn to_affine(point: &ProjectivePoint<C>, zinv: &C::FieldElement) -> AffinePoint<C> {
+        jacobian_to_affine(point, zinv)
+    }
+
+    #[cfg(feature = "side-channel-hardening")]
+    fn rescale(point: &ProjectivePoint<C>, lambda: &C::FieldElement) -> ProjectivePoint<C> {
+        jacobian_rescale(point, lambda)
+    }
+
+    #[cfg(feature = "fault-countermeasures")]
+    fn is_on_curve(point: &ProjectivePoint<C>) -> Choice {
+        jacobian_is_on_curve(point)
+    }
+}
+
+/// Co-Z Jacobian coordinates for curves with `a = -3`.
+///
+/// See [`EquationAIsGenericCoZ`] for the details of the scalar multiplication.
+pub struct EquationAIsMinusThreeCoZ {}
+
+impl<C: PrimeCurveParams> PointArithmetic<C> for EquationAIsMinusThreeCoZ {
+    fn add(lhs: &ProjectivePoint<C>, rhs: &ProjectivePoint<C>) -> ProjectivePoint<C> {
+        jacobian_add(lhs, rhs)
+    }
+
+    fn add_mixed(lhs: &ProjectivePoint<C>, rhs: &AffinePoint<C>) -> ProjectivePoint<C> {
+        jacobian_add_mixed(lhs, rhs)
+    }
+
+    fn double(point: &ProjectivePoint<C>) -> ProjectivePoint<C> {
+        EquationAIsMinusThreeJacobian::double(point)
+    }
+
+    /// Implements the co-Z Montgomery ladder.
+    fn mul(point: &ProjectivePoint<C>, k: &Scalar<C>) -> ProjectivePoint<C> {
+        coz_mul::<C, Self>(point, k)
+    }
+
+    fn to_affine(point: &ProjectivePoint<C>, zinv: &C::FieldElement) -> AffinePoint<C> {
+        jacobian_to_affine(point, zinv)
+    }
+
+    #[cfg(feature = "side-channel-hardening")]
+    fn rescale(point: &ProjectivePoint<C>, lambda: &C::FieldElement) -> ProjectivePoint<C> {
+        jacobian_rescale(point, lambda)
+    }
+
+    #[cfg(feature = "fault-countermeasures")]
+    fn is_on_curve(point: &ProjectivePoint<C>) -> Choice {
+        jacobian_is_on_curve(point)
+    }
+}
+
+/// Jacobian addition, handling the exceptional cases in constant time.
+fn jacobian_add<C: PrimeCurveParams>(
+    lhs: &ProjectivePoint<C>,
+    rhs: &ProjectivePoint<C>,
+) -> ProjectivePoint<C> {
+    let z1z1 = lhs.z.square();
+    let z2z2 = rhs.z.square();
+    let u1 = lhs.x * z2z2;
+    let u2 = rhs.x * z1z1;
+    let s1 = lhs.y * rhs.z * z2z2;
+    let s2 = rhs.y * lhs.z * z1z1;
+
+    let mut ret = jacobian_add_unified::<C>(u1, u2, s1, s2, lhs.z * rhs.z);
+    ret.conditional_assign(lhs, rhs.z.is_zero());
+    ret.conditional_assign(rhs, lhs.z.is_zero());
+    ret
+}
+
+/// Jacobian mixed addition, handling the exceptional cases in constant time.
+fn jacobian_add_mixed<C: PrimeCurveParams>(
+    lhs: &ProjectivePoint<C>,
+    rhs: &AffinePoint<C>,
+) -> ProjectivePoint<C> {
+    let z1z1 = lhs.z.square();
+    let u2 = rhs.x * z1z1;
+    let s2 = rhs.y * lhs.z * z1z1;
+
+    let mut ret = jacobian_add_unified::<C>(lhs.x, u2, lhs.y, s2, lhs.z);
+    ret.conditional_assign(&ProjectivePoint::from(*rhs), lhs.z.is_zero());
+    ret.conditional_assign(lhs, rhs.is_identity());
+    ret
+}
+
+/// Adds the points `(u1/z², s1/z³)` and `(u2/z², s2/z³)` using the unified
+/// formula from [Brier-Joye 2002], which is also correct for doubling.
+///
+/// The slope of the line through both points is `λ = m / (r z)`, where
+/// `m = u1² + u1 u2 + u2² + a z⁴` and `r = s1 + s2`. This is undefined if
+/// `s1 = -s2`, in which case the chord slope is used instead: `m = s1 - s2`
+/// and `r = u1 - u2`. If the points are also equal, then `lhs = -rhs` and
+/// `r = 0` results in the identity.
+///
+/// Neither point may be the identity.
+///
+/// [Brier-Joye 2002]: https://link.springer.com/chapter/10.1007/3-540-45664-3_24
+fn jacobian_add_unified<C: PrimeCurveParams>(
+    u1: C::FieldElement,
+    u2: C::FieldElement,
+    s1: C::FieldElement,
+    s2: C::FieldElement,
+    z: C::FieldElement,
+) -> ProjectivePoint<C> {
+    let t = u1 + u2;
+    let m = t.square() - u1 * u2 + C::EQUATION_A * z.square().square();
+    let r = s1 + s2;
+
+    let degenerate = r.is_zero();
+    let m = C::FieldElement::conditional_select(&m, &(s1 - s2), degenerate);
+    let r = C::FieldElement::conditional_select(&r, &(u1 - u2), degenerate);
+
+    let rr = r.square();
+    let x = m.square() - t * rr;
+    let y = m * (u1 * rr - x) - s1 * rr * r;
+
+    ProjectivePoint { x, y, z: r * z }
+}
+
+/// Maximum number of limbs of the scalars processed by the co-Z ladder, which
+/// is enough for curve orders of up to 576 bits (i.e. P-521).
+const MAX_LADDER_LIMBS: usize = 576 / Limb::BITS as usize + 1;
+
+/// Computes `[k] point` in constant time using the co-Z Montgomery ladder
+/// ("Montgomery ladder with co-Z addition formulæ" from
+/// [Goundar-Joye-Miyaji 2010]), with the doubling formula of `A`.
+///
+/// The ladder keeps `R0 = [m] point` and `R1 = [m + 1] point`, where `m` is
+/// the prefix of the scalar processed so far, and needs `R0 ≠ ±R1` and neither
+/// to be the identity. To fix the length of the scalar it processes
+/// `k' = k + n` or `k' = k + 2n`, whichever has one more bit than the order
+/// `n`. The prefixes of `k'` are then in `[1, n)`, except for the last, and
+/// only `k ∈ {-2, -1, 0, 1}` result in an exceptional case, which is
+/// corrected by conditionally selecting the result.
+///
+/// [Goundar-Joye-Miyaji 2010]: https://eprint.iacr.org/2010/309
+fn coz_mul<C, A>(point: &ProjectivePoint<C>, k: &Scalar<C>) -> ProjectivePoint<C>
+where
+    C: PrimeCurveParams,
+    A: PointArithmetic<C>,
+{
+    let n = C::ORDER;
+    let n = n.as_ref();
+    let len = n.len() + 1;
+    assert!(len <= MAX_LADDER_LIMBS, "curve order is too large");
+    let bits = C::ORDER.bits() as usize;
+
+    let mut k1 = [Limb::ZERO; MAX_LADDER_LIMBS];
+    k1[..n.len()].copy_from_slice(Into::<C::Uint>::into(*k).as_ref());
+    add_limbs(&mut k1[..len], n);
+    let mut k2 = k1;
+    add_limbs(&mut k2[..len], n);
+
+    // `k + n < 2n`, so if it doesn't have the top bit set then `k + 2n` does
+    let short = !Choice::from(window(&k1, bits, 1) as u8);
+    for (l1, l2) in k1.iter_mut().zip(k2.iter()) {
+        l1.conditional_assign(l2, short);
+    }
+
+    let (mut r1, mut r0, mut z) = coz_double_initial::<C>(point);
+
+    for i in (0..bits).rev() {
+        // Swap so that `r1` is `R_b` and `r0` is `R_(1-b)`, then compute
+        // `R_(1-b) = R_b + R_(1-b)` and `R_b = 2 R_b` as `(R_b + R_(1-b)) +
+        // (R_b - R_(1-b))`.
+        let swap = !Choice::from(window(&k1, i, 1) as u8);
+        coz_swap::<C>(&mut r0, &mut r1, swap);
+
+        let (sum, diff, z1) = coz_add_conjugate::<C>(r1, r0);
+        let (double, sum, z2) = coz_add::<C>(sum, diff);
+        (r0, r1) = (sum, double);
+        z = z * z1 * z2;
+
+        coz_swap::<C>(&mut r0, &mut r1, swap);
+    }
+
+    let mut ret = ProjectivePoint {
+        x: r0.0,
+        y: r0.1,
+        z,
+    };
+
+    let one = Scalar::<C>::ONE;
+    ret.conditional_assign(&ProjectivePoint::IDENTITY, k.is_zero());
+    ret.conditional_assign(point, k.ct_eq(&one));
+    ret.conditional_assign(&-*point, k.ct_eq(&-one));
+    ret.conditional_assign(&-A::double(point), k.ct_eq(&-one.double()));
+    ret
+}
+
+/// Computes `k += n` for a little-endian integer `k` which is at least as long
+/// as `n`, discarding the carry.
+fn add_limbs(k: &mut [Limb], n: &[Limb]) {
+    let mut carry = Limb::ZERO;
+
+    for (i, l) in k.iter_mut().enumerate() {
+        (*l, carry) = l.adc(n.get(i).copied().unwrap_or(Limb::ZERO), carry);
+    }
+}
+
+/// Affine coordinates of a point relative to an implicit shared `Z`
+/// coordinate.
+type CoZ<C> = (
+    <C as PrimeCurveParams>::FieldElement,
+    <C as PrimeCurveParams>::FieldElement,
+);
+
+/// Conditionally swaps two points with a shared `Z` coordinate.
+fn coz_swap<C: PrimeCurveParams>(a: &mut CoZ<C>, b: &mut CoZ<C>, choice: Choice) {
+    C::FieldElement::conditional_swap(&mut a.0, &mut b.0, choice);
+    C::FieldElement::conditional_swap(&mut a.1, &mut b.1, choice);
+}
+
+/// Returns `(2P, P, Z)`, where both points have the `Z` coordinate `Z`
+/// ("XYCZ-IDBL").
+fn coz_double_initial<C: PrimeCurveParams>(
+    point: &ProjectivePoint<C>,
+) -> (CoZ<C>, CoZ<C>, C::FieldElement) {
+    let xx = point.x.square();
+    let yy = point.y.square();
+    let yyyy = yy.square();
+    let zz = point.z.square();
+    let s = ((point.x + yy).square() - xx - yyyy).double();
+    let m = xx.double() + xx + C::EQUATION_A * zz.square();
+    let yyyy8 = yyyy.double().double().double();
+
+    let x = m.square() - s.double();
+    let y = m * (s - x) - yyyy8;
+    let z = (point.y + point.z).square() - yy - zz;
+
+    ((x, y), (s, yyyy8), z)
+}
+
+/// Returns `(P + Q, P)` with a new shared `Z` coordinate, which is the previous
+/// one multiplied by the returned factor ("XYCZ-ADD").
+fn coz_add<C: PrimeCurveParams>(p: CoZ<C>, q: CoZ<C>) -> (CoZ<C>, CoZ<C>, C::FieldElement) {
+    let dx = p.0 - q.0;
+    let c = dx.square();
+    let w1 = p.0 * c;
+    let w2 = q.0 * c;
+    let a1 = p.1 * (w1 - w2);
+
+    let dy = p.1 - q.1;
+    let x = dy.square() - w1 - w2;
+    let y = dy * (w1 - x) - a1;
+
+    ((x, y), (w1, a1), dx)
+}
+
+/// Returns `(P + Q, P - Q)` with a new shared `Z` coordinate, which is the
+/// previous one multiplied by the returned factor ("XYCZ-ADDC").
+fn coz_add_conjugate<C: PrimeCurveParams>(
+    p: CoZ<C>,
+    q: CoZ<C>,
+) -> (CoZ<C>, CoZ<C>, C::FieldElement) {
+    let dx = p.0 - q.0;
+    let c = dx.square();
+    let w1 = p.0 * c;
+    let w2 = q.0 * c;
+    let a1 = p.1 * (w1 - w2);
+
+    let dy = p.1 - q.1;
+    let x = dy.square() - w1 - w2;
+    let y = dy * (w1 - x) - a1;
+
+    let sy = p.1 + q.1;
+    let x_conj = sy.square() - w1 - w2;
+    let y_conj = sy * (w1 - x_conj) - a1;
+
+    ((x, y), (x_conj, y_conj), dx)
+}
+
+/// Converts Jacobian coordinates to affine: `(X/Z², Y/Z³)`.
+fn jacobian_to_affine<C: PrimeCurveParams>(
+    point: &ProjectivePoint<C>,
+    zinv: &C::FieldElement,
+) -> AffinePoint<C> {
+    let zinv2 = zinv.square();
+
+    AffinePoint {
+        x: point.x * zinv2,
+        y: point.y * zinv2 * zinv,
+        infinity: 0,
+    }
+}
+
+/// Rescales Jacobian coordinates: `(λ²X : λ³Y : λZ)`.
+#[cfg(feature = "side-channel-hardening")]
+fn jacobian_rescale<C: PrimeCurveParams>(
+    point: &ProjectivePoint<C>,
+    lambda: &C::FieldElement,
+) -> ProjectivePoint<C> {
+    let lambda2 = lambda.square();
+
+    ProjectivePoint {
+        x: point.x * lambda2,
+        y: point.y * lambda2 * lambda,
+        z: point.z * lambda,
+    }
+}
+
+/// Checks the curve equation in Jacobian coordinates:
+/// `Y² = X³ + aXZ⁴ + bZ⁶`.
+#[cfg(feature = "fault-countermeasures")]
+fn jacobian_is_on_curve<C: PrimeCurveParams>(point: &ProjectivePoint<C>) -> Choice {
+    let zz = point.z.square();
+    let z4 = zz.square();
+    let lhs = point.y.square();
+    let rhs = (point.x.square() + C::EQUATION_A * z4) * point.x + C::EQUATION_B * z4 * zz;
+
+    point.z.is_zero() | lhs.ct_eq(&rhs)
+}
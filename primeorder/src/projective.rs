@@ -2,7 +2,9 @@
 
 #![allow(clippy::needless_range_loop, clippy::op_ref)]
 
-use crate::{point_arithmetic::PointArithmetic, AffinePoint, Field, PrimeCurveParams};
+use crate::{
+    mul::lincomb_vartime, point_arithmetic::PointArithmetic, AffinePoint, Field, PrimeCurveParams,
+};
 use core::{
     borrow::Borrow,
     iter::Sum,
@@ -10,7 +12,6 @@ use core::{
 };
 use elliptic_curve::{
     array::ArraySize,
-    bigint::{ArrayEncoding, Bounded},
     group::{
         self,
         cofactor::CofactorGroup,
@@ -30,9 +31,18 @@ use elliptic_curve::{
 };
 
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use {alloc::vec::Vec, elliptic_curve::bigint::BitOps};
+
+#[cfg(feature = "side-channel-hardening")]
+use elliptic_curve::rand_core::CryptoRngCore;
 
 /// Point on a Weierstrass curve in projective coordinates.
+///
+/// The coordinates are homogeneous `(X : Y : Z)` unless the curve selects a
+/// Jacobian [`PointArithmetic`] backend, in which case they are Jacobian. In
+/// either case, points with `Z = 0` are the identity.
+///
+/// [`PointArithmetic`]: PrimeCurveParams::PointArithmetic
 #[derive(Clone, Copy, Debug)]
 pub struct ProjectivePoint<C: PrimeCurveParams> {
     pub(crate) x: C::FieldElement,
@@ -58,7 +68,10 @@ where
         z: C::FieldElement::ONE,
     };
 
-    /// Get point from projective coordinates
+    /// Get point from projective coordinates.
+    ///
+    /// The coordinates are interpreted according to the curve's
+    /// [`PointArithmetic`][`PrimeCurveParams::PointArithmetic`] backend.
     pub fn from_projective_coordinates(
         x: C::FieldElement,
         y: C::FieldElement,
@@ -75,11 +88,7 @@ where
     }
 
     pub(super) fn to_affine_internal(self, zinv: C::FieldElement) -> AffinePoint<C> {
-        AffinePoint {
-            x: self.x * &zinv,
-            y: self.y * &zinv,
-            infinity: 0,
-        }
+        C::PointArithmetic::to_affine(&self, &zinv)
     }
 
     /// Returns `-self`.
@@ -116,49 +125,146 @@ where
     where
         Self: Double,
     {
-        let k = Into::<C::Uint>::into(*k).to_le_byte_array();
-
-        let mut pc = [Self::default(); 16];
-        pc[0] = Self::IDENTITY;
-        pc[1] = *self;
-
-        for i in 2..16 {
-            pc[i] = if i % 2 == 0 {
-                Double::double(&pc[i / 2])
-            } else {
-                pc[i - 1].add(self)
-            };
-        }
-
-        let mut q = Self::IDENTITY;
-        let mut pos = C::Uint::BITS as usize - 4;
-
-        loop {
-            let slot = (k[pos >> 3] >> (pos & 7)) & 0xf;
-
-            let mut t = ProjectivePoint::IDENTITY;
+        C::PointArithmetic::mul(self, k).fault_check()
+    }
+
+    /// Returns `[k] self`, computed in constant time and randomised on every
+    /// call using `rng` to protect `k` against differential power analysis
+    /// and template attacks.
+    ///
+    /// The scalar is replaced by `k + r * n`, where `n` is the group order and
+    /// `r` is a random 64-bit integer, and the projective coordinates of `self`
+    /// are multiplied by a random nonzero field element, so neither the digits
+    /// processed nor the intermediate points repeat between calls.
+    ///
+    /// The blinded scalar is not reduced, so this doesn't use the point
+    /// arithmetic backend's multiplication or an endomorphism.
+    #[cfg(feature = "side-channel-hardening")]
+    pub fn mul_blinded(&self, k: &Scalar<C>, rng: &mut impl CryptoRngCore) -> Self {
+        crate::mul::mul_blinded(self, k, rng).fault_check()
+    }
+
+    /// Returns `self`, or the identity if `self` is not on the curve.
+    ///
+    /// With the `fault-countermeasures` feature, this is applied to the result
+    /// of every multiplication by a secret scalar, so that a point corrupted
+    /// by an injected fault is never released. The identity is rejected by
+    /// the signature schemes, e.g. it results in `r = 0` for ECDSA, and by the
+    /// Diffie-Hellman functions in `ecdh`, which return an error
+    /// rather than a shared secret of all zeroes. Without the feature, this is
+    /// a no-op.
+    #[inline(always)]
+    fn fault_check(self) -> Self {
+        #[cfg(feature = "fault-countermeasures")]
+        return Self::conditional_select(
+            &Self::IDENTITY,
+            &self,
+            C::PointArithmetic::is_on_curve(&self),
+        );
 
-            for i in 1..16 {
-                t.conditional_assign(
-                    &pc[i],
-                    Choice::from(((slot as usize ^ i).wrapping_sub(1) >> 8) as u8 & 1),
-                );
-            }
+        #[cfg(not(feature = "fault-countermeasures"))]
+        self
+    }
+
+    /// Returns `[k] self`, computed using width-5 NAF.
+    ///
+    /// <div class="warning">
+    /// This operation is variable time with respect to both the point and
+    /// the scalar. It must only be used with public inputs, e.g. when
+    /// verifying signatures.
+    /// </div>
+    pub fn mul_vartime(&self, k: &Scalar<C>) -> Self {
+        lincomb_vartime(&[(*self, *k)])
+    }
+
+    /// Computes `x1 * k1 + ... + xn * kn` using interleaved width-5 NAF.
+    ///
+    /// <div class="warning">
+    /// This operation is variable time with respect to both the points and
+    /// the scalars. It must only be used with public inputs, e.g. when
+    /// verifying signatures.
+    /// </div>
+    pub fn lincomb_vartime(points_and_scalars: &[(Self, Scalar<C>)]) -> Self {
+        lincomb_vartime(points_and_scalars)
+    }
+
+    /// Computes the multi-scalar multiplication `x1 * k1 + ... + xn * kn`
+    /// in constant time.
+    ///
+    /// Uses the bucket method (a.k.a. Pippenger's algorithm), which is
+    /// considerably faster than [`LinearCombination`] for large numbers of
+    /// terms. Fewer than [`pippenger::MIN_TERMS`] terms are computed with
+    /// [`LinearCombination`] instead.
+    ///
+    /// # Panics
+    ///
+    /// If `points` and `scalars` have different lengths.
+    ///
+    /// [`pippenger::MIN_TERMS`]: crate::pippenger::MIN_TERMS
+    #[cfg(feature = "alloc")]
+    pub fn msm(points: &[Self], scalars: &[Scalar<C>]) -> Self {
+        assert_eq!(points.len(), scalars.len(), "length mismatch");
 
-            q = q.add(&t);
+        if points.len() < crate::pippenger::MIN_TERMS {
+            return C::lincomb(&terms(points, scalars));
+        }
 
-            if pos == 0 {
-                break;
-            }
+        crate::pippenger::msm(points, &uints::<C>(scalars), C::ORDER.bits() as usize)
+    }
+
+    /// Computes the multi-scalar multiplication `x1 * k1 + ... + xn * kn`
+    /// using the bucket method (a.k.a. Pippenger's algorithm).
+    ///
+    /// Fewer than [`pippenger::MIN_TERMS`] terms are computed with
+    /// [`ProjectivePoint::lincomb_vartime`] instead.
+    ///
+    /// <div class="warning">
+    /// This operation is variable time with respect to both the points and
+    /// the scalars. It must only be used with public inputs.
+    /// </div>
+    ///
+    /// # Panics
+    ///
+    /// If `points` and `scalars` have different lengths.
+    ///
+    /// [`pippenger::MIN_TERMS`]: crate::pippenger::MIN_TERMS
+    #[cfg(feature = "alloc")]
+    pub fn msm_vartime(points: &[Self], scalars: &[Scalar<C>]) -> Self {
+        assert_eq!(points.len(), scalars.len(), "length mismatch");
 
-            q = Double::double(&Double::double(&Double::double(&Double::double(&q))));
-            pos -= 4;
+        if points.len() < crate::pippenger::MIN_TERMS {
+            return lincomb_vartime(&terms(points, scalars));
         }
 
-        q
+        crate::pippenger::msm_vartime(points, &uints::<C>(scalars), C::ORDER.bits() as usize)
     }
 }
 
+/// Pair up points and scalars for [`LinearCombination`].
+#[cfg(feature = "alloc")]
+fn terms<C>(
+    points: &[ProjectivePoint<C>],
+    scalars: &[Scalar<C>],
+) -> Vec<(ProjectivePoint<C>, Scalar<C>)>
+where
+    C: PrimeCurveParams,
+{
+    points
+        .iter()
+        .copied()
+        .zip(scalars.iter().copied())
+        .collect()
+}
+
+/// Convert scalars to integers for [`pippenger`](crate::pippenger).
+#[cfg(feature = "alloc")]
+fn uints<C>(scalars: &[Scalar<C>]) -> Vec<C::Uint>
+where
+    C: PrimeCurveParams,
+{
+    scalars.iter().map(|&k| Into::<C::Uint>::into(k)).collect()
+}
+
 impl<C> CofactorGroup for ProjectivePoint<C>
 where
     Self: Double,
@@ -424,7 +530,9 @@ where
     Self: Double,
     C: PrimeCurveParams,
 {
-    // TODO(tarcieri): optimized implementation
+    fn lincomb(points_and_scalars: &[(Self, Scalar<C>)]) -> Self {
+        C::lincomb(points_and_scalars)
+    }
 }
 
 impl<C, const N: usize> LinearCombination<[(Self, Scalar<C>); N]> for ProjectivePoint<C>
@@ -432,7 +540,9 @@ where
     Self: Double,
     C: PrimeCurveParams,
 {
-    // TODO(tarcieri): optimized implementation
+    fn lincomb(points_and_scalars: &[(Self, Scalar<C>); N]) -> Self {
+        C::lincomb(points_and_scalars)
+    }
 }
 
 impl<C> MulByGenerator for ProjectivePoint<C>
@@ -441,8 +551,7 @@ where
     C: PrimeCurveParams,
 {
     fn mul_by_generator(scalar: &Self::Scalar) -> Self {
-        // TODO(tarcieri): precomputed basepoint tables
-        Self::generator() * scalar
+        C::mul_by_generator(scalar).fault_check()
     }
 }
 
@@ -0,0 +1,331 @@
+//! Support for explicitly specified elliptic curve domain parameters.
+//!
+//! [RFC5480 § 2.1.1] forbids `specifiedCurve` in PKIX, but some encoders
+//! (e.g. OpenSSL with `-param_enc explicit`) still emit keys which carry the
+//! full [X9.62] `SpecifiedECDomain` instead of a `namedCurve` OID:
+//!
+//! ```text
+//! SpecifiedECDomain ::= SEQUENCE {
+//!     version   SpecifiedECDomainVersion(ecdpVer1 | ecdpVer2 | ecdpVer3, ...),
+//!     fieldID   FieldID {{FieldTypes}},
+//!     curve     Curve,
+//!     base      ECPoint,
+//!     order     INTEGER,
+//!     cofactor  INTEGER OPTIONAL,
+//!     hash      HashAlgorithm OPTIONAL,
+//!     ...
+//! }
+//! ```
+//!
+//! The functions in this module accept such keys as long as the parameters
+//! are identical to those of the named curve `C`. [`DecodePublicKey`] and
+//! [`DecodePrivateKey`] are implemented for [`PublicKey`] and [`SecretKey`] by
+//! `elliptic-curve` and only accept a `namedCurve`, so keys with explicit
+//! parameters are decoded as the named curve's types with the
+//! [`DecodeSpecifiedPublicKey`] and [`DecodeSpecifiedPrivateKey`] traits.
+//!
+//! [RFC5480 § 2.1.1]: https://datatracker.ietf.org/doc/html/rfc5480#section-2.1.1
+//! [X9.62]: https://www.x9.org/
+//! [`DecodePublicKey`]: elliptic_curve::pkcs8::DecodePublicKey
+//! [`DecodePrivateKey`]: elliptic_curve::pkcs8::DecodePrivateKey
+
+use crate::{Field, PrimeCurveParams, PrimeField};
+use elliptic_curve::{
+    group::{Curve as _, Group},
+    pkcs8::{
+        self,
+        der::{
+            self,
+            asn1::{AnyRef, BitStringRef, ObjectIdentifier, OctetStringRef, UintRef},
+            Decode, DecodeValue, FixedTag, Header, Reader, Tag, Tagged,
+        },
+        spki, AssociatedOid, PrivateKeyInfo, SubjectPublicKeyInfoRef,
+    },
+    sec1::{EncodedPoint, FromEncodedPoint, ModulusSize, ToEncodedPoint, ValidatePublicKey},
+    AffinePoint, CurveArithmetic, FieldBytes, FieldBytesEncoding, FieldBytesSize, PublicKey,
+    SecretKey, ALGORITHM_OID,
+};
+
+#[cfg(feature = "ecdsa")]
+use {
+    ecdsa_core::{EcdsaCurve, SignatureSize, SigningKey, VerifyingKey},
+    elliptic_curve::{array::ArraySize, ops::Invert, subtle::CtOption, Scalar},
+};
+
+/// `prime-field` field type OID (X9.62).
+const PRIME_FIELD_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.1.1");
+
+/// Explicit elliptic curve domain parameters over a prime field.
+///
+/// Integers are stored big endian with leading zeros stripped, so they can be
+/// compared byte-for-byte.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SpecifiedCurve<'a> {
+    /// Field modulus `p`.
+    pub p: &'a [u8],
+
+    /// Curve coefficient `a`.
+    pub a: &'a [u8],
+
+    /// Curve coefficient `b`.
+    pub b: &'a [u8],
+
+    /// SEC1-encoded base point `G`.
+    pub base: &'a [u8],
+
+    /// Order `n` of the base point.
+    pub order: &'a [u8],
+
+    /// Cofactor `h`, if present.
+    pub cofactor: Option<&'a [u8]>,
+}
+
+impl<'a> SpecifiedCurve<'a> {
+    /// Do these parameters describe the curve `C`?
+    ///
+    /// Compares `p`, `a`, `b`, `G` and `n` against the constants of `C`, and
+    /// requires the cofactor to be absent or `1`.
+    pub fn is_curve<C>(&self) -> bool
+    where
+        C: SpecifiedCurveParams,
+        FieldBytesSize<C>: ModulusSize,
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    {
+        let (p, a, b) = C::field_params();
+
+        let generator = C::ProjectivePoint::generator()
+            .to_affine()
+            .to_encoded_point(false);
+
+        let base_matches = EncodedPoint::<C>::from_bytes(self.base)
+            .ok()
+            .and_then(|base| {
+                Option::<AffinePoint<C>>::from(AffinePoint::<C>::from_encoded_point(&base))
+            })
+            .is_some_and(|base| base.to_encoded_point(false) == generator);
+
+        base_matches
+            && self.p == strip_leading_zeros(&p)
+            && strip_leading_zeros(self.a) == strip_leading_zeros(&a)
+            && strip_leading_zeros(self.b) == strip_leading_zeros(&b)
+            && self.order == strip_leading_zeros(&C::ORDER.encode_field_bytes())
+            && self.cofactor.map_or(true, |h| h == [1])
+    }
+}
+
+impl<'a> DecodeValue<'a> for SpecifiedCurve<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            let version = u8::decode(reader)?;
+            if !(1..=3).contains(&version) {
+                return Err(Tag::Integer.value_error());
+            }
+
+            let p = reader.sequence(|reader| {
+                if ObjectIdentifier::decode(reader)? != PRIME_FIELD_OID {
+                    return Err(Tag::ObjectIdentifier.value_error());
+                }
+                UintRef::decode(reader)
+            })?;
+
+            let (a, b) = reader.sequence(|reader| {
+                let a = OctetStringRef::decode(reader)?;
+                let b = OctetStringRef::decode(reader)?;
+                let _seed = Option::<BitStringRef<'_>>::decode(reader)?;
+                Ok((a, b))
+            })?;
+
+            let base = OctetStringRef::decode(reader)?;
+            let order = UintRef::decode(reader)?;
+            let cofactor = Option::<UintRef<'_>>::decode(reader)?;
+
+            // Skip the optional `hash` and any future extensions.
+            while !reader.is_finished() {
+                reader.tlv_bytes()?;
+            }
+
+            Ok(Self {
+                p: p.as_bytes(),
+                a: a.as_bytes(),
+                b: b.as_bytes(),
+                base: base.as_bytes(),
+                order: order.as_bytes(),
+                cofactor: cofactor.map(|h| h.as_bytes()),
+            })
+        })
+    }
+}
+
+impl FixedTag for SpecifiedCurve<'_> {
+    const TAG: Tag = Tag::Sequence;
+}
+
+/// Domain parameters which aren't otherwise exposed by [`CurveArithmetic`],
+/// used to recognise a named curve from its [`SpecifiedCurve`] encoding.
+///
+/// Implemented for all curves which impl [`PrimeCurveParams`].
+pub trait SpecifiedCurveParams: AssociatedOid + CurveArithmetic {
+    /// Big endian encodings of the field modulus `p` and the curve equation
+    /// coefficients `a` and `b`.
+    fn field_params() -> (FieldBytes<Self>, FieldBytes<Self>, FieldBytes<Self>);
+}
+
+impl<C> SpecifiedCurveParams for C
+where
+    C: PrimeCurveParams + AssociatedOid,
+{
+    fn field_params() -> (FieldBytes<C>, FieldBytes<C>, FieldBytes<C>) {
+        // `p - 1` is the largest representable field element.
+        let mut p = (-C::FieldElement::ONE).to_repr();
+        for byte in p.iter_mut().rev() {
+            let (sum, carry) = byte.overflowing_add(1);
+            *byte = sum;
+            if !carry {
+                break;
+            }
+        }
+
+        (p, C::EQUATION_A.to_repr(), C::EQUATION_B.to_repr())
+    }
+}
+
+/// Check the `parameters` of an `id-ecPublicKey` algorithm identifier,
+/// accepting either the `namedCurve` OID of `C` or matching explicit
+/// parameters.
+pub fn check_parameters<C>(parameters: Option<AnyRef<'_>>) -> spki::Result<()>
+where
+    C: SpecifiedCurveParams,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+{
+    let parameters = parameters.ok_or(spki::Error::AlgorithmParametersMissing)?;
+
+    match parameters.tag() {
+        Tag::ObjectIdentifier => {
+            let oid = parameters.decode_as::<ObjectIdentifier>()?;
+            if oid == C::OID {
+                Ok(())
+            } else {
+                Err(spki::Error::OidUnknown { oid })
+            }
+        }
+        Tag::Sequence => {
+            if parameters
+                .decode_as::<SpecifiedCurve<'_>>()?
+                .is_curve::<C>()
+            {
+                Ok(())
+            } else {
+                Err(spki::Error::KeyMalformed)
+            }
+        }
+        tag => Err(tag.value_error().into()),
+    }
+}
+
+/// Decode a DER-encoded SPKI public key for the curve `C`, accepting either a
+/// `namedCurve` OID or matching explicit domain parameters.
+pub fn public_key_from_der<C>(bytes: &[u8]) -> spki::Result<PublicKey<C>>
+where
+    C: SpecifiedCurveParams,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+{
+    let spki = SubjectPublicKeyInfoRef::from_der(bytes)?;
+    spki.algorithm.assert_algorithm_oid(ALGORITHM_OID)?;
+    check_parameters::<C>(spki.algorithm.parameters)?;
+
+    let public_key_bytes = spki
+        .subject_public_key
+        .as_bytes()
+        .ok_or_else(|| Tag::BitString.value_error())?;
+
+    PublicKey::from_sec1_bytes(public_key_bytes).map_err(|_| spki::Error::KeyMalformed)
+}
+
+/// Decode a DER-encoded PKCS#8 secret key for the curve `C`, accepting
+/// either a `namedCurve` OID or matching explicit domain parameters.
+///
+/// The inner SEC1 `ECPrivateKey` may only carry a `namedCurve`, if any.
+pub fn secret_key_from_pkcs8_der<C>(bytes: &[u8]) -> pkcs8::Result<SecretKey<C>>
+where
+    C: SpecifiedCurveParams + ValidatePublicKey,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+{
+    let private_key_info = PrivateKeyInfo::try_from(bytes)?;
+    private_key_info
+        .algorithm
+        .assert_algorithm_oid(ALGORITHM_OID)?;
+    check_parameters::<C>(private_key_info.algorithm.parameters)?;
+
+    SecretKey::from_sec1_der(private_key_info.private_key).map_err(|_| pkcs8::Error::KeyMalformed)
+}
+
+/// Decode a public key from a DER-encoded SPKI document, accepting either a
+/// `namedCurve` OID or matching explicit domain parameters.
+pub trait DecodeSpecifiedPublicKey: Sized {
+    /// Deserialize from a DER-encoded SPKI public key.
+    fn from_specified_public_key_der(bytes: &[u8]) -> spki::Result<Self>;
+}
+
+/// Decode a secret key from a DER-encoded PKCS#8 document, accepting either a
+/// `namedCurve` OID or matching explicit domain parameters.
+pub trait DecodeSpecifiedPrivateKey: Sized {
+    /// Deserialize from a DER-encoded PKCS#8 private key.
+    fn from_specified_pkcs8_der(bytes: &[u8]) -> pkcs8::Result<Self>;
+}
+
+impl<C> DecodeSpecifiedPublicKey for PublicKey<C>
+where
+    C: SpecifiedCurveParams,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+{
+    fn from_specified_public_key_der(bytes: &[u8]) -> spki::Result<Self> {
+        public_key_from_der(bytes)
+    }
+}
+
+impl<C> DecodeSpecifiedPrivateKey for SecretKey<C>
+where
+    C: SpecifiedCurveParams + ValidatePublicKey,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+{
+    fn from_specified_pkcs8_der(bytes: &[u8]) -> pkcs8::Result<Self> {
+        secret_key_from_pkcs8_der(bytes)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl<C> DecodeSpecifiedPublicKey for VerifyingKey<C>
+where
+    C: SpecifiedCurveParams + EcdsaCurve,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+{
+    fn from_specified_public_key_der(bytes: &[u8]) -> spki::Result<Self> {
+        public_key_from_der(bytes).map(Into::into)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl<C> DecodeSpecifiedPrivateKey for SigningKey<C>
+where
+    C: SpecifiedCurveParams + ValidatePublicKey + EcdsaCurve,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>>,
+    SignatureSize<C>: ArraySize,
+{
+    fn from_specified_pkcs8_der(bytes: &[u8]) -> pkcs8::Result<Self> {
+        secret_key_from_pkcs8_der(bytes).map(Into::into)
+    }
+}
+
+/// Strip leading zeros from a big endian integer.
+fn strip_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let len = bytes.iter().take_while(|&&b| b == 0).count();
+    &bytes[len..]
+}
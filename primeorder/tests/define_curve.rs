@@ -0,0 +1,268 @@
+//! Tests for curves defined with `primeorder::define_curve!`, using P-256 as
+//! a reference, and Pallas as a curve whose order is below `2^(W - 1)`.
+
+mod redefined {
+    use primeorder::elliptic_curve::{bigint::U256, consts::U32};
+
+    primeorder::define_curve! {
+        /// NIST P-256 defined from its domain parameters.
+        pub struct RedefinedP256;
+        uint: U256;
+        field_bytes_size: U32;
+        oid: "1.2.840.10045.3.1.7";
+        p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+        n: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+        a: "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc";
+        b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b";
+        gx: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296";
+        gy: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5";
+    }
+
+    mod field {
+        use super::FieldElement;
+        use primeorder::elliptic_curve::ff::PrimeField;
+        use primeorder::{
+            impl_field_identity_tests, impl_field_invert_tests, impl_field_pow_tests,
+            impl_field_sqrt_ratio_tests, impl_field_sqrt_tests, impl_primefield_tests,
+        };
+
+        /// t = (modulus - 1) >> S
+        const T: [u64; 4] = [
+            0xffffffffffffffff,
+            0x000000007fffffff,
+            0x8000000000000000,
+            0x7fffffff80000000,
+        ];
+
+        impl_field_identity_tests!(FieldElement);
+        impl_field_invert_tests!(FieldElement);
+        impl_field_sqrt_tests!(FieldElement);
+        impl_field_sqrt_ratio_tests!(FieldElement);
+        impl_field_pow_tests!(FieldElement);
+        impl_primefield_tests!(FieldElement, T);
+    }
+
+    mod scalar {
+        use super::Scalar;
+        use primeorder::elliptic_curve::ff::PrimeField;
+        use primeorder::{
+            impl_field_identity_tests, impl_field_invert_tests, impl_field_pow_tests,
+            impl_field_sqrt_ratio_tests, impl_field_sqrt_tests, impl_primefield_tests,
+        };
+
+        /// t = (modulus - 1) >> S
+        const T: [u64; 4] = [
+            0x4f3b9cac2fc63255,
+            0xfbce6faada7179e8,
+            0x0fffffffffffffff,
+            0x0ffffffff0000000,
+        ];
+
+        impl_field_identity_tests!(Scalar);
+        impl_field_invert_tests!(Scalar);
+        impl_field_sqrt_tests!(Scalar);
+        impl_field_sqrt_ratio_tests!(Scalar);
+        impl_field_pow_tests!(Scalar);
+        impl_primefield_tests!(Scalar, T);
+    }
+
+    mod projective {
+        use super::{AffinePoint, ProjectivePoint, Scalar};
+        use p256::test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS};
+        use primeorder::elliptic_curve::{
+            group::ff::PrimeField,
+            sec1::{self, ToEncodedPoint},
+        };
+        use primeorder::{impl_projective_arithmetic_tests, Double};
+
+        impl_projective_arithmetic_tests!(
+            AffinePoint,
+            ProjectivePoint,
+            Scalar,
+            ADD_TEST_VECTORS,
+            MUL_TEST_VECTORS
+        );
+    }
+}
+
+mod pallas {
+    use primeorder::elliptic_curve::{bigint::U256, consts::U32};
+
+    primeorder::define_curve! {
+        /// The Pallas curve, whose order is not in the workspace and below
+        /// `2^255`.
+        pub struct Pallas;
+        uint: U256;
+        field_bytes_size: U32;
+        p: "40000000000000000000000000000000224698fc094cf91b992d30ed00000001";
+        n: "40000000000000000000000000000000224698fc0994a8dd8c46eb2100000001";
+        a: "0000000000000000000000000000000000000000000000000000000000000000";
+        b: "0000000000000000000000000000000000000000000000000000000000000005";
+        gx: "40000000000000000000000000000000224698fc094cf91b992d30ed00000000";
+        gy: "0000000000000000000000000000000000000000000000000000000000000002";
+        point_arithmetic: primeorder::point_arithmetic::EquationAIsZero;
+    }
+
+    mod field {
+        use super::FieldElement;
+        use primeorder::elliptic_curve::ff::PrimeField;
+        use primeorder::{
+            impl_field_identity_tests, impl_field_invert_tests, impl_field_pow_tests,
+            impl_field_sqrt_ratio_tests, impl_field_sqrt_tests, impl_primefield_tests,
+        };
+
+        /// t = (modulus - 1) >> S
+        const T: [u64; 4] = [
+            0x094cf91b992d30ed,
+            0x00000000224698fc,
+            0x0000000000000000,
+            0x0000000040000000,
+        ];
+
+        impl_field_identity_tests!(FieldElement);
+        impl_field_invert_tests!(FieldElement);
+        impl_field_sqrt_tests!(FieldElement);
+        impl_field_sqrt_ratio_tests!(FieldElement);
+        impl_field_pow_tests!(FieldElement);
+        impl_primefield_tests!(FieldElement, T);
+    }
+
+    mod scalar {
+        use super::Scalar;
+        use primeorder::elliptic_curve::ff::PrimeField;
+        use primeorder::{
+            impl_field_identity_tests, impl_field_invert_tests, impl_field_pow_tests,
+            impl_field_sqrt_ratio_tests, impl_field_sqrt_tests, impl_primefield_tests,
+        };
+
+        /// t = (modulus - 1) >> S
+        const T: [u64; 4] = [
+            0x0994a8dd8c46eb21,
+            0x00000000224698fc,
+            0x0000000000000000,
+            0x0000000040000000,
+        ];
+
+        impl_field_identity_tests!(Scalar);
+        impl_field_invert_tests!(Scalar);
+        impl_field_sqrt_tests!(Scalar);
+        impl_field_sqrt_ratio_tests!(Scalar);
+        impl_field_pow_tests!(Scalar);
+        impl_primefield_tests!(Scalar, T);
+    }
+}
+
+use p256::{NistP256, ProjectivePoint, Scalar};
+use pallas::Pallas;
+use primeorder::elliptic_curve::{
+    bigint::U256,
+    group::Group,
+    ops::Reduce,
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+    Curve, PrimeField,
+};
+use redefined::RedefinedP256;
+
+#[test]
+fn order_matches() {
+    assert_eq!(RedefinedP256::ORDER, NistP256::ORDER);
+}
+
+#[test]
+fn primefield_constants_are_consistent() {
+    assert_eq!(redefined::FieldElement::NUM_BITS, 256);
+    assert_eq!(redefined::FieldElement::S, 1);
+    assert_eq!(redefined::Scalar::NUM_BITS, 256);
+    assert_eq!(redefined::Scalar::S, 4);
+}
+
+#[test]
+fn mul_matches_p256() {
+    let mut k = Scalar::from(0x1234_5678u64);
+
+    for _ in 0..16 {
+        let k_redefined = redefined::Scalar::from_repr(k.to_repr()).unwrap();
+
+        let expected = (ProjectivePoint::GENERATOR * k).to_affine();
+        let actual = (redefined::ProjectivePoint::GENERATOR * k_redefined).to_affine();
+        assert_eq!(
+            expected.to_encoded_point(false).as_bytes(),
+            actual.to_encoded_point(false).as_bytes()
+        );
+
+        k = k.square() + Scalar::ONE;
+    }
+}
+
+#[test]
+fn scalar_arithmetic_matches_p256() {
+    let mut a = Scalar::from(0xdead_beefu64);
+    let mut b = Scalar::from(0xcafe_f00du64);
+
+    for _ in 0..16 {
+        let a_redefined = redefined::Scalar::from_repr(a.to_repr()).unwrap();
+        let b_redefined = redefined::Scalar::from_repr(b.to_repr()).unwrap();
+
+        assert_eq!((a * b).to_repr(), (a_redefined * b_redefined).to_repr());
+        assert_eq!((a - b).to_repr(), (a_redefined - b_redefined).to_repr());
+        assert_eq!(
+            a.invert().unwrap().to_repr(),
+            a_redefined.invert().unwrap().to_repr()
+        );
+
+        (a, b) = (a * b + Scalar::ONE, a.square() - b);
+    }
+}
+
+#[test]
+fn pallas_primefield_constants_are_consistent() {
+    assert_eq!(pallas::FieldElement::NUM_BITS, 255);
+    assert_eq!(pallas::FieldElement::S, 32);
+    assert_eq!(pallas::Scalar::NUM_BITS, 255);
+    assert_eq!(pallas::Scalar::S, 32);
+}
+
+#[test]
+fn pallas_reduce() {
+    // 2^256 - 1 mod n
+    let expected = pallas::Scalar::from_hex(
+        "3fffffffffffffffffffffffffffffff992c350be34205675b2b3e9cfffffffc",
+    );
+    assert_eq!(pallas::Scalar::reduce(U256::MAX), expected);
+
+    // 3n + 5 < 2^256 needs more than one subtraction of `n`.
+    let w = Pallas::ORDER
+        .wrapping_mul(&U256::from_u8(3))
+        .wrapping_add(&U256::from_u8(5));
+    assert_eq!(pallas::Scalar::reduce(w), pallas::Scalar::from_u64(5));
+
+    let bytes = pallas::FieldBytes::from([0xff; 32]);
+    assert_eq!(pallas::Scalar::reduce_bytes(&bytes), expected);
+}
+
+#[test]
+fn pallas_generator_has_order_n() {
+    let g = pallas::ProjectivePoint::GENERATOR;
+    let minus_one = -pallas::Scalar::ONE;
+
+    assert_eq!(g * minus_one, -g);
+    assert!(bool::from((g * minus_one + g).is_identity()));
+}
+
+#[test]
+fn pallas_mul_is_consistent() {
+    let g = pallas::ProjectivePoint::GENERATOR;
+    let mut a = pallas::Scalar::from_u64(0xdead_beef);
+    let mut b = pallas::Scalar::from_u64(0xcafe_f00d);
+
+    for _ in 0..8 {
+        assert_eq!((g * a) * b, g * (a * b));
+        assert_eq!(g * a + g * b, g * (a + b));
+
+        let p = (g * a).to_affine();
+        let q = pallas::AffinePoint::from_encoded_point(&p.to_encoded_point(false)).unwrap();
+        assert_eq!(p, q);
+
+        (a, b) = (a * b + pallas::Scalar::ONE, a.square() - b);
+    }
+}
@@ -0,0 +1,235 @@
+//! Tests for hashing to curves defined with `primeorder::define_curve!`, using
+//! the test vectors from RFC 9380 and the `k256` crate as a reference.
+
+use hex_literal::hex;
+use primeorder::{
+    elliptic_curve::{
+        hash2curve::{ExpandMsgXmd, GroupDigest},
+        sec1::ToEncodedPoint,
+    },
+    hash2curve::map_to_curve,
+    PrimeField,
+};
+use sha2::Sha256;
+
+mod nistp256 {
+    use primeorder::{
+        elliptic_curve::{bigint::U256, consts::U48},
+        hash2curve::SswuParams,
+    };
+
+    primeorder::define_curve! {
+        /// NIST P-256 defined from its domain parameters.
+        pub struct P256;
+        uint: U256;
+        field_bytes_size: primeorder::elliptic_curve::consts::U32;
+        oid: "1.2.840.10045.3.1.7";
+        p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+        n: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+        a: "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc";
+        b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b";
+        gx: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296";
+        gy: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5";
+    }
+
+    impl SswuParams for P256 {
+        /// Z = -10
+        const Z: FieldElement = FieldElement::from_u64(10).neg();
+
+        const SUITE_ID_RO: &'static str = "P256_XMD:SHA-256_SSWU_RO_";
+        const SUITE_ID_NU: &'static str = "P256_XMD:SHA-256_SSWU_NU_";
+    }
+
+    primeorder::impl_hash2curve!(P256, FieldElement, Scalar, U48);
+
+    mod tests {
+        use super::{AffinePoint, FieldElement, Scalar, P256};
+
+        primeorder::impl_hash2curve_tests!(P256, AffinePoint, FieldElement, Scalar);
+    }
+}
+
+mod secp256k1 {
+    use primeorder::{
+        elliptic_curve::{bigint::U256, consts::U48},
+        hash2curve::{SswuIsogeny, SswuParams},
+    };
+
+    primeorder::define_curve! {
+        /// secp256k1 defined from its domain parameters.
+        pub struct Secp256k1;
+        uint: U256;
+        field_bytes_size: primeorder::elliptic_curve::consts::U32;
+        oid: "1.3.132.0.10";
+        p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+        n: "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+        a: "0000000000000000000000000000000000000000000000000000000000000000";
+        b: "0000000000000000000000000000000000000000000000000000000000000007";
+        gx: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        gy: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+        point_arithmetic: primeorder::point_arithmetic::EquationAIsZero;
+    }
+
+    /// The 3-isogeny from RFC 9380 § E.1.
+    const ISOGENY: SswuIsogeny<FieldElement> = SswuIsogeny {
+        a: FieldElement::from_hex(
+            "3f8731abdd661adca08a5558f0f5d272e953d363cb6f0e5d405447c01a444533",
+        ),
+        b: FieldElement::from_u64(1771),
+        x_num: &[
+            FieldElement::from_hex(
+                "8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa8c7",
+            ),
+            FieldElement::from_hex(
+                "07d3d4c80bc321d5b9f315cea7fd44c5d595d2fc0bf63b92dfff1044f17c6581",
+            ),
+            FieldElement::from_hex(
+                "534c328d23f234e6e2a413deca25caece4506144037c40314ecbd0b53d9dd262",
+            ),
+            FieldElement::from_hex(
+                "8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa88c",
+            ),
+        ],
+        x_den: &[
+            FieldElement::from_hex(
+                "d35771193d94918a9ca34ccbb7b640dd86cd409542f8487d9fe6b745781eb49b",
+            ),
+            FieldElement::from_hex(
+                "edadc6f64383dc1df7c4b2d51b54225406d36b641f5e41bbc52a56612a8c6d14",
+            ),
+            FieldElement::ONE,
+        ],
+        y_num: &[
+            FieldElement::from_hex(
+                "4bda12f684bda12f684bda12f684bda12f684bda12f684bda12f684b8e38e23c",
+            ),
+            FieldElement::from_hex(
+                "c75e0c32d5cb7c0fa9d0a54b12a0a6d5647ab046d686da6fdffc90fc201d71a3",
+            ),
+            FieldElement::from_hex(
+                "29a6194691f91a73715209ef6512e576722830a201be2018a765e85a9ecee931",
+            ),
+            FieldElement::from_hex(
+                "2f684bda12f684bda12f684bda12f684bda12f684bda12f684bda12f38e38d84",
+            ),
+        ],
+        y_den: &[
+            FieldElement::from_hex(
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffff93b",
+            ),
+            FieldElement::from_hex(
+                "7a06534bb8bdb49fd5e9e6632722c2989467c1bfc8e8d978dfb425d2685c2573",
+            ),
+            FieldElement::from_hex(
+                "6484aa716545ca2cf3a70c3fa8fe337e0a3d21162f0d6299a7bf8192bfd2a76f",
+            ),
+            FieldElement::ONE,
+        ],
+    };
+
+    impl SswuParams for Secp256k1 {
+        /// Z = -11
+        const Z: FieldElement = FieldElement::from_u64(11).neg();
+        const ISOGENY: Option<SswuIsogeny<FieldElement>> = Some(ISOGENY);
+
+        const SUITE_ID_RO: &'static str = "secp256k1_XMD:SHA-256_SSWU_RO_";
+        const SUITE_ID_NU: &'static str = "secp256k1_XMD:SHA-256_SSWU_NU_";
+    }
+
+    primeorder::impl_hash2curve!(Secp256k1, FieldElement, Scalar, U48);
+
+    mod tests {
+        use super::{AffinePoint, FieldElement, Scalar, Secp256k1};
+
+        primeorder::impl_hash2curve_tests!(Secp256k1, AffinePoint, FieldElement, Scalar);
+    }
+}
+
+/// Test vectors for `P256_XMD:SHA-256_SSWU_RO_` from RFC 9380 § J.1.1, given
+/// as `(msg, u0, u1, Q0, Q1, P)`, with the points uncompressed.
+#[allow(clippy::type_complexity)]
+const P256_TEST_VECTORS: &[(&[u8], [u8; 32], [u8; 32], [u8; 65], [u8; 65], [u8; 65])] = &[
+    (
+        b"",
+        hex!("ad5342c66a6dd0ff080df1da0ea1c04b96e0330dd89406465eeba11582515009"),
+        hex!("8c0f1d43204bd6f6ea70ae8013070a1518b43873bcd850aafa0a9e220e2eea5a"),
+        hex!("04ab640a12220d3ff283510ff3f4b1953d09fad35795140b1c5d64f313967934d5dccb558863804a881d4fff3455716c836cef230e5209594ddd33d85c565b19b1"),
+        hex!("0451cce63c50d972a6e51c61334f0f4875c9ac1cd2d3238412f84e31da7d980ef5b45d1a36d00ad90e5ec7840a60a4de411917fbe7c82c3949a6e699e5a1b66aac"),
+        hex!("042c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e48a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415"),
+    ),
+    (
+        b"abc",
+        hex!("afe47f2ea2b10465cc26ac403194dfb68b7f5ee865cda61e9f3e07a537220af1"),
+        hex!("379a27833b0bfe6f7bdca08e1e83c760bf9a338ab335542704edcd69ce9e46e0"),
+        hex!("045219ad0ddef3cc49b714145e91b2f7de6ce0a7a7dc7406c7726c7e373c58cb487950144e52d30acbec7b624c203b1996c99617d0b61c2442354301b191d93ecf"),
+        hex!("04019b7cb4efcfeaf39f738fe638e31d375ad6837f58a852d032ff60c69ee3875f589a62d2b22357fed5449bc38065b760095ebe6aeac84b01156ee4252715446e"),
+        hex!("040bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e"),
+    ),
+    (
+        b"abcdef0123456789",
+        hex!("0fad9d125a9477d55cf9357105b0eb3a5c4259809bf87180aa01d651f53d312c"),
+        hex!("b68597377392cd3419d8fcc7d7660948c8403b19ea78bbca4b133c9d2196c0fb"),
+        hex!("04a17bdf2965eb88074bc01157e644ed409dac97cfcf0c61c998ed0fa45e79e4a24f1bc80c70d411a3cc1d67aeae6e726f0f311639fee560c7f5a664554e3c9c2e"),
+        hex!("047da48bb67225c1a17d452c983798113f47e438e4202219dd0715f8419b274d66b765696b2913e36db3016c47edb99e24b1da30e761a8a3215dc0ec4d8f96e6f9"),
+        hex!("0465038ac8f2b1def042a5df0b33b1f4eca6bff7cb0f9c6c1526811864e544ed80cad44d40a656e7aff4002a8de287abc8ae0482b5ae825822bb870d6df9b56ca3"),
+    ),
+];
+
+#[test]
+fn p256_map_to_curve() {
+    for (_, u0, u1, q0, q1, _) in P256_TEST_VECTORS {
+        for (u, q) in [(u0, q0), (u1, q1)] {
+            let u = nistp256::FieldElement::from_repr((*u).into()).unwrap();
+            let point = map_to_curve::<nistp256::P256>(&u).to_affine();
+            assert_eq!(point.to_encoded_point(false).as_bytes(), q);
+        }
+    }
+}
+
+#[test]
+fn p256_hash_to_curve() {
+    const DST: &[u8] = b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_";
+
+    for (msg, _, _, _, _, p) in P256_TEST_VECTORS {
+        let point =
+            nistp256::P256::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[msg], &[DST]).unwrap();
+        assert_eq!(point.to_affine().to_encoded_point(false).as_bytes(), p);
+    }
+}
+
+/// Compare the map to the isogenous curve and the isogeny with `k256`.
+#[test]
+fn secp256k1_map_to_curve() {
+    use k256::elliptic_curve::hash2curve::MapToCurve;
+
+    let mut u = secp256k1::FieldElement::from_u64(7);
+
+    for _ in 0..64 {
+        let expected = k256::FieldElement::from_bytes(&u.to_repr())
+            .unwrap()
+            .map_to_curve()
+            .to_affine()
+            .to_encoded_point(false);
+
+        let point = map_to_curve::<secp256k1::Secp256k1>(&u).to_affine();
+        assert_eq!(point.to_encoded_point(false), expected);
+
+        u = u.square() + u;
+    }
+}
+
+#[test]
+fn secp256k1_hash_to_curve() {
+    const DST: &[u8] = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+
+    for msg in [&b""[..], b"abc", b"abcdef0123456789"] {
+        let expected = k256::Secp256k1::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[msg], &[DST])
+            .unwrap()
+            .to_affine()
+            .to_encoded_point(false);
+
+        let point =
+            secp256k1::Secp256k1::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[msg], &[DST]).unwrap();
+        assert_eq!(point.to_affine().to_encoded_point(false), expected);
+    }
+}
@@ -0,0 +1,196 @@
+//! Tests for the Jacobian and co-Z point arithmetic backends, using curves
+//! defined with `primeorder::define_curve!` and the `p256` and `k256` crates
+//! as a reference.
+
+/// Define P-256 with the given point arithmetic backend, along with the
+/// projective arithmetic tests for it.
+macro_rules! define_p256 {
+    ($point_arithmetic:ty) => {
+        use primeorder::elliptic_curve::{bigint::U256, consts::U32};
+
+        primeorder::define_curve! {
+            /// NIST P-256 defined from its domain parameters.
+            pub struct P256;
+            uint: U256;
+            field_bytes_size: U32;
+            oid: "1.2.840.10045.3.1.7";
+            p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+            n: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+            a: "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc";
+            b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b";
+            gx: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296";
+            gy: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5";
+            point_arithmetic: $point_arithmetic;
+        }
+
+        mod projective {
+            use super::{AffinePoint, ProjectivePoint, Scalar};
+            use p256::test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS};
+            use primeorder::{
+                elliptic_curve::{
+                    group::ff::PrimeField,
+                    sec1::{self, ToEncodedPoint},
+                },
+                impl_projective_arithmetic_tests, Double,
+            };
+
+            impl_projective_arithmetic_tests!(
+                AffinePoint,
+                ProjectivePoint,
+                Scalar,
+                ADD_TEST_VECTORS,
+                MUL_TEST_VECTORS
+            );
+
+            #[test]
+            fn add_equal_and_opposite_points() {
+                let x = ProjectivePoint::GENERATOR * Scalar::from(5u64);
+
+                // The same point as `x`, with a different `Z`
+                let y = x.double() - x;
+
+                assert_eq!(x + y, x.double());
+                assert_eq!(y + x.to_affine(), x.double());
+                assert_eq!(x - y, ProjectivePoint::IDENTITY);
+                assert_eq!(y - x.to_affine(), ProjectivePoint::IDENTITY);
+                assert_eq!(x + ProjectivePoint::IDENTITY, x);
+                assert_eq!(ProjectivePoint::IDENTITY + x.to_affine(), x);
+            }
+
+            #[test]
+            fn mul_exceptional_scalars() {
+                crate::check_mul_exceptional_scalars::<super::P256>();
+            }
+        }
+    };
+}
+
+mod generic {
+    define_p256!(primeorder::point_arithmetic::EquationAIsGenericJacobian);
+}
+
+mod minus_three {
+    define_p256!(primeorder::point_arithmetic::EquationAIsMinusThreeJacobian);
+}
+
+mod coz_generic {
+    define_p256!(primeorder::point_arithmetic::EquationAIsGenericCoZ);
+}
+
+mod coz_minus_three {
+    define_p256!(primeorder::point_arithmetic::EquationAIsMinusThreeCoZ);
+}
+
+mod secp256k1 {
+    use primeorder::elliptic_curve::{bigint::U256, consts::U32};
+
+    primeorder::define_curve! {
+        /// secp256k1 defined from its domain parameters.
+        pub struct Secp256k1;
+        uint: U256;
+        field_bytes_size: U32;
+        oid: "1.3.132.0.10";
+        p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+        n: "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+        a: "0000000000000000000000000000000000000000000000000000000000000000";
+        b: "0000000000000000000000000000000000000000000000000000000000000007";
+        gx: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        gy: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+        point_arithmetic: primeorder::point_arithmetic::EquationAIsGenericJacobian;
+    }
+}
+
+mod secp256k1_coz {
+    use primeorder::elliptic_curve::{bigint::U256, consts::U32};
+
+    primeorder::define_curve! {
+        /// secp256k1 defined from its domain parameters.
+        pub struct Secp256k1;
+        uint: U256;
+        field_bytes_size: U32;
+        oid: "1.3.132.0.10";
+        p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+        n: "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+        a: "0000000000000000000000000000000000000000000000000000000000000000";
+        b: "0000000000000000000000000000000000000000000000000000000000000007";
+        gx: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        gy: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+        point_arithmetic: primeorder::point_arithmetic::EquationAIsGenericCoZ;
+    }
+
+    mod projective {
+        use super::{AffinePoint, ProjectivePoint, Scalar};
+        use k256::test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS};
+        use primeorder::{
+            elliptic_curve::{
+                group::ff::PrimeField,
+                sec1::{self, ToEncodedPoint},
+            },
+            impl_projective_arithmetic_tests, Double,
+        };
+
+        impl_projective_arithmetic_tests!(
+            AffinePoint,
+            ProjectivePoint,
+            Scalar,
+            ADD_TEST_VECTORS,
+            MUL_TEST_VECTORS
+        );
+
+        #[test]
+        fn mul_exceptional_scalars() {
+            crate::check_mul_exceptional_scalars::<super::Secp256k1>();
+        }
+    }
+}
+
+use primeorder::{
+    elliptic_curve::sec1::ToEncodedPoint, Double, Field, PrimeCurveParams, PrimeField,
+    ProjectivePoint,
+};
+
+/// Checks multiplication by the scalars close to `0`, `n/2` and `n`, which
+/// are exceptional cases of the co-Z ladder, against `mul_vartime`.
+fn check_mul_exceptional_scalars<C: PrimeCurveParams>()
+where
+    ProjectivePoint<C>: Double,
+{
+    let x = ProjectivePoint::<C>::GENERATOR * C::Scalar::from(5);
+    let half = C::Scalar::from(2).invert().unwrap();
+
+    for base in [C::Scalar::ZERO, half] {
+        for i in 0..4 {
+            for k in [base + C::Scalar::from(i), base - C::Scalar::from(i)] {
+                assert_eq!(x * k, x.mul_vartime(&k));
+                assert_eq!(
+                    ProjectivePoint::<C>::IDENTITY * k,
+                    ProjectivePoint::IDENTITY
+                );
+            }
+        }
+    }
+}
+
+/// The unified addition formula is undefined for distinct points with
+/// opposite `y`-coordinates, such as `G` and `-[λ]G = (βx, -y)` on secp256k1.
+#[test]
+fn add_opposite_y_coordinates() {
+    const LAMBDA: &str = "5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72";
+
+    let g = secp256k1::ProjectivePoint::GENERATOR;
+    let h = -(g * secp256k1::Scalar::from_hex(LAMBDA));
+    assert_eq!(
+        h.to_affine().to_encoded_point(false).y(),
+        (-g).to_affine().to_encoded_point(false).y()
+    );
+
+    let lambda = k256::Scalar::from_repr(secp256k1::Scalar::from_hex(LAMBDA).to_repr()).unwrap();
+    let expected = k256::ProjectivePoint::GENERATOR * (k256::Scalar::ONE - lambda);
+    let expected = expected.to_affine().to_encoded_point(false);
+
+    assert_eq!((g + h).to_affine().to_encoded_point(false), expected);
+    assert_eq!(
+        (h + g.to_affine()).to_affine().to_encoded_point(false),
+        expected
+    );
+}
@@ -0,0 +1,173 @@
+//! Tests for the point arithmetic specialised for `a = 0` and for the GLV
+//! endomorphism, using secp256k1 defined with `primeorder::define_curve!` and
+//! `k256` as a reference.
+
+mod secp256k1 {
+    use primeorder::elliptic_curve::{bigint::U256, consts::U32};
+
+    primeorder::define_curve! {
+        /// secp256k1 defined from its domain parameters.
+        pub struct Secp256k1;
+        uint: U256;
+        field_bytes_size: U32;
+        oid: "1.3.132.0.10";
+        p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+        n: "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+        a: "0000000000000000000000000000000000000000000000000000000000000000";
+        b: "0000000000000000000000000000000000000000000000000000000000000007";
+        gx: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        gy: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+        point_arithmetic: primeorder::point_arithmetic::EquationAIsZero;
+        glv: {
+            beta: "7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee",
+            lambda: "5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72",
+            minus_b1: "00000000000000000000000000000000e4437ed6010e88286f547fa90abfe4c3",
+            minus_b2: "fffffffffffffffffffffffffffffffe8a280ac50774346dd765cda83db1562c",
+            g1: "3086d221a7d46bcde86c90e49284eb153daa8a1471e8ca7fe893209a45dbb031",
+            g2: "e4437ed6010e88286f547fa90abfe4c4221208ac9df506c61571b4ae8ac47f71",
+        };
+    }
+
+    mod projective {
+        use super::{AffinePoint, ProjectivePoint, Scalar};
+        use k256::test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS};
+        use primeorder::{
+            elliptic_curve::{
+                group::ff::PrimeField,
+                sec1::{self, ToEncodedPoint},
+            },
+            impl_projective_arithmetic_tests, Double,
+        };
+
+        impl_projective_arithmetic_tests!(
+            AffinePoint,
+            ProjectivePoint,
+            Scalar,
+            ADD_TEST_VECTORS,
+            MUL_TEST_VECTORS
+        );
+    }
+}
+
+use k256::{ProjectivePoint, Scalar};
+use primeorder::{elliptic_curve::sec1::ToEncodedPoint, GlvParams, PrimeField};
+use secp256k1::Secp256k1;
+
+/// Scalars `k` and their decompositions `(r1, r2)` with `k = r1 + r2 * λ`,
+/// computed independently from the lattice basis.
+const DECOMPOSITION_TEST_VECTORS: &[(&str, &str, &str)] = &[
+    (
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+    ),
+    (
+        "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+        "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+    ),
+    (
+        "5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+    ),
+    (
+        "7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0",
+        "00000000000000000000000000000000a2a8918ca85bafe22016d0b917e4dd76",
+        "fffffffffffffffffffffffffffffffe60d0868c82ab920e7c5e672a9418c46a",
+    ),
+    (
+        "8000000000000000000000000000000000000000000000000000000000000000",
+        "00000000000000000000000000000000000000000000000000000000800001e9",
+        "0000000000000000000000000000000059de565a2c9d0e2d4373f7623c1d7cd7",
+    ),
+    (
+        "000000000000000000000001234567890abcdef0000000000000000000000000",
+        "fffffffffffffffffffffffffffffffe88b98841e3612d92233d134edf034a72",
+        "fffffffffffffffffffffffffffffffe96919e31e82d0cfa4c311bae31cbb08a",
+    ),
+    (
+        "1e2feb89414c343c1027c4d1c386bbc4cd613e30d8f16adf91b7584a2265b1f5",
+        "0000000000000000000000000000000071ec3da3357fcf8d7e693a6556d72732",
+        "00000000000000000000000000000000113e30f71b31abcabb2108e9e92cadde",
+    ),
+    (
+        "35bf992dc9e9c616612e7696a6cecc1b78e510617311d8a3c2ce6f447ed4d57b",
+        "fffffffffffffffffffffffffffffffeba612074a03e368881e64af970c33ca1",
+        "fffffffffffffffffffffffffffffffe4bf39c048c486930ac32d9f0049b0463",
+    ),
+];
+
+#[test]
+fn decompose_scalar() {
+    for &(k, r1, r2) in DECOMPOSITION_TEST_VECTORS {
+        let k = secp256k1::Scalar::from_hex(k);
+        let (r1, r2) = (
+            secp256k1::Scalar::from_hex(r1),
+            secp256k1::Scalar::from_hex(r2),
+        );
+
+        assert_eq!(Secp256k1::decompose_scalar(&k), (r1, r2));
+        assert_eq!(r1 + r2 * Secp256k1::LAMBDA, k);
+    }
+}
+
+#[test]
+fn endomorphism() {
+    let generator = secp256k1::ProjectivePoint::GENERATOR;
+    assert_eq!(generator.endomorphism(), generator * Secp256k1::LAMBDA);
+    assert_eq!(
+        generator.endomorphism().endomorphism().endomorphism(),
+        generator
+    );
+}
+
+#[test]
+fn mul_matches_k256() {
+    let mut k = Scalar::from(0x1234_5678u64);
+
+    for _ in 0..16 {
+        let k_redefined = secp256k1::Scalar::from_repr(k.to_repr()).unwrap();
+
+        let expected = (ProjectivePoint::GENERATOR * k).to_affine();
+        let actual = (secp256k1::ProjectivePoint::GENERATOR * k_redefined).to_affine();
+        assert_eq!(
+            expected.to_encoded_point(false).as_bytes(),
+            actual.to_encoded_point(false).as_bytes()
+        );
+
+        k = k.square() + Scalar::ONE;
+    }
+}
+
+#[cfg(feature = "side-channel-hardening")]
+#[test]
+fn mul_blinded_matches_k256() {
+    use rand_core::OsRng;
+
+    let mut k = Scalar::from(0x1234_5678u64);
+    let table =
+        primeorder::FixedBaseTable::<Secp256k1, 32>::new(&secp256k1::ProjectivePoint::GENERATOR);
+
+    for _ in 0..16 {
+        let k_redefined = secp256k1::Scalar::from_repr(k.to_repr()).unwrap();
+        let expected = (ProjectivePoint::GENERATOR * k).to_affine();
+
+        for actual in [
+            secp256k1::ProjectivePoint::GENERATOR.mul_blinded(&k_redefined, &mut OsRng),
+            table.mul_blinded(&k_redefined, &mut OsRng),
+        ] {
+            assert_eq!(
+                expected.to_encoded_point(false).as_bytes(),
+                actual.to_affine().to_encoded_point(false).as_bytes()
+            );
+        }
+
+        k = k.square() + Scalar::ONE;
+    }
+}
@@ -1,6 +1,8 @@
 //! Field arithmetic modulo p = 0xfffffffeffffffffffffffffffffffffffffffff00000000ffffffffffffffff
 //!
-//! Arithmetic implementations have been synthesized using fiat-crypto.
+//! Arithmetic is implemented in terms of `crypto-bigint`'s `ConstMontyForm` by
+//! default, or by code synthesized using fiat-crypto when the `fiat` feature
+//! is enabled.
 //!
 //! # License
 //!
@@ -23,34 +25,53 @@
     rustdoc::bare_urls
 )]
 
+#[cfg(any(feature = "fiat", test))]
 #[cfg_attr(target_pointer_width = "32", path = "field/sm2_32.rs")]
 #[cfg_attr(target_pointer_width = "64", path = "field/sm2_64.rs")]
 mod field_impl;
 
-use self::field_impl::*;
+#[cfg(all(test, target_pointer_width = "64"))]
+#[path = "field/sm2_32.rs"]
+mod field_impl_32;
+
+#[cfg(all(test, target_pointer_width = "32"))]
+#[path = "field/sm2_64.rs"]
+mod field_impl_64;
+
 use crate::{FieldBytes, Sm2, U256};
-use core::{
-    fmt::{self, Debug},
-    iter::{Product, Sum},
-    ops::{AddAssign, MulAssign, Neg, SubAssign},
-};
-use elliptic_curve::ops::Invert;
-use elliptic_curve::{
-    bigint::Limb,
-    ff::PrimeField,
-    subtle::{Choice, ConstantTimeEq, CtOption},
+
+#[cfg(feature = "fiat")]
+use {
+    self::field_impl::*,
+    core::{
+        cmp::Ordering,
+        fmt::{self, Debug},
+        iter::{Product, Sum},
+        ops::{AddAssign, MulAssign, Neg, SubAssign},
+    },
+    elliptic_curve::{
+        bigint::Limb,
+        ff::PrimeField,
+        ops::Invert,
+        subtle::{Choice, ConstantTimeEq, CtOption},
+    },
 };
 
 /// Constant representing the modulus serialized as hex.
 const MODULUS_HEX: &str = "fffffffeffffffffffffffffffffffffffffffff00000000ffffffffffffffff";
 
+#[cfg(feature = "fiat")]
 const MODULUS: U256 = U256::from_be_hex(MODULUS_HEX);
 
 /// Element of the SM2 elliptic curve base field used for curve point coordinates.
 #[derive(Clone, Copy)]
 pub struct FieldElement(pub(super) U256);
 
-primeorder::impl_mont_field_element!(
+#[cfg(not(feature = "fiat"))]
+primefield::impl_monty_field_element!(Sm2, FieldElement, FieldBytes, U256, MODULUS_HEX, 13);
+
+#[cfg(feature = "fiat")]
+primefield::impl_mont_field_element!(
     Sm2,
     FieldElement,
     FieldBytes,
@@ -66,12 +87,14 @@ primeorder::impl_mont_field_element!(
     fiat_sm2_square
 );
 
+#[cfg(feature = "fiat")]
 impl Debug for FieldElement {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "FieldElement(0x{:X})", &self.0)
+        write!(f, "FieldElement(0x{:X})", &self.to_canonical())
     }
 }
 
+#[cfg(feature = "fiat")]
 impl FieldElement {
     /// Compute [`FieldElement`] inversion: `1 / self`.
     pub fn invert(&self) -> CtOption<Self> {
@@ -115,6 +138,21 @@ impl FieldElement {
     }
 }
 
+#[cfg(feature = "fiat")]
+impl PartialOrd for FieldElement {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "fiat")]
+impl Ord for FieldElement {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_canonical().cmp(&other.to_canonical())
+    }
+}
+
+#[cfg(feature = "fiat")]
 impl PrimeField for FieldElement {
     type Repr = FieldBytes;
 
@@ -145,23 +183,35 @@ impl PrimeField for FieldElement {
     }
 }
 
+#[cfg(feature = "fiat")]
 impl Invert for FieldElement {
     type Output = CtOption<Self>;
 
     fn invert(&self) -> CtOption<Self> {
         self.invert()
     }
+
+    fn invert_vartime(&self) -> CtOption<Self> {
+        self.invert_vartime()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::FieldElement;
     use elliptic_curve::ff::PrimeField;
-    use primeorder::{
-        impl_field_identity_tests, impl_field_invert_tests, impl_field_sqrt_tests,
-        impl_primefield_tests,
+    use primefield::{
+        impl_fiat_differential_tests, impl_field_batch_invert_tests, impl_field_differential_tests,
+        impl_field_identity_tests, impl_field_invert_tests, impl_field_invert_vartime_tests,
+        impl_field_pow_tests, impl_field_sqrt_differential_tests, impl_field_sqrt_ratio_tests,
+        impl_field_sqrt_tests, impl_primefield_tests,
     };
 
+    #[cfg(target_pointer_width = "64")]
+    use super::{field_impl as field_impl_64, field_impl_32};
+    #[cfg(target_pointer_width = "32")]
+    use super::{field_impl as field_impl_32, field_impl_64};
+
     /// t = (modulus - 1) >> S
     /// 0x7fffffff7fffffffffffffffffffffffffffffff800000007fffffffffffffff
     const T: [u64; 4] = [
@@ -173,6 +223,25 @@ mod tests {
 
     impl_field_identity_tests!(FieldElement);
     impl_field_invert_tests!(FieldElement);
+    impl_field_invert_vartime_tests!(FieldElement);
+    impl_field_batch_invert_tests!(FieldElement);
     impl_field_sqrt_tests!(FieldElement);
+    impl_field_sqrt_ratio_tests!(FieldElement);
+    impl_field_pow_tests!(FieldElement);
+    impl_field_differential_tests!(FieldElement);
+    impl_field_sqrt_differential_tests!(FieldElement);
+    impl_fiat_differential_tests!(
+        FieldElement,
+        [field_impl_32, field_impl_64],
+        fiat_sm2_from_bytes,
+        fiat_sm2_to_bytes,
+        fiat_sm2_to_montgomery,
+        fiat_sm2_from_montgomery,
+        fiat_sm2_add,
+        fiat_sm2_sub,
+        fiat_sm2_mul,
+        fiat_sm2_square,
+        fiat_sm2_opp,
+    );
     impl_primefield_tests!(FieldElement, T);
 }
@@ -0,0 +1,24 @@
+//! Hashing to SM2 using the simplified SWU map.
+
+use super::{field::FieldElement, scalar::Scalar};
+use crate::Sm2;
+use elliptic_curve::consts::U48;
+use primeorder::hash2curve::SswuParams;
+
+impl SswuParams for Sm2 {
+    /// Z = -9
+    const Z: FieldElement = FieldElement::from_u64(9).neg();
+
+    const SUITE_ID_RO: &'static str = "SM2_XMD:SM3_SSWU_RO_";
+    const SUITE_ID_NU: &'static str = "SM2_XMD:SM3_SSWU_NU_";
+}
+
+primeorder::impl_hash2curve!(Sm2, FieldElement, Scalar, U48);
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldElement, Scalar};
+    use crate::{AffinePoint, Sm2};
+
+    primeorder::impl_hash2curve_tests!(Sm2, AffinePoint, FieldElement, Scalar);
+}
@@ -1,6 +1,8 @@
 //! SM2 scalar field elements.
 //!
-//! Arithmetic implementations have been synthesized using fiat-crypto.
+//! Arithmetic is implemented in terms of `crypto-bigint`'s `ConstMontyForm` by
+//! default, or by code synthesized using fiat-crypto when the `fiat` feature
+//! is enabled.
 //!
 //! # License
 //!
@@ -19,35 +21,59 @@
     clippy::unnecessary_cast
 )]
 
+#[cfg(any(feature = "fiat", test))]
 #[cfg_attr(target_pointer_width = "32", path = "scalar/sm2_scalar_32.rs")]
 #[cfg_attr(target_pointer_width = "64", path = "scalar/sm2_scalar_64.rs")]
 mod scalar_impl;
 
-use self::scalar_impl::*;
-use crate::{FieldBytes, FieldBytesEncoding, SecretKey, Sm2, ORDER_HEX, U256};
-use core::{
-    fmt::{self, Debug},
-    iter::{Product, Sum},
-    ops::{AddAssign, MulAssign, Neg, Shr, ShrAssign, SubAssign},
-};
+#[cfg(all(test, target_pointer_width = "64"))]
+#[path = "scalar/sm2_scalar_32.rs"]
+mod scalar_impl_32;
+
+#[cfg(all(test, target_pointer_width = "32"))]
+#[path = "scalar/sm2_scalar_64.rs"]
+mod scalar_impl_64;
+
+use crate::{FieldBytes, FieldBytesEncoding, SecretKey, Sm2, WideBytes, ORDER_HEX, U256};
+use core::ops::{Shr, ShrAssign};
 use elliptic_curve::{
-    bigint::Limb,
-    ff::PrimeField,
+    bigint::{ArrayEncoding, Limb, U512},
+    ff::{FromUniformBytes, PrimeField},
     ops::Reduce,
     scalar::{FromUintUnchecked, IsHigh},
-    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, CtOption},
+    subtle::{Choice, ConditionallySelectable, ConstantTimeGreater},
     Curve as _, Error, Result, ScalarPrimitive,
 };
 
+#[cfg(feature = "fiat")]
+use {
+    self::scalar_impl::*,
+    core::{
+        cmp::Ordering,
+        fmt::{self, Debug},
+        iter::{Product, Sum},
+        ops::{AddAssign, MulAssign, SubAssign},
+    },
+    elliptic_curve::{
+        ops::Invert,
+        subtle::{ConstantTimeEq, CtOption},
+    },
+};
+
 #[cfg(feature = "bits")]
-use {crate::ScalarBits, elliptic_curve::group::ff::PrimeFieldBits};
+use {
+    crate::ScalarBits,
+    elliptic_curve::{bigint::Word, group::ff::PrimeFieldBits},
+};
 
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
 
 #[cfg(doc)]
 use core::ops::{Add, Mul, Sub};
-use elliptic_curve::ops::Invert;
+
+#[cfg(any(doc, feature = "fiat"))]
+use core::ops::Neg;
 
 /// Scalars are elements in the finite field modulo `n`.
 ///
@@ -72,10 +98,14 @@ use elliptic_curve::ops::Invert;
 ///   operations over field elements represented as bits (requires `bits` feature)
 ///
 /// Please see the documentation for the relevant traits for more information.
-#[derive(Clone, Copy, PartialOrd, Ord)]
+#[derive(Clone, Copy)]
 pub struct Scalar(U256);
 
-primeorder::impl_mont_field_element!(
+#[cfg(not(feature = "fiat"))]
+primefield::impl_monty_field_element!(Sm2, Scalar, FieldBytes, U256, ORDER_HEX, 3);
+
+#[cfg(feature = "fiat")]
+primefield::impl_mont_field_element!(
     Sm2,
     Scalar,
     FieldBytes,
@@ -91,6 +121,7 @@ primeorder::impl_mont_field_element!(
     fiat_sm2_scalar_square
 );
 
+#[cfg(feature = "fiat")]
 impl Scalar {
     /// Compute [`Scalar`] inversion: `1 / self`.
     pub fn invert(&self) -> CtOption<Self> {
@@ -132,7 +163,74 @@ impl Scalar {
         ]);
         CtOption::new(sqrt, sqrt.square().ct_eq(self))
     }
+}
+
+#[cfg(feature = "fiat")]
+impl Debug for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Scalar(0x{:X})", &self.to_canonical())
+    }
+}
+
+#[cfg(feature = "fiat")]
+impl PartialOrd for Scalar {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "fiat")]
+impl Ord for Scalar {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_canonical().cmp(&other.to_canonical())
+    }
+}
+
+#[cfg(feature = "fiat")]
+impl PrimeField for Scalar {
+    type Repr = FieldBytes;
+
+    const MODULUS: &'static str = ORDER_HEX;
+    const NUM_BITS: u32 = 256;
+    const CAPACITY: u32 = 255;
+    const TWO_INV: Self = Self::from_u64(2).invert_unchecked();
+    const MULTIPLICATIVE_GENERATOR: Self = Self::from_u64(3);
+    const S: u32 = 1;
+    const ROOT_OF_UNITY: Self =
+        Self::from_hex("fffffffeffffffffffffffffffffffff7203df6b21c6052b53bbf40939d54122");
+    const ROOT_OF_UNITY_INV: Self = Self::ROOT_OF_UNITY.invert_unchecked();
+    const DELTA: Self = Self::from_u64(9);
+
+    #[inline]
+    fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
+        Self::from_bytes(&bytes)
+    }
+
+    #[inline]
+    fn to_repr(&self) -> FieldBytes {
+        self.to_bytes()
+    }
 
+    #[inline]
+    fn is_odd(&self) -> Choice {
+        self.is_odd()
+    }
+}
+
+#[cfg(feature = "fiat")]
+impl Invert for Scalar {
+    type Output = CtOption<Self>;
+
+    fn invert(&self) -> CtOption<Self> {
+        self.invert()
+    }
+
+    fn invert_vartime(&self) -> CtOption<Self> {
+        self.invert_vartime()
+    }
+}
+
+impl Scalar {
     /// Right shifts the scalar.
     ///
     /// Note: not constant-time with respect to the `shift` parameter.
@@ -147,12 +245,6 @@ impl AsRef<Scalar> for Scalar {
     }
 }
 
-impl Debug for Scalar {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Scalar(0x{:X})", &self.0)
-    }
-}
-
 impl FromUintUnchecked for Scalar {
     type Uint = U256;
 
@@ -190,39 +282,9 @@ impl ShrAssign<usize> for Scalar {
     }
 }
 
-impl PrimeField for Scalar {
-    type Repr = FieldBytes;
-
-    const MODULUS: &'static str = ORDER_HEX;
-    const NUM_BITS: u32 = 256;
-    const CAPACITY: u32 = 255;
-    const TWO_INV: Self = Self::from_u64(2).invert_unchecked();
-    const MULTIPLICATIVE_GENERATOR: Self = Self::from_u64(3);
-    const S: u32 = 1;
-    const ROOT_OF_UNITY: Self =
-        Self::from_hex("fffffffeffffffffffffffffffffffff7203df6b21c6052b53bbf40939d54122");
-    const ROOT_OF_UNITY_INV: Self = Self::ROOT_OF_UNITY.invert_unchecked();
-    const DELTA: Self = Self::from_u64(9);
-
-    #[inline]
-    fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
-        Self::from_bytes(&bytes)
-    }
-
-    #[inline]
-    fn to_repr(&self) -> FieldBytes {
-        self.to_bytes()
-    }
-
-    #[inline]
-    fn is_odd(&self) -> Choice {
-        self.is_odd()
-    }
-}
-
 #[cfg(feature = "bits")]
 impl PrimeFieldBits for Scalar {
-    type ReprBits = fiat_sm2_scalar_montgomery_domain_field_element;
+    type ReprBits = [Word; U256::LIMBS];
 
     fn to_le_bits(&self) -> ScalarBits {
         self.to_canonical().to_words().into()
@@ -249,6 +311,25 @@ impl Reduce<U256> for Scalar {
     }
 }
 
+impl Reduce<U512> for Scalar {
+    type Bytes = WideBytes;
+
+    fn reduce(w: U512) -> Self {
+        primefield::reduce_wide(&w)
+    }
+
+    #[inline]
+    fn reduce_bytes(bytes: &WideBytes) -> Self {
+        Self::reduce(U512::from_be_byte_array(*bytes))
+    }
+}
+
+impl FromUniformBytes<64> for Scalar {
+    fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
+        primefield::reduce_le_bytes(bytes)
+    }
+}
+
 impl From<ScalarPrimitive<Sm2>> for Scalar {
     fn from(w: ScalarPrimitive<Sm2>) -> Self {
         Scalar::from(&w)
@@ -331,23 +412,22 @@ impl<'de> Deserialize<'de> for Scalar {
     }
 }
 
-impl Invert for Scalar {
-    type Output = CtOption<Self>;
-
-    fn invert(&self) -> CtOption<Self> {
-        self.invert()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::Scalar;
-    use elliptic_curve::ff::PrimeField;
-    use primeorder::{
-        impl_field_identity_tests, impl_field_invert_tests, impl_field_sqrt_tests,
-        impl_primefield_tests,
+    use elliptic_curve::{bigint::U512, ff::PrimeField};
+    use primefield::{
+        impl_fiat_differential_tests, impl_field_batch_invert_tests, impl_field_differential_tests,
+        impl_field_identity_tests, impl_field_invert_tests, impl_field_invert_vartime_tests,
+        impl_field_pow_tests, impl_field_reduce_wide_tests, impl_field_sqrt_differential_tests,
+        impl_field_sqrt_ratio_tests, impl_field_sqrt_tests, impl_primefield_tests,
     };
 
+    #[cfg(target_pointer_width = "64")]
+    use super::{scalar_impl as scalar_impl_64, scalar_impl_32};
+    #[cfg(target_pointer_width = "32")]
+    use super::{scalar_impl as scalar_impl_32, scalar_impl_64};
+
     /// t = (modulus - 1) >> S
     /// 0x7fffffff7fffffffffffffffffffffffb901efb590e30295a9ddfa049ceaa091
     const T: [u64; 4] = [
@@ -359,6 +439,26 @@ mod tests {
 
     impl_field_identity_tests!(Scalar);
     impl_field_invert_tests!(Scalar);
+    impl_field_invert_vartime_tests!(Scalar);
+    impl_field_batch_invert_tests!(Scalar);
     impl_field_sqrt_tests!(Scalar);
+    impl_field_sqrt_ratio_tests!(Scalar);
+    impl_field_pow_tests!(Scalar);
+    impl_field_reduce_wide_tests!(Scalar, U512, 64);
+    impl_field_differential_tests!(Scalar);
+    impl_field_sqrt_differential_tests!(Scalar);
+    impl_fiat_differential_tests!(
+        Scalar,
+        [scalar_impl_32, scalar_impl_64],
+        fiat_sm2_scalar_from_bytes,
+        fiat_sm2_scalar_to_bytes,
+        fiat_sm2_scalar_to_montgomery,
+        fiat_sm2_scalar_from_montgomery,
+        fiat_sm2_scalar_add,
+        fiat_sm2_scalar_sub,
+        fiat_sm2_scalar_mul,
+        fiat_sm2_scalar_square,
+        fiat_sm2_scalar_opp,
+    );
     impl_primefield_tests!(Scalar, T);
 }
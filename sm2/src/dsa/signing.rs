@@ -16,7 +16,7 @@
 
 use super::{Signature, VerifyingKey};
 use crate::{
-    DistId, FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Scalar, SecretKey, Sm2,
+    DistId, FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Scalar, SecretKey, Sm2, U256,
 };
 use core::fmt::{self, Debug};
 use elliptic_curve::{
@@ -33,6 +33,9 @@ use signature::{
 };
 use sm3::Sm3;
 
+#[cfg(feature = "fault-countermeasures")]
+use signature::hazmat::PrehashVerifier;
+
 /// SM2DSA secret key used for signing messages and producing signatures.
 ///
 /// ## Usage
@@ -114,7 +117,17 @@ impl SigningKey {
 
 impl PrehashSigner<Signature> for SigningKey {
     fn sign_prehash(&self, prehash: &[u8]) -> Result<Signature> {
-        sign_prehash_rfc6979(&self.secret_scalar, prehash, &[])
+        let signature = sign_prehash_rfc6979(
+            &self.secret_scalar,
+            prehash,
+            &[],
+            ProjectivePoint::mul_by_generator,
+        )?;
+
+        #[cfg(feature = "fault-countermeasures")]
+        self.verifying_key.verify_prehash(prehash, &signature)?;
+
+        Ok(signature)
     }
 }
 
@@ -126,7 +139,21 @@ impl RandomizedPrehashSigner<Signature> for SigningKey {
     ) -> Result<Signature> {
         let mut data = FieldBytes::default();
         rng.try_fill_bytes(&mut data)?;
-        sign_prehash_rfc6979(&self.secret_scalar, prehash, &data)
+
+        // With `side-channel-hardening`, also use `rng` to randomise the
+        // computation of `[k]G`.
+        #[cfg(feature = "side-channel-hardening")]
+        let mul_by_generator = |k: &Scalar| ProjectivePoint::GENERATOR.mul_blinded(k, rng);
+        #[cfg(not(feature = "side-channel-hardening"))]
+        let mul_by_generator = ProjectivePoint::mul_by_generator;
+
+        let signature =
+            sign_prehash_rfc6979(&self.secret_scalar, prehash, &data, mul_by_generator)?;
+
+        #[cfg(feature = "fault-countermeasures")]
+        self.verifying_key.verify_prehash(prehash, &signature)?;
+
+        Ok(signature)
     }
 }
 
@@ -182,14 +209,20 @@ impl KeypairRef for SigningKey {
     type VerifyingKey = VerifyingKey;
 }
 
-/// Compute a signature using RFC6979 to deterministically derive `k`.
-fn sign_prehash_rfc6979(secret_scalar: &Scalar, prehash: &[u8], data: &[u8]) -> Result<Signature> {
+/// Compute a signature using RFC6979 to deterministically derive `k`, and
+/// `mul_by_generator` to compute `[k]G`.
+fn sign_prehash_rfc6979(
+    secret_scalar: &Scalar,
+    prehash: &[u8],
+    data: &[u8],
+    mul_by_generator: impl FnOnce(&Scalar) -> ProjectivePoint,
+) -> Result<Signature> {
     if prehash.len() != <Sm2 as Curve>::FieldBytesSize::USIZE {
         return Err(Error::new());
     }
 
     // A2: calculate e=Hv(M~)
-    let e = Scalar::reduce_bytes(FieldBytes::from_slice(prehash));
+    let e = <Scalar as Reduce<U256>>::reduce_bytes(FieldBytes::from_slice(prehash));
 
     // A3: pick a random number k in [1, n-1] via a random number generator
     let k = Scalar::from_repr(rfc6979::generate_k::<Sm3, _>(
@@ -201,10 +234,10 @@ fn sign_prehash_rfc6979(secret_scalar: &Scalar, prehash: &[u8], data: &[u8]) ->
     .unwrap();
 
     // A4: calculate the elliptic curve point (x1, y1)=[k]G
-    let R = ProjectivePoint::mul_by_generator(&k).to_affine();
+    let R = mul_by_generator(&k).to_affine();
 
     // A5: calculate r=(e+x1) modn, return to A3 if r=0 or r+k=n
-    let r = e + Scalar::reduce_bytes(&R.x());
+    let r = e + <Scalar as Reduce<U256>>::reduce_bytes(&R.x());
     if bool::from(r.is_zero() | (r + k).ct_eq(&Scalar::ZERO)) {
         return Err(Error::new());
     }
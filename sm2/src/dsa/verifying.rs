@@ -15,14 +15,11 @@
 use super::Signature;
 use crate::{
     distid::hash_z, AffinePoint, DistId, EncodedPoint, FieldBytes, Hash, ProjectivePoint,
-    PublicKey, Scalar, Sm2,
+    PublicKey, Scalar, Sm2, U256,
 };
 use elliptic_curve::{
-    array::typenum::Unsigned,
-    ops::{LinearCombination, Reduce},
-    point::AffineCoordinates,
-    sec1::ToEncodedPoint,
-    Curve, Group,
+    array::typenum::Unsigned, ops::Reduce, point::AffineCoordinates, sec1::ToEncodedPoint, Curve,
+    Group,
 };
 use signature::{hazmat::PrehashVerifier, Error, Result, Verifier};
 use sm3::{digest::Digest, Sm3};
@@ -140,7 +137,7 @@ impl PrehashVerifier<Signature> for VerifyingKey {
         let s = signature.s(); // NonZeroScalar checked at signature parse time
 
         // B4: calculate e'=Hv(M'~)
-        let e = Scalar::reduce_bytes(FieldBytes::from_slice(prehash));
+        let e = <Scalar as Reduce<U256>>::reduce_bytes(FieldBytes::from_slice(prehash));
 
         // B5: calculate t = (r' + s') modn, verification failed if t=0
         let t = *r + *s;
@@ -149,7 +146,7 @@ impl PrehashVerifier<Signature> for VerifyingKey {
         }
 
         // B6: calculate the point (x1', y1')=[s']G + [t]PA
-        let x = ProjectivePoint::lincomb(&[
+        let x = ProjectivePoint::lincomb_vartime(&[
             (ProjectivePoint::generator(), *s),
             (ProjectivePoint::from(&self.public_key), t),
         ])
@@ -157,7 +154,7 @@ impl PrehashVerifier<Signature> for VerifyingKey {
         .x();
 
         // B7: calculate R=(e'+x1') modn, verification pass if yes, otherwise failed
-        if *r == e + Scalar::reduce_bytes(&x) {
+        if *r == e + <Scalar as Reduce<U256>>::reduce_bytes(&x) {
             Ok(())
         } else {
             Err(Error::new())
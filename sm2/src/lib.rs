@@ -30,6 +30,8 @@ extern crate alloc;
 
 #[cfg(feature = "dsa")]
 pub mod dsa;
+#[cfg(feature = "ecdh")]
+pub mod ecdh;
 
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
@@ -47,7 +49,7 @@ pub use elliptic_curve::pkcs8;
 use elliptic_curve::{
     array::{typenum::U33, Array},
     bigint::ArrayEncoding,
-    consts::U32,
+    consts::{U32, U64},
     FieldBytesEncoding,
 };
 
@@ -101,6 +103,9 @@ pub type EncodedPoint = elliptic_curve::sec1::EncodedPoint<Sm2>;
 /// scalar).
 pub type FieldBytes = elliptic_curve::FieldBytes<Sm2>;
 
+/// Bytes used by a wide reduction: twice the width of [`FieldBytes`].
+pub type WideBytes = Array<u8, U64>;
+
 impl FieldBytesEncoding<Sm2> for U256 {
     fn decode_field_bytes(field_bytes: &FieldBytes) -> Self {
         U256::from_be_byte_array(*field_bytes)
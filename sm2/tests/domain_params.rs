@@ -0,0 +1,21 @@
+//! Domain parameter audit.
+
+#![cfg(feature = "arithmetic")]
+
+use primeorder::{
+    dev::{AuditReport, TwistSecurity},
+    impl_domain_params_tests,
+};
+use sm2::Sm2;
+
+impl_domain_params_tests!(
+    Sm2,
+    AuditReport {
+        twist_security: TwistSecurity::AtLeast {
+            bits: 8,
+            unfactored_bits: 245,
+        },
+        embedding_degree: None,
+        cm_discriminant: None,
+    }
+);
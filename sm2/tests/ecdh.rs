@@ -0,0 +1,41 @@
+//! ECDH tests.
+
+#![cfg(feature = "ecdh")]
+
+use elliptic_curve::group::ff::PrimeField;
+use hex_literal::hex;
+use primeorder::impl_projective_arithmetic_tests;
+use sm2::{AffinePoint, ProjectivePoint, Scalar};
+
+/// Scalar multiplication with the generator, for some of the scalars of the
+/// NIST P-256 test vectors.
+const MUL_TEST_VECTORS: &[([u8; 32], [u8; 32], [u8; 32])] = &[
+    (
+        hex!("000000000000000000000000000000000000000000000000018EBBB95EED0E13"),
+        hex!("046E082A9DB1E461594E9249A0BB98343C84E67097F3404DA9CFFB2ABDA885C6"),
+        hex!("533A16D93B5AFF9A7BB4E45CC5C353EF0B96551B4CC124564E3C8FCB1193BC63"),
+    ),
+    (
+        hex!("0000000000000000000000000000000000159D893D4CDD747246CDCA43590E13"),
+        hex!("B57A7986550CA2BC6E55277E9C820633C848C9B03678062144A77F1BC22C4CDC"),
+        hex!("787F3C823827336AD593504C959378C5012381B6EAA2FD0B6C9714F7097BEA60"),
+    ),
+    (
+        hex!("41FFC1FFFFFE01FFFC0003FFFE0007C001FFF00003FFF07FFE0007C000000003"),
+        hex!("E2A6C0B1566F7696CE86B056560D924BEB78B89AF7AEBD103370BBFD24E48C7B"),
+        hex!("B145CCC1C14D1B7A320F407C66622E26242801A5559513FAA9765ADDEC761AF3"),
+    ),
+    (
+        hex!("7FFFFFC03FFFC003FFFFFC007FFF00000000070000100000000E00FFFFFFF3FF"),
+        hex!("0C9B2EBD8B8CDB65CC8B7D42D36C00D940B1180ABD94D74C5116C26C4D5CFEF7"),
+        hex!("AAE8FF8EE619123A3BBB4234B23DAE00954D22BDE18FA6AB61319EC0E1743862"),
+    ),
+];
+
+impl_projective_arithmetic_tests!(
+    diffie_hellman_x: sm2::ecdh::diffie_hellman_x,
+    AffinePoint,
+    ProjectivePoint,
+    Scalar,
+    MUL_TEST_VECTORS
+);